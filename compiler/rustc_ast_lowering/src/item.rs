@@ -235,11 +235,13 @@ fn lower_item_kind(
                 self.lower_use_tree(use_tree, &prefix, id, vis_span, ident, attrs)
             }
             ItemKind::Static(ref t, m, ref e) => {
-                let (ty, body_id) = self.lower_const_item(t, span, e.as_deref());
+                let (ty, body_id) =
+                    self.lower_const_item(t, span, e.as_deref(), ImplTraitPosition::StaticTy);
                 hir::ItemKind::Static(ty, m, body_id)
             }
             ItemKind::Const(_, ref t, ref e) => {
-                let (ty, body_id) = self.lower_const_item(t, span, e.as_deref());
+                let (ty, body_id) =
+                    self.lower_const_item(t, span, e.as_deref(), ImplTraitPosition::ConstTy);
                 hir::ItemKind::Const(ty, body_id)
             }
             ItemKind::Fn(box Fn {
@@ -280,6 +282,7 @@ fn lower_item_kind(
             },
             ItemKind::ForeignMod(ref fm) => hir::ItemKind::ForeignMod {
                 abi: fm.abi.map_or(abi::Abi::FALLBACK, |abi| self.lower_abi(abi)),
+                abi_span: fm.abi.map(|abi| abi.span),
                 items: self
                     .arena
                     .alloc_from_iter(fm.items.iter().map(|x| self.lower_foreign_item_ref(x))),
@@ -473,11 +476,24 @@ fn lower_const_item(
         ty: &Ty,
         span: Span,
         body: Option<&Expr>,
+        position: ImplTraitPosition,
     ) -> (&'hir hir::Ty<'hir>, hir::BodyId) {
-        let ty = self.lower_ty(ty, ImplTraitContext::Disallowed(ImplTraitPosition::Type));
+        let ty = self.lower_ty(ty, self.const_ty_impl_trait_context(position));
         (ty, self.lower_const_body(span, body))
     }
 
+    /// With `#![feature(impl_trait_in_consts)]`, `impl Trait` in the type of a static or
+    /// constant is lowered like `impl Trait` in a type alias, its hidden type being
+    /// inferred from the initializer. Only the initializer itself may define the hidden type,
+    /// see `Map::get_defining_scope`.
+    fn const_ty_impl_trait_context(&self, position: ImplTraitPosition) -> ImplTraitContext {
+        if self.tcx.features().impl_trait_in_consts {
+            ImplTraitContext::TypeAliasesOpaqueTy
+        } else {
+            ImplTraitContext::Disallowed(position)
+        }
+    }
+
     #[instrument(level = "debug", skip(self))]
     fn lower_use_tree(
         &mut self,
@@ -756,14 +772,20 @@ fn lower_trait_item(&mut self, i: &AssocItem) -> &'hir hir::TraitItem<'hir> {
 
         let (generics, kind, has_default) = match i.kind {
             AssocItemKind::Const(_, ref ty, ref default) => {
-                let ty = self.lower_ty(ty, ImplTraitContext::Disallowed(ImplTraitPosition::Type));
+                let itctx = ImplTraitContext::Disallowed(ImplTraitPosition::TraitConstTy);
+                let ty = self.lower_ty(ty, itctx);
                 let body = default.as_ref().map(|x| self.lower_const_body(i.span, Some(x)));
                 (hir::Generics::empty(), hir::TraitItemKind::Const(ty, body), body.is_some())
             }
             AssocItemKind::Fn(box Fn { ref sig, ref generics, body: None, .. }) => {
                 let names = self.lower_fn_params_to_names(&sig.decl);
-                let (generics, sig) =
-                    self.lower_method_sig(generics, sig, i.id, FnDeclKind::Trait, None);
+                let (generics, sig) = self.lower_method_sig(
+                    generics,
+                    sig,
+                    i.id,
+                    FnDeclKind::Trait,
+                    sig.header.asyncness.opt_return_id(),
+                );
                 (generics, hir::TraitItemKind::Fn(sig, hir::TraitFn::Required(names)), false)
             }
             AssocItemKind::Fn(box Fn { ref sig, ref generics, body: Some(ref body), .. }) => {
@@ -852,7 +874,13 @@ fn lower_impl_item(&mut self, i: &AssocItem) -> &'hir hir::ImplItem<'hir> {
 
         let (generics, kind) = match &i.kind {
             AssocItemKind::Const(_, ty, expr) => {
-                let ty = self.lower_ty(ty, ImplTraitContext::Disallowed(ImplTraitPosition::Type));
+                // The type of a trait's associated constant is given by the trait.
+                let itctx = if self.is_in_trait_impl {
+                    ImplTraitContext::Disallowed(ImplTraitPosition::TraitConstTy)
+                } else {
+                    self.const_ty_impl_trait_context(ImplTraitPosition::ConstTy)
+                };
+                let ty = self.lower_ty(ty, itctx);
                 (
                     hir::Generics::empty(),
                     hir::ImplItemKind::Const(ty, self.lower_const_body(i.span, expr.as_deref())),
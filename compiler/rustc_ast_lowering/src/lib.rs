@@ -58,7 +58,7 @@
 use rustc_index::vec::{Idx, IndexVec};
 use rustc_middle::span_bug;
 use rustc_middle::ty::{ResolverAstLowering, TyCtxt};
-use rustc_session::parse::feature_err;
+use rustc_session::parse::{add_feature_diagnostics, feature_err};
 use rustc_span::hygiene::MacroKind;
 use rustc_span::source_map::DesugaringKind;
 use rustc_span::symbol::{kw, sym, Ident, Symbol};
@@ -264,6 +264,9 @@ enum ImplTraitContext {
     ReturnPositionOpaqueTy {
         /// Origin: Either OpaqueTyOrigin::FnReturn or OpaqueTyOrigin::AsyncFn,
         origin: hir::OpaqueTyOrigin,
+        /// Whether this is the return type of a trait method, in which case it is lowered to
+        /// a placeholder that each impl of the trait provides a hidden type for.
+        in_trait: bool,
     },
     /// Impl trait in type aliases.
     TypeAliasesOpaqueTy,
@@ -293,6 +296,9 @@ enum ImplTraitPosition {
     FnTraitReturn,
     TraitReturn,
     ImplReturn,
+    ConstTy,
+    StaticTy,
+    TraitConstTy,
 }
 
 impl std::fmt::Display for ImplTraitPosition {
@@ -317,6 +323,9 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             ImplTraitPosition::FnTraitReturn => "`Fn` trait return",
             ImplTraitPosition::TraitReturn => "trait method return",
             ImplTraitPosition::ImplReturn => "`impl` method return",
+            ImplTraitPosition::ConstTy => "const type",
+            ImplTraitPosition::StaticTy => "static type",
+            ImplTraitPosition::TraitConstTy => "trait associated const type",
         };
 
         write!(f, "{}", name)
@@ -335,9 +344,12 @@ enum FnDeclKind {
 }
 
 impl FnDeclKind {
-    fn impl_trait_return_allowed(&self) -> bool {
+    fn impl_trait_return_allowed(&self, tcx: TyCtxt<'_>) -> bool {
         match self {
             FnDeclKind::Fn | FnDeclKind::Inherent => true,
+            FnDeclKind::Impl | FnDeclKind::Trait => {
+                tcx.features().return_position_impl_trait_in_trait
+            }
             _ => false,
         }
     }
@@ -1251,6 +1263,10 @@ fn lower_ty_direct(&mut self, t: &Ty, itctx: ImplTraitContext) -> hir::Ty<'hir>
                     generic_params,
                     unsafety: self.lower_unsafety(f.unsafety),
                     abi: self.lower_extern(f.ext),
+                    abi_span: match f.ext {
+                        Extern::Explicit(abi, _) => Some(abi.span),
+                        Extern::None | Extern::Implicit(_) => None,
+                    },
                     decl: self.lower_fn_decl(&f.decl, None, FnDeclKind::Pointer, None),
                     param_names: self.lower_fn_params_to_names(&f.decl),
                 }))
@@ -1316,9 +1332,15 @@ fn lower_ty_direct(&mut self, t: &Ty, itctx: ImplTraitContext) -> hir::Ty<'hir>
             TyKind::ImplTrait(def_node_id, ref bounds) => {
                 let span = t.span;
                 match itctx {
-                    ImplTraitContext::ReturnPositionOpaqueTy { origin } => {
-                        self.lower_opaque_impl_trait(span, origin, def_node_id, bounds, itctx)
-                    }
+                    ImplTraitContext::ReturnPositionOpaqueTy { origin, in_trait } => self
+                        .lower_opaque_impl_trait(
+                            span,
+                            origin,
+                            def_node_id,
+                            bounds,
+                            in_trait,
+                            itctx,
+                        ),
                     ImplTraitContext::TypeAliasesOpaqueTy => {
                         let nested_itctx = ImplTraitContext::TypeAliasesOpaqueTy;
                         self.lower_opaque_impl_trait(
@@ -1326,6 +1348,7 @@ fn lower_ty_direct(&mut self, t: &Ty, itctx: ImplTraitContext) -> hir::Ty<'hir>
                             hir::OpaqueTyOrigin::TyAlias,
                             def_node_id,
                             bounds,
+                            false,
                             nested_itctx,
                         )
                     }
@@ -1348,6 +1371,34 @@ fn lower_ty_direct(&mut self, t: &Ty, itctx: ImplTraitContext) -> hir::Ty<'hir>
                             "`impl Trait` only allowed in function and inherent method return types, not in {}",
                             position
                         );
+                        match position {
+                            ImplTraitPosition::TraitReturn | ImplTraitPosition::ImplReturn => {
+                                add_feature_diagnostics(
+                                    &mut err,
+                                    &self.tcx.sess.parse_sess,
+                                    sym::return_position_impl_trait_in_trait,
+                                );
+                            }
+                            ImplTraitPosition::ConstTy | ImplTraitPosition::StaticTy => {
+                                err.note("the types of statics and constants must be named");
+                                err.help(
+                                    "consider replacing `impl Trait` with the type of the \
+                                     initializer",
+                                );
+                                add_feature_diagnostics(
+                                    &mut err,
+                                    &self.tcx.sess.parse_sess,
+                                    sym::impl_trait_in_consts,
+                                );
+                            }
+                            ImplTraitPosition::TraitConstTy => {
+                                err.note(
+                                    "the type of an associated constant of a trait is shared by \
+                                     all of its implementations, so it must be named",
+                                );
+                            }
+                            _ => {}
+                        }
                         err.emit();
                         hir::TyKind::Err
                     }
@@ -1402,6 +1453,7 @@ fn lower_opaque_impl_trait(
         origin: hir::OpaqueTyOrigin,
         opaque_ty_node_id: NodeId,
         bounds: &GenericBounds,
+        in_trait: bool,
         itctx: ImplTraitContext,
     ) -> hir::TyKind<'hir> {
         // Make sure we know that some funky desugaring has been going on here.
@@ -1490,6 +1542,7 @@ fn lower_opaque_impl_trait(
                     }),
                     bounds: hir_bounds,
                     origin,
+                    in_trait,
                 };
                 debug!(?opaque_ty_item);
 
@@ -1673,15 +1726,17 @@ fn lower_fn_decl(
                 &decl.output,
                 fn_node_id.expect("`make_ret_async` but no `fn_def_id`"),
                 ret_id,
+                matches!(kind, FnDeclKind::Trait),
             )
         } else {
             match decl.output {
                 FnRetTy::Ty(ref ty) => {
                     let context = match fn_node_id {
-                        Some(fn_node_id) if kind.impl_trait_return_allowed() => {
+                        Some(fn_node_id) if kind.impl_trait_return_allowed(self.tcx) => {
                             let fn_def_id = self.local_def_id(fn_node_id);
                             ImplTraitContext::ReturnPositionOpaqueTy {
                                 origin: hir::OpaqueTyOrigin::FnReturn(fn_def_id),
+                                in_trait: matches!(kind, FnDeclKind::Trait),
                             }
                         }
                         _ => ImplTraitContext::Disallowed(match kind {
@@ -1740,12 +1795,14 @@ fn lower_fn_decl(
     // `output`: unlowered output type (`T` in `-> T`)
     // `fn_def_id`: `DefId` of the parent function (used to create child impl trait definition)
     // `opaque_ty_node_id`: `NodeId` of the opaque `impl Trait` type that should be created
+    // `in_trait`: whether the `async fn` is declared in a trait
     #[tracing::instrument(level = "debug", skip(self))]
     fn lower_async_fn_ret_ty(
         &mut self,
         output: &FnRetTy,
         fn_node_id: NodeId,
         opaque_ty_node_id: NodeId,
+        in_trait: bool,
     ) -> hir::FnRetTy<'hir> {
         let span = output.span();
 
@@ -1873,7 +1930,9 @@ fn lower_async_fn_ret_ty(
                 // Then, we will create `fn foo(..) -> Foo<'_, '_>`, and
                 // hence the elision takes place at the fn site.
                 let future_bound =
-                    this.lower_async_fn_output_type_to_future_bound(output, fn_def_id, span);
+                    this.lower_async_fn_output_type_to_future_bound(
+                        output, fn_def_id, in_trait, span,
+                    );
 
                 let generic_params = this.arena.alloc_from_iter(collected_lifetimes.iter().map(
                     |&(new_node_id, lifetime, _)| {
@@ -1911,6 +1970,7 @@ fn lower_async_fn_ret_ty(
                     }),
                     bounds: arena_vec![this; future_bound],
                     origin: hir::OpaqueTyOrigin::AsyncFn(fn_def_id),
+                    in_trait,
                 };
 
                 trace!("exist ty from async fn def id: {:#?}", opaque_ty_def_id);
@@ -1967,6 +2027,7 @@ fn lower_async_fn_output_type_to_future_bound(
         &mut self,
         output: &FnRetTy,
         fn_def_id: LocalDefId,
+        in_trait: bool,
         span: Span,
     ) -> hir::GenericBound<'hir> {
         // Compute the `T` in `Future<Output = T>` from the return type.
@@ -1977,6 +2038,7 @@ fn lower_async_fn_output_type_to_future_bound(
                 // generates.
                 let context = ImplTraitContext::ReturnPositionOpaqueTy {
                     origin: hir::OpaqueTyOrigin::FnReturn(fn_def_id),
+                    in_trait,
                 };
                 self.lower_ty(ty, context)
             }
@@ -13,12 +13,13 @@
 use rustc_ast::*;
 use rustc_ast_pretty::pprust::{self, State};
 use rustc_data_structures::fx::FxHashMap;
-use rustc_errors::{error_code, fluent, pluralize, struct_span_err, Applicability};
+use rustc_errors::{error_code, fluent, pluralize, struct_span_err, Applicability, StashKey};
 use rustc_parse::validate_attr;
 use rustc_session::lint::builtin::{
     DEPRECATED_WHERE_CLAUSE_LOCATION, MISSING_ABI, PATTERNS_IN_FNS_WITHOUT_BODY,
 };
 use rustc_session::lint::{BuiltinLintDiagnostics, LintBuffer};
+use rustc_session::parse::add_feature_diagnostics;
 use rustc_session::Session;
 use rustc_span::source_map::Spanned;
 use rustc_span::symbol::{kw, sym, Ident};
@@ -36,6 +37,9 @@
 enum SelfSemantic {
     Yes,
     No,
+    /// Not allowed either, but the error is stashed so that late resolution can suggest
+    /// moving the function into an `impl` block.
+    FreeFn,
 }
 
 struct AstValidator<'a> {
@@ -287,7 +291,12 @@ fn check_decl_no_pat(decl: &FnDecl, mut report_err: impl FnMut(Span, Option<Iden
 
     fn check_trait_fn_not_async(&self, fn_span: Span, asyncness: Async) {
         if let Async::Yes { span, .. } = asyncness {
-            self.session.emit_err(TraitFnAsync { fn_span, span });
+            if self.session.features_untracked().async_fn_in_trait {
+                return;
+            }
+            let mut err = self.session.create_err(TraitFnAsync { fn_span, span });
+            add_feature_diagnostics(&mut err, &self.session.parse_sess, sym::async_fn_in_trait);
+            err.emit();
         }
     }
 
@@ -342,10 +351,24 @@ fn check_decl_cvaradic_pos(&self, fn_decl: &FnDecl) {
                     self.session.emit_err(FnParamCVarArgsOnly { span: *span });
                 }
             }
-            [ps @ .., _] => {
-                for Param { ty, span, .. } in ps {
+            [ps @ .., last] => {
+                // Only offer to move the `...` when it is the sole one, otherwise the
+                // reordered signature would still be invalid.
+                let c_variadic_count =
+                    fn_decl.inputs.iter().filter(|p| matches!(p.ty.kind, TyKind::CVarArgs)).count();
+                for (i, Param { ty, span, .. }) in ps.iter().enumerate() {
                     if let TyKind::CVarArgs = ty.kind {
-                        self.session.emit_err(FnParamCVarArgsNotLast { span: *span });
+                        // The parameter is moved as written, so a name like in `mut ap: ...`
+                        // is kept.
+                        let suggestion = (c_variadic_count == 1)
+                            .then(|| self.session.source_map().span_to_snippet(*span).ok())
+                            .flatten()
+                            .map(|param| FnParamCVarArgsMoveToEnd {
+                                remove_span: span.until(fn_decl.inputs[i + 1].span),
+                                insert_span: last.span.shrink_to_hi(),
+                                param,
+                            });
+                        self.session.emit_err(FnParamCVarArgsNotLast { span: *span, suggestion });
                     }
                 }
             }
@@ -380,10 +403,16 @@ fn check_decl_attrs(&self, fn_decl: &FnDecl) {
     }
 
     fn check_decl_self_param(&self, fn_decl: &FnDecl, self_semantic: SelfSemantic) {
-        if let (SelfSemantic::No, [param, ..]) = (self_semantic, &*fn_decl.inputs) {
-            if param.is_self() {
+        match (self_semantic, &*fn_decl.inputs) {
+            (SelfSemantic::No, [param, ..]) if param.is_self() => {
                 self.session.emit_err(FnParamForbiddenSelf { span: param.span });
             }
+            (SelfSemantic::FreeFn, [param, ..]) if param.is_self() => {
+                self.session
+                    .create_err(FnParamForbiddenSelf { span: param.span })
+                    .stash(param.span, StashKey::SelfParamInFreeFn);
+            }
+            _ => {}
         }
     }
 
@@ -1448,8 +1477,9 @@ fn visit_enum_def(&mut self, enum_definition: &'a EnumDef) {
 
     fn visit_fn(&mut self, fk: FnKind<'a>, span: Span, id: NodeId) {
         // Only associated `fn`s can have `self` parameters.
-        let self_semantic = match fk.ctxt() {
-            Some(FnCtxt::Assoc(_)) => SelfSemantic::Yes,
+        let self_semantic = match fk {
+            FnKind::Fn(FnCtxt::Assoc(_), ..) => SelfSemantic::Yes,
+            FnKind::Fn(FnCtxt::Free, _, _, _, _, Some(_)) => SelfSemantic::FreeFn,
             _ => SelfSemantic::No,
         };
         self.check_fn_decl(fk.decl(), self_semantic);
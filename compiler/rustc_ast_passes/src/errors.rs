@@ -124,6 +124,30 @@ pub struct FnParamCVarArgsOnly {
 pub struct FnParamCVarArgsNotLast {
     #[primary_span]
     pub span: Span,
+    #[subdiagnostic]
+    pub suggestion: Option<FnParamCVarArgsMoveToEnd>,
+}
+
+pub struct FnParamCVarArgsMoveToEnd {
+    /// The `...` parameter along with the separator that follows it.
+    pub remove_span: Span,
+    /// The end of the last parameter.
+    pub insert_span: Span,
+    /// The `...` parameter as written, including its pattern if it has one.
+    pub param: String,
+}
+
+impl AddSubdiagnostic for FnParamCVarArgsMoveToEnd {
+    fn add_to_diagnostic(self, diag: &mut Diagnostic) {
+        diag.multipart_suggestion(
+            fluent::ast_passes::fn_param_c_var_args_move_to_end,
+            vec![
+                (self.remove_span, String::new()),
+                (self.insert_span, format!(", {}", self.param)),
+            ],
+            Applicability::MachineApplicable,
+        );
+    }
 }
 
 #[derive(SessionDiagnostic)]
@@ -9,7 +9,7 @@
 
 use rustc_codegen_ssa::base::{compare_simd_types, wants_msvc_seh};
 use rustc_codegen_ssa::common::span_invalid_monomorphization_error;
-use rustc_codegen_ssa::common::{IntPredicate, TypeKind};
+use rustc_codegen_ssa::common::IntPredicate;
 use rustc_codegen_ssa::mir::operand::OperandRef;
 use rustc_codegen_ssa::mir::place::PlaceRef;
 use rustc_codegen_ssa::traits::*;
@@ -867,8 +867,15 @@ macro_rules! require_simd {
         tcx.normalize_erasing_late_bound_regions(ty::ParamEnv::reveal_all(), callee_ty.fn_sig(tcx));
     let arg_tys = sig.inputs();
 
+    // The requirements on the argument and return types that don't depend on the backend are
+    // shared with typeck, which checks the calls that don't depend on generic parameters.
+    if let Err(msg) =
+        ty::simd::check_simd_intrinsic_types(tcx, ty::ParamEnv::reveal_all(), name, arg_tys, ret_ty)
+    {
+        return_error!("{}", msg);
+    }
+
     if name == sym::simd_select_bitmask {
-        require_simd!(arg_tys[1], "argument");
         let (len, _) = arg_tys[1].simd_size_and_type(bx.tcx());
 
         let expected_int_bits = (len.max(8) - 1).next_power_of_two();
@@ -889,12 +896,7 @@ macro_rules! require_simd {
                 let ptr = bx.pointercast(place.llval, bx.cx.type_ptr_to(int_ty));
                 bx.load(int_ty, ptr, Align::ONE)
             }
-            _ => return_error!(
-                "invalid bitmask `{}`, expected `u{}` or `[u8; {}]`",
-                mask_ty,
-                expected_int_bits,
-                expected_bytes
-            ),
+            _ => span_bug!(span, "invalid bitmask `{}`", mask_ty),
         };
 
         let i1 = bx.type_i1();
@@ -906,7 +908,6 @@ macro_rules! require_simd {
     }
 
     // every intrinsic below takes a SIMD vector as its first argument
-    require_simd!(arg_tys[0], "input");
     let in_ty = arg_tys[0];
 
     let comparison = match name {
@@ -921,25 +922,6 @@ macro_rules! require_simd {
 
     let (in_len, in_elem) = arg_tys[0].simd_size_and_type(bx.tcx());
     if let Some(cmp_op) = comparison {
-        require_simd!(ret_ty, "return");
-
-        let (out_len, out_ty) = ret_ty.simd_size_and_type(bx.tcx());
-        require!(
-            in_len == out_len,
-            "expected return type with length {} (same as input type `{}`), \
-             found `{}` with length {}",
-            in_len,
-            in_ty,
-            ret_ty,
-            out_len
-        );
-        require!(
-            bx.type_kind(bx.element_type(llret_ty)) == TypeKind::Integer,
-            "expected return type with integer elements, found `{}` with non-integer `{}`",
-            ret_ty,
-            out_ty
-        );
-
         return Ok(compare_simd_types(
             bx,
             args[0].immediate(),
@@ -962,10 +944,7 @@ macro_rules! require_simd {
                         span_bug!(span, "could not evaluate shuffle index array length")
                     })
                 }
-                _ => return_error!(
-                    "simd_shuffle index must be an array of `u32`, got `{}`",
-                    args[2].layout.ty
-                ),
+                _ => span_bug!(span, "simd_shuffle index must be an array of `u32`"),
             }
         } else {
             stripped.parse().unwrap_or_else(|_| {
@@ -973,25 +952,6 @@ macro_rules! require_simd {
             })
         };
 
-        require_simd!(ret_ty, "return");
-        let (out_len, out_ty) = ret_ty.simd_size_and_type(bx.tcx());
-        require!(
-            out_len == n,
-            "expected return type of length {}, found `{}` with length {}",
-            n,
-            ret_ty,
-            out_len
-        );
-        require!(
-            in_elem == out_ty,
-            "expected return element type `{}` (element of input `{}`), \
-             found `{}` with element type `{}`",
-            in_elem,
-            in_ty,
-            ret_ty,
-            out_ty
-        );
-
         let total_len = u128::from(in_len) * 2;
 
         let vector = args[2].immediate();
@@ -1029,13 +989,6 @@ macro_rules! require_simd {
     }
 
     if name == sym::simd_insert {
-        require!(
-            in_elem == arg_tys[2],
-            "expected inserted type `{}` (element of input `{}`), found `{}`",
-            in_elem,
-            in_ty,
-            arg_tys[2]
-        );
         return Ok(bx.insert_element(
             args[0].immediate(),
             args[2].immediate(),
@@ -1043,34 +996,13 @@ macro_rules! require_simd {
         ));
     }
     if name == sym::simd_extract {
-        require!(
-            ret_ty == in_elem,
-            "expected return type `{}` (element of input `{}`), found `{}`",
-            in_elem,
-            in_ty,
-            ret_ty
-        );
         return Ok(bx.extract_element(args[0].immediate(), args[1].immediate()));
     }
 
     if name == sym::simd_select {
-        let m_elem_ty = in_elem;
-        let m_len = in_len;
-        require_simd!(arg_tys[1], "argument");
-        let (v_len, _) = arg_tys[1].simd_size_and_type(bx.tcx());
-        require!(
-            m_len == v_len,
-            "mismatched lengths: mask length `{}` != other vector length `{}`",
-            m_len,
-            v_len
-        );
-        match m_elem_ty.kind() {
-            ty::Int(_) => {}
-            _ => return_error!("mask element type is `{}`, expected `i_`", m_elem_ty),
-        }
         // truncate the mask to a vector of i1s
         let i1 = bx.type_i1();
-        let i1xn = bx.type_vector(i1, m_len as u64);
+        let i1xn = bx.type_vector(i1, in_len as u64);
         let m_i1s = bx.trunc(args[0].immediate(), i1xn);
         return Ok(bx.select(m_i1s, args[1].immediate(), args[2].immediate()));
     }
@@ -1098,11 +1030,7 @@ macro_rules! require_simd {
                 args[0].immediate(),
                 i.bit_width().unwrap_or_else(|| bx.data_layout().pointer_size.bits()),
             ),
-            _ => return_error!(
-                "vector argument `{}`'s element type `{}`, expected integer element type",
-                in_ty,
-                in_elem
-            ),
+            _ => span_bug!(span, "vector argument `{}` doesn't have integer elements", in_ty),
         };
 
         // Shift the MSB to the right by "in_elem_bitwidth - 1" into the first bit position.
@@ -1137,12 +1065,7 @@ macro_rules! require_simd {
                 let ptr = bx.pointercast(ptr, bx.cx.type_ptr_to(array_ty));
                 return Ok(bx.load(array_ty, ptr, Align::ONE));
             }
-            _ => return_error!(
-                "cannot return `{}`, expected `u{}` or `[u8; {}]`",
-                ret_ty,
-                expected_int_bits,
-                expected_bytes
-            ),
+            _ => span_bug!(span, "invalid bitmask `{}`", ret_ty),
         }
     }
 
@@ -1551,13 +1474,6 @@ macro_rules! arith_red {
         ($name:ident : $integer_reduce:ident, $float_reduce:ident, $ordered:expr, $op:ident,
          $identity:expr) => {
             if name == sym::$name {
-                require!(
-                    ret_ty == in_elem,
-                    "expected return type `{}` (element of input `{}`), found `{}`",
-                    in_elem,
-                    in_ty,
-                    ret_ty
-                );
                 return match in_elem.kind() {
                     ty::Int(_) | ty::Uint(_) => {
                         let r = bx.$integer_reduce(args[0].immediate());
@@ -1591,13 +1507,7 @@ macro_rules! arith_red {
                         };
                         Ok(bx.$float_reduce(acc, args[0].immediate()))
                     }
-                    _ => return_error!(
-                        "unsupported {} from `{}` with element `{}` to `{}`",
-                        sym::$name,
-                        in_ty,
-                        in_elem,
-                        ret_ty
-                    ),
+                    _ => span_bug!(span, "unsupported {} from `{}`", sym::$name, in_ty),
                 };
             }
         };
@@ -1623,24 +1533,11 @@ macro_rules! arith_red {
     macro_rules! minmax_red {
         ($name:ident: $int_red:ident, $float_red:ident) => {
             if name == sym::$name {
-                require!(
-                    ret_ty == in_elem,
-                    "expected return type `{}` (element of input `{}`), found `{}`",
-                    in_elem,
-                    in_ty,
-                    ret_ty
-                );
                 return match in_elem.kind() {
                     ty::Int(_i) => Ok(bx.$int_red(args[0].immediate(), true)),
                     ty::Uint(_u) => Ok(bx.$int_red(args[0].immediate(), false)),
                     ty::Float(_f) => Ok(bx.$float_red(args[0].immediate())),
-                    _ => return_error!(
-                        "unsupported {} from `{}` with element `{}` to `{}`",
-                        sym::$name,
-                        in_ty,
-                        in_elem,
-                        ret_ty
-                    ),
+                    _ => span_bug!(span, "unsupported {} from `{}`", sym::$name, in_ty),
                 };
             }
         };
@@ -1656,26 +1553,8 @@ macro_rules! bitwise_red {
         ($name:ident : $red:ident, $boolean:expr) => {
             if name == sym::$name {
                 let input = if !$boolean {
-                    require!(
-                        ret_ty == in_elem,
-                        "expected return type `{}` (element of input `{}`), found `{}`",
-                        in_elem,
-                        in_ty,
-                        ret_ty
-                    );
                     args[0].immediate()
                 } else {
-                    match in_elem.kind() {
-                        ty::Int(_) | ty::Uint(_) => {}
-                        _ => return_error!(
-                            "unsupported {} from `{}` with element `{}` to `{}`",
-                            sym::$name,
-                            in_ty,
-                            in_elem,
-                            ret_ty
-                        ),
-                    }
-
                     // boolean reductions operate on vectors of i1s:
                     let i1 = bx.type_i1();
                     let i1xn = bx.type_vector(i1, in_len as u64);
@@ -1686,13 +1565,7 @@ macro_rules! bitwise_red {
                         let r = bx.$red(input);
                         Ok(if !$boolean { r } else { bx.zext(r, bx.type_bool()) })
                     }
-                    _ => return_error!(
-                        "unsupported {} from `{}` with element `{}` to `{}`",
-                        sym::$name,
-                        in_ty,
-                        in_elem,
-                        ret_ty
-                    ),
+                    _ => span_bug!(span, "unsupported {} from `{}`", sym::$name, in_ty),
                 };
             }
         };
@@ -1705,17 +1578,7 @@ macro_rules! bitwise_red {
     bitwise_red!(simd_reduce_any: vector_reduce_or, true);
 
     if name == sym::simd_cast || name == sym::simd_as {
-        require_simd!(ret_ty, "return");
-        let (out_len, out_elem) = ret_ty.simd_size_and_type(bx.tcx());
-        require!(
-            in_len == out_len,
-            "expected return type with length {} (same as input type `{}`), \
-                  found `{}` with length {}",
-            in_len,
-            in_ty,
-            ret_ty,
-            out_len
-        );
+        let (_, out_elem) = ret_ty.simd_size_and_type(bx.tcx());
         // casting cares about nominal type, not just structural type
         if in_elem == out_elem {
             return Ok(args[0].immediate());
@@ -1791,14 +1654,7 @@ enum Style {
             }
             _ => { /* Unsupported. Fallthrough. */ }
         }
-        require!(
-            false,
-            "unsupported cast from `{}` with element `{}` to `{}` with element `{}`",
-            in_ty,
-            in_elem,
-            ret_ty,
-            out_elem
-        );
+        span_bug!(span, "unsupported cast from `{}` to `{}`", in_ty, ret_ty);
     }
     macro_rules! arith_binary {
         ($($name: ident: $($($p: ident),* => $call: ident),*;)*) => {
@@ -1809,10 +1665,7 @@ macro_rules! arith_binary {
                     })*
                     _ => {},
                 }
-                require!(false,
-                         "unsupported operation on `{}` with element `{}`",
-                         in_ty,
-                         in_elem)
+                span_bug!(span, "unsupported operation on `{}`", in_ty)
             })*
         }
     }
@@ -1840,10 +1693,7 @@ macro_rules! arith_unary {
                     })*
                     _ => {},
                 }
-                require!(false,
-                         "unsupported operation on `{}` with element `{}`",
-                         in_ty,
-                         in_elem)
+                span_bug!(span, "unsupported operation on `{}`", in_ty)
             })*
         }
     }
@@ -1880,14 +1730,7 @@ macro_rules! arith_unary {
         let (signed, elem_width, elem_ty) = match *in_elem.kind() {
             ty::Int(i) => (true, i.bit_width().unwrap_or(ptr_bits), bx.cx.type_int_from_ty(i)),
             ty::Uint(i) => (false, i.bit_width().unwrap_or(ptr_bits), bx.cx.type_uint_from_ty(i)),
-            _ => {
-                return_error!(
-                    "expected element type `{}` of vector type `{}` \
-                     to be a signed or unsigned integer type",
-                    arg_tys[0].simd_size_and_type(bx.tcx()).1,
-                    arg_tys[0]
-                );
-            }
+            _ => span_bug!(span, "expected `{}` to have integer elements", in_ty),
         };
         let llvm_intrinsic = &format!(
             "llvm.{}{}.sat.v{}i{}",
@@ -47,6 +47,9 @@ ast_passes_fn_param_c_var_args_only =
 ast_passes_fn_param_c_var_args_not_last =
     `...` must be the last argument of a C-variadic function
 
+ast_passes_fn_param_c_var_args_move_to_end =
+    move `...` to the end of the argument list
+
 ast_passes_fn_param_doc_comment =
     documentation comments cannot be applied to function parameters
     .label = doc comments are not allowed here
@@ -175,6 +175,8 @@ lint_unused_comparisons = comparison is useless due to type limits
 
 lint_improper_ctypes = `extern` {$desc} uses type `{$ty}`, which is not FFI-safe
     .label = not FFI-safe
+    .param_label = parameter `{$param}` is not FFI-safe
+    .return_label = return type is not FFI-safe
     .note = the type is defined here
 
 lint_improper_ctypes_opaque = opaque types have no C equivalent
@@ -340,6 +342,8 @@ lint_builtin_unused_doc_comment = unused doc comment
     .block_help = use `/* */` for a plain comment
 
 lint_builtin_no_mangle_generic = functions generic over types or consts must be mangled
+    .label = generic parameter
+    .note = each instantiation of a generic function is a separate function, so they cannot all be exported under a single symbol name
     .suggestion = remove this attribute
 
 lint_builtin_const_no_mangle = const items should never be `#[no_mangle]`
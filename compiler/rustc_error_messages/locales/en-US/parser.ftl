@@ -150,3 +150,8 @@ parser_dotdotdot = unexpected token: `...`
 
 parser_left_arrow_operator = unexpected token: `<-`
     .suggestion = if you meant to write a comparison against a negative value, add a space in between `<` and `-`
+
+parser_binder_on_assoc_binding = `{$binder}` is not allowed on the associated type binding `{$binding}`
+    .label = the binder is introduced here
+    .binding_label = for this binding
+    .help = higher-ranked lifetimes can be introduced on the whole trait bound instead, by writing `{$binder}` before the path of the trait
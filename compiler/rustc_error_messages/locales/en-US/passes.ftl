@@ -260,8 +260,29 @@ passes_unused_multiple = multiple `{$name}` attributes
 passes_rustc_lint_opt_ty = `#[rustc_lint_opt_ty]` should be applied to a struct
     .label = not a struct
 
+passes_rustc_do_not_suggest = `#[rustc_do_not_suggest]` should be applied to a struct, enum or union
+    .label = not a struct, enum or union
+
+passes_rustc_do_not_suggest_unknown_family = unknown suggestion family
+    .help = expected one of `borrow`, `deref` or `clone`
+
 passes_rustc_lint_opt_deny_field_access = `#[rustc_lint_opt_deny_field_access]` should be applied to a field
     .label = not a field
 
 passes_link_ordinal = attribute should be applied to a foreign function or static
     .label = not a foreign function or static
+
+passes_break_non_loop = `break` with value from a `{$kind}` loop
+    .label = can only break with a value inside `loop` or breakable block
+    .label_head = you can't `break` with a value in a `{$kind}` loop
+    .suggestion = use `break` on its own without a value inside this `{$kind}` loop
+    .break_expr_suggestion = alternatively, you might have meant to use the available loop label
+    .loop_suggestion = alternatively, use `loop` and check the condition explicitly
+
+passes_attr_only_on_main = `{$attr}` attribute can only be used on `fn main()`
+
+passes_attr_only_on_root_main = `{$attr}` attribute can only be used on root `fn main()`
+
+passes_unix_sigpipe_values = valid values for `#[unix_sigpipe = "..."]` are `inherit`, `sig_ign`, or `sig_dfl`
+
+passes_unix_sigpipe_value_suggestion = there is a valid value with a similar name
@@ -0,0 +1,5 @@
+trait_selection_auto_deref_reached_recursion_limit =
+    reached the recursion limit while auto-dereferencing `{$ty}`
+    .label = deref recursion limit reached
+    .note = auto-dereferencing went through {$deref_chain}
+    .help = consider increasing the recursion limit by adding a `#![recursion_limit = "{$suggested_limit}"]` attribute to your crate (`{$crate_name}`)
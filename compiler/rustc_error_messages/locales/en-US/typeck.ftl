@@ -7,6 +7,12 @@ typeck_unrecognized_atomic_operation =
     unrecognized atomic operation function: `{$op}`
     .label = unrecognized atomic operation
 
+typeck_unrecognized_atomic_ordering =
+    unrecognized memory ordering `{$ordering}` in atomic intrinsic `{$name}`
+    .label = unrecognized memory ordering
+    .note = the valid {$kind} orderings for `{$op}` are {$valid_orderings}
+    .suggestion = use a valid memory ordering
+
 typeck_wrong_number_of_generic_arguments_to_intrinsic =
     intrinsic has wrong number of {$descr} parameters: found {$found}, expected {$expected}
     .label = expected {$expected} {$descr} {$expected ->
@@ -23,6 +29,10 @@ typeck_lifetimes_or_bounds_mismatch_on_trait =
     .label = lifetimes do not match {$item_kind} in trait
     .generics_label = lifetimes in impl do not match this {$item_kind} in trait
 
+typeck_bound_only_on_trait = this bound is declared on the trait but not in the impl
+
+typeck_bound_only_on_impl = this bound is declared in the impl but not on the trait
+
 typeck_drop_impl_on_wrong_item =
     the `Drop` trait may only be implemented for local structs, enums, and unions
     .label = must be a struct, enum, or union in the current crate
@@ -47,18 +57,42 @@ typeck_trait_object_declared_with_no_traits =
     at least one trait is required for an object type
     .alias_span = this alias does not contain a trait
 
+typeck_object_safe_principal_candidates =
+    {$count ->
+        [0] none of these traits are object safe, so none of them can be the principal trait of a trait object
+        [one] {$traits} is the only object safe trait here, so it must be the principal trait
+        *[other] {$traits} are object safe, so any one of them could be the principal trait
+    }
+
+typeck_principal_trait_candidates =
+    {$count ->
+        [one] {$traits} can only be used once as the principal trait of a trait object
+        *[other] only one of {$traits} can be the principal trait of a trait object
+    }
+
+typeck_remove_non_auto_trait = consider removing `{$trait_name}` from the trait object
+
 typeck_ambiguous_lifetime_bound =
     ambiguous lifetime bound, explicit lifetime bound required
+    .note = the traits of this object type imply different lifetime bounds: {$lifetimes}
+    .suggestion = specify the lifetime bound of the object type explicitly
 
 typeck_assoc_type_binding_not_allowed =
     associated type bindings are not allowed here
     .label = associated type not allowed here
+    .suggestion = constrain the associated type in a `where` clause instead
+
+typeck_unused_where_clause_lifetime =
+    lifetime parameter `{$name}` is only used in the `where` clause
+    .label = this bound doesn't constrain anything
+    .note = as `{$name}` isn't used anywhere else, a lifetime satisfying these bounds can always be chosen for it
+    .suggestion = remove the lifetime parameter and the bounds using it
 
 typeck_functional_record_update_on_non_struct =
     functional record update syntax requires a struct
 
 typeck_typeof_reserved_keyword_used =
-    `typeof` is a reserved keyword but unimplemented
+    use of the reserved `typeof` keyword is experimental
     .suggestion = consider replacing `typeof(...)` with an actual type
     .label = reserved keyword
 
@@ -88,6 +122,14 @@ typeck_add_return_type_add = try adding a return type
 
 typeck_add_return_type_missing_here = a return type might be missing here
 
+typeck_add_return_type_closure = try adding a return type to the closure
+
+typeck_add_return_type_closure_and_values =
+    try adding a return type to the closure and returning a value from each bare `return`
+
+typeck_add_return_type_async_block =
+    async blocks cannot have a return type annotation; to make this block evaluate to `{$found}`, every `return` in it must also return a value of type `{$found}`
+
 typeck_expected_default_return_type = expected `()` because of default return type
 
 typeck_expected_return_type = expected `{$expected}` because of return type
@@ -128,6 +170,11 @@ typeck_unused_extern_crate =
     unused extern crate
     .suggestion = remove it
 
+typeck_unreachable_drop_impl =
+    this `Drop` impl is never used
+    .label = `{$bound}` is never satisfied
+    .note = no type implements `{$trait_name}`, so no `{$self_ty}` is ever dropped using this impl
+
 typeck_extern_crate_not_idiomatic =
     `extern crate` is not idiomatic in the new edition
     .suggestion = convert it to a `{$msg_code}`
@@ -156,6 +203,9 @@ typeck_type_parameter_not_constrained_for_impl =
     .label = unconstrained {$kind} parameter
     .first_note = expressions using a const parameter must map each value to a distinct output value
     .second_note = proving the result of expressions other than the parameter are unique is not supported
+    .remove_param_suggestion = remove the unused {$kind} parameter `{$name}`
+    .add_param_suggestion = use the {$kind} parameter `{$name}` as a generic argument
+    .phantom_data_suggestion = add the {$kind} parameter `{$name}` to `{$self_ty}` and use it in a `PhantomData` field
 
 typeck_associated_items_not_distinct =
     duplicate definitions with name `{$ident}`:
@@ -170,8 +220,12 @@ typeck_associated_items_not_defined_in_trait =
 
 typeck_enum_discriminant_overflow =
     enum discriminant overflowed
-    .label = overflowed on value after {$last_good_discriminant}
     .note = explicitly set `{$overflown_discriminant} = {$wrapped_value}` if that is desired outcome
+    .range_note = discriminants of `{$repr_ty}` must be within `{$min}..={$max}`
+
+typeck_discriminant_overflowed_after = overflowed on value after {$last_good_discriminant}
+
+typeck_discriminant_underflowed = underflowed on value {$value}
 
 typeck_rustc_paren_sugar_not_enabled =
     the `#[rustc_paren_sugar]` attribute is a temporary means of controlling which traits can use parenthetical notation
@@ -189,8 +243,21 @@ cmse_nonsecure_entry_requires_c_abi =
 cmse_nonsecure_entry_requires_trust_zone_m_ext =
     `#[cmse_nonsecure_entry]` is only valid for targets with the TrustZone-M extension
 
+typeck_cmse_nonsecure_call_inputs_too_large =
+    arguments for `"C-cmse-nonsecure-call"` function too large to pass via registers
+    .label = this argument of type `{$ty}` is {$size} bytes and doesn't fit in the available registers
+    .note = functions with the `"C-cmse-nonsecure-call"` ABI must pass all their arguments via the 4 32-bit argument registers
+
+typeck_cmse_nonsecure_call_output_too_large =
+    return value of `"C-cmse-nonsecure-call"` function too large to pass via registers
+    .label = this call returns `{$ty}`, which is {$size} bytes and doesn't fit in the available return registers
+    .note = functions with the `"C-cmse-nonsecure-call"` ABI must return their result via the available return registers
+
 typeck_track_caller_requires_cabi =
     `#[track_caller]` requires Rust ABI
+    .note = this function uses the "{$abi}" ABI
+    .suggestion = remove the `extern` qualifier to use the Rust ABI
+    .remove_attr_suggestion = remove the `#[track_caller]` attribute
 
 typeck_export_name_contains_null_characters =
     `export_name` may not contain null characters
@@ -201,6 +268,7 @@ typeck_instruction_set_unsupported_on_target =
 typeck_varargs_on_non_cabi_function =
     C-variadic function must have C or cdecl calling convention
     .label = C-variadics require C or cdecl calling convention
+    .suggestion = use the {$suggested_abi} calling convention
 
 typeck_generic_params_on_main_function =
     `main` function is not allowed to have generic parameters
@@ -214,9 +282,20 @@ typeck_async_main_function =
     `main` function is not allowed to be `async`
     .label = `main` function is not allowed to be `async`
 
+typeck_non_rust_abi_on_main_function =
+    `main` function is not allowed to use the "{$abi}" ABI
+    .label = `main` must use the Rust ABI
+    .suggestion = remove the `extern` qualifier
+
 typeck_generic_return_type_on_main =
     `main` function return type is not allowed to have generic parameters
 
+typeck_main_return_type_not_termination =
+    `main` has invalid return type `{$return_ty}`
+    .label = `main` can only return types that implement `Termination`
+    .help = consider using `()`, or a `Result`
+    .unsatisfied_bound_note = `{$return_ty}` would implement `Termination`, but the bound `{$unsatisfied_bound}` isn't satisfied
+
 typeck_type_parameter_on_start_function =
     start function is not allowed to have type parameters
     .label = start function cannot have type parameters
@@ -225,6 +304,9 @@ typeck_where_clause_on_start_function =
     start function is not allowed to have a `where` clause
     .label = start function cannot have a `where` clause
 
+typeck_start_function_signature =
+    a start function must have the signature `fn(isize, *const *const u8) -> isize`
+
 typeck_async_start_function =
     `start` is not allowed to be `async`
     .label = `start` is not allowed to be `async`
@@ -259,18 +341,50 @@ typeck_invalid_dispatch_from_dyn_too_many_coerced_fields =
     implementing the `DispatchFromDyn` trait requires multiple coercions
     .note = the trait `DispatchFromDyn` may only be implemented for a coercion between structures with a single field being coerced
     .fields_that_need_coercions_fields = currently, {$coerced_fields_len} fields need coercions: {$coerced_fields}
+    .coerced_field_label = this field needs a coercion
 
 typeck_invalid_dispatch_from_dyn_not_a_struct =
     the trait `DispatchFromDyn` may only be implemented for a coercion between structures
 
+typeck_dispatch_from_dyn_field_implicitly_sized =
+    the trait bound `{$field_ty}: DispatchFromDyn<{$target_ty}>` is not satisfied because of an implicit `Sized` bound
+
+typeck_dispatch_from_dyn_through_pin =
+    the trait `DispatchFromDyn` must be implemented for the pointer inside `Pin`
+    .label = `{$pointer}` must implement `DispatchFromDyn<{$pointer_target}>`
+    .note = `Pin<P>` already implements `DispatchFromDyn<Pin<U>>` whenever `P: DispatchFromDyn<U>`
+    .suggestion = implement `DispatchFromDyn` for the inner pointer type instead
+
 typeck_coerce_unsized_invalid_definition =
-    the trait `CoerceUnsized` may only be implemented for a coercion between structures with the same definition; expected `{$source_path}`, found `{$target_path}
+    the trait `CoerceUnsized` may only be implemented for a coercion between structures with the same definition; expected `{$source_path}`, found `{$target_path}`
+    .source_label = coercion source is `{$source}`
+    .target_label = coercion target is `{$target}`
+    .note = `CoerceUnsized` requires the source and target to be the same struct, differing only in their generic arguments
 
 typeck_coerce_unsized_no_coerced_field =
+    the trait `CoerceUnsized` may only be implemented for a coercion between structures with one field being coerced, none found
+
+typeck_coerce_unsized_unchanged_fields =
+    {$fields_len ->
+        [one] the type of field {$fields} is
+        *[other] the types of fields {$fields} are
+    } the same in `{$source}` and `{$target}`, because both instantiate {$params}
+
+typeck_coerce_unsized_too_many_coerced_fields =
     implementing the trait `CoerceUnsized` requires multiple coercions
     .note = `CoerceUnsized` may only be implemented for a coercion between structures with one field being coerced
     .fields_that_need_coercions_fields = currently, {$coerced_fields_len} fields need coercions: {$coerced_fields}
     .label = requires multiple coercions
+    .coerced_field_label = this field needs a coercion
+
+typeck_coerce_unsized_multi_field_mismatch =
+    fields coerced by a `#[rustc_coerce_unsized_multi_field]` impl must all undergo the same coercion
+    .note = `{$first_field}` is coerced from `{$first_source}` to `{$first_target}`, but `{$field}` is coerced from `{$source}` to `{$target}`
+
+typeck_unreachable_coerce_unsized_impl =
+    this `CoerceUnsized` impl can never be used outside of the current crate
+    .label = the coerced field `{$field_name}` is private
+    .note = `{$self_ty}` is not reachable from other crates, so only this crate can perform the coercion
 
 typeck_coerce_unsized_not_a_struct =
     the trait `CoerceUnsized` may only be implemented for a coercion between structures
@@ -282,6 +396,11 @@ typeck_explicit_impl_of_internal_structs =
     explicit impls for the `{$trait_name}` trait are not permitted
     .label = impl of `{$trait_name}` not allowed
 
+typeck_explicit_impl_of_compiler_implemented_trait =
+    explicit impls for the `{$trait_name}` trait are not permitted
+    .label = impl of `{$trait_name}` not allowed
+    .note = `{$trait_name}` is implemented by the compiler for every type it applies to
+
 typeck_marker_trait_impl_contains_items =
     impls for marker traits cannot contain items
 
@@ -298,3 +417,98 @@ typeck_cross_crate_opt_out_trait_impl_on_invalid_target =
                 [cross_crate] can't implement cross-crate trait for type in another crate
                 *[invalid_type] can't implement cross-crate trait with a default impl for non-struct/enum type
             }
+    .self_ty_label = { $error_type ->
+                [cross_crate] `{$self_type}` is not defined in the current crate
+                *[invalid_type] `{$self_type}` is not a struct or enum
+            }
+
+typeck_suspicious_auto_trait_impl =
+    cross-crate traits with a default impl, like `{$trait_path}`, should not be specialized
+    .label = { $kind ->
+                [duplicate] `{$arg}` is mentioned multiple times
+                *[not_param] `{$arg}` is not a generic parameter
+            }
+    .note = try using the same sequence of generic parameters as the {$adt_descr} definition
+
+typeck_auto_trait_impl_default =
+    impls of auto traits cannot be default
+    .label = auto trait
+    .default_label = default because of this
+    .suggestion = remove the `default` keyword
+
+typeck_missing_gat_bounds =
+    missing required {$bounds_len ->
+        [one] bound
+        *[other] bounds
+    } on `{$gat}`
+    .suggestion = add the required where {$bounds_len ->
+        [one] clause
+        *[other] clauses
+    }
+    .note = {$bounds_len ->
+        [one] this bound is
+        *[other] these bounds are
+    } currently required to ensure that impls have maximum flexibility
+    .feedback_note = we are soliciting feedback, see issue #87479 <https://github.com/rust-lang/rust/issues/87479> for more information
+
+typeck_impl_trait_in_trait_bound_not_satisfied =
+    the return type `{$hidden_ty}` does not satisfy the bound `{$bound}` on the trait's `impl Trait`
+    .label = this return type must satisfy `{$bound}`
+    .bound_label = required by this bound in the trait
+
+typeck_implicit_sized_bound_note =
+    `{$param_name}` is required to be `Sized` because of its implicit `Sized` bound
+
+typeck_implicit_sized_bound_suggestion =
+    consider relaxing the implicit `Sized` bound on `{$param_name}`
+
+typeck_transparent_non_zero_sized_fields =
+    {$is_variant ->
+        [true] the variant of a transparent
+        *[false] transparent
+    } {$descr} needs at most one non-zero-sized field, but has {$field_count}
+    .label = needs at most one non-zero-sized field, but has {$field_count}
+    .first_field_label = this field is non-zero-sized
+    .extra_field_label = this field is also non-zero-sized
+    .note = all fields of a transparent {$descr} except one must be zero-sized with an alignment of 1
+
+typeck_non_exhaustive_no_effect =
+    `#[non_exhaustive]` has no effect on {$kind}s
+    .suggestion = remove this attribute
+    .label = this {$kind} can't be non-exhaustive
+    .note = `#[non_exhaustive]` can only be applied to structs, enums and enum variants
+
+typeck_target_feature_on_safe_trait_method =
+    `#[target_feature(..)]` cannot be applied to safe trait method
+    .label = cannot be applied to safe trait method
+    .def_label = not an `unsafe` function
+    .trait_method_label = the trait method is declared as safe here
+
+typeck_conflicting_packed_and_align_repr =
+    type has conflicting packed and align representation hints
+    .packed_label = the type is packed here
+    .align_label = ...but aligned here
+    .suggestion = remove the `align` representation hint
+
+typeck_conflicting_packed_repr =
+    type has conflicting packed representation hints
+    .first_label = the type is packed here
+    .label = ...but packed differently here
+    .suggestion = remove the conflicting `packed` representation hint
+
+typeck_const_unstable_intrinsic =
+    `{$name}` is not yet stable as a const intrinsic
+    .label = called in a {$kind}
+    .help = add `#![feature({$feature})]` to the crate attributes to enable
+
+typeck_deref_pattern_unsupported_scrutinee =
+    cannot match `{$ty}` against a string literal
+    .label = this string literal can only match `String` through `Deref`
+    .unsupported_target_note = `{$ty}` dereferences to `str`, but only `String` is supported by deref patterns
+    .wrong_target_note = `{$ty}` dereferences to `{$target}`, not to `str`
+    .no_deref_note = `{$ty}` does not implement `Deref`
+    .help = deref patterns can only match `String`s against string literals, and the contents of `Box`es against any pattern
+
+typeck_impl_trait_in_trait_with_default_body =
+    return-position `impl Trait` in traits is not yet supported in trait methods with a default body
+    .note = only trait methods without a body can return `impl Trait` for now
@@ -43,6 +43,7 @@
     passes => "../locales/en-US/passes.ftl",
     plugin_impl => "../locales/en-US/plugin_impl.ftl",
     privacy => "../locales/en-US/privacy.ftl",
+    trait_selection => "../locales/en-US/trait_selection.ftl",
     typeck => "../locales/en-US/typeck.ftl",
 }
 
@@ -13,8 +13,9 @@
 
 use crate::emitter::{Emitter, HumanReadableErrorType};
 use crate::registry::Registry;
+use crate::snippet::Style;
 use crate::translation::Translate;
-use crate::DiagnosticId;
+use crate::{DiagnosticArg, DiagnosticArgValue, DiagnosticId, DiagnosticMessage};
 use crate::{
     CodeSuggestion, FluentBundle, LazyFallbackBundle, MultiSpan, SpanLabel, SubDiagnostic,
 };
@@ -45,6 +46,7 @@ pub struct JsonEmitter {
     json_rendered: HumanReadableErrorType,
     diagnostic_width: Option<usize>,
     macro_backtrace: bool,
+    diagnostic_records: bool,
 }
 
 impl JsonEmitter {
@@ -69,6 +71,7 @@ pub fn stderr(
             json_rendered,
             diagnostic_width,
             macro_backtrace,
+            diagnostic_records: false,
         }
     }
 
@@ -115,12 +118,17 @@ pub fn new(
             json_rendered,
             diagnostic_width,
             macro_backtrace,
+            diagnostic_records: false,
         }
     }
 
     pub fn ui_testing(self, ui_testing: bool) -> Self {
         Self { ui_testing, ..self }
     }
+
+    pub fn diagnostic_records(self, diagnostic_records: bool) -> Self {
+        Self { diagnostic_records, ..self }
+    }
 }
 
 impl Translate for JsonEmitter {
@@ -219,6 +227,20 @@ struct Diagnostic {
     children: Vec<Diagnostic>,
     /// The message as rustc would render it.
     rendered: Option<String>,
+    /// The untranslated message, only emitted with `-Z json-diagnostic-records` or with
+    /// `--json=diagnostic-rendered-ansi -Z unstable-options`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    record: Option<DiagnosticRecord>,
+}
+
+#[derive(Serialize)]
+struct DiagnosticRecord {
+    /// The Fluent identifier of the message, followed by `.` and the attribute if there is one.
+    /// `None` for messages that aren't translatable.
+    slug: Option<String>,
+    /// The arguments of the diagnostic, sorted by name. Empty for children, which use the
+    /// arguments of their parent.
+    args: Vec<(String, String)>,
 }
 
 #[derive(Serialize)]
@@ -322,6 +344,7 @@ fn from_errors_diagnostic(diag: &crate::Diagnostic, je: &JsonEmitter) -> Diagnos
                 spans: DiagnosticSpan::from_suggestion(sugg, &args, je),
                 children: vec![],
                 rendered: None,
+                record: DiagnosticRecord::new(&[(sugg.msg.clone(), Style::NoStyle)], &[], je),
             }
         });
 
@@ -369,6 +392,7 @@ fn flush(&mut self) -> io::Result<()> {
                 .chain(sugg)
                 .collect(),
             rendered: Some(output),
+            record: DiagnosticRecord::new(&diag.message, diag.args(), je),
         }
     }
 
@@ -389,7 +413,37 @@ fn from_sub_diagnostic(
                 .unwrap_or_else(|| DiagnosticSpan::from_multispan(&diag.span, args, je)),
             children: vec![],
             rendered: None,
+            record: DiagnosticRecord::new(&diag.message, &[], je),
+        }
+    }
+}
+
+impl DiagnosticRecord {
+    fn new(
+        messages: &[(DiagnosticMessage, Style)],
+        args: &[DiagnosticArg<'_>],
+        je: &JsonEmitter,
+    ) -> Option<DiagnosticRecord> {
+        if !je.diagnostic_records {
+            return None;
         }
+        let slug = messages.iter().find_map(|(message, _)| match message {
+            DiagnosticMessage::FluentIdentifier(id, Some(attr)) => Some(format!("{id}.{attr}")),
+            DiagnosticMessage::FluentIdentifier(id, None) => Some(id.to_string()),
+            DiagnosticMessage::Str(_) => None,
+        });
+        let mut args: Vec<_> = args
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    DiagnosticArgValue::Str(s) => s.to_string(),
+                    DiagnosticArgValue::Number(n) => n.to_string(),
+                };
+                (name.to_string(), value)
+            })
+            .collect();
+        args.sort();
+        Some(DiagnosticRecord { slug, args })
     }
 }
 
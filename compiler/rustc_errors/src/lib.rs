@@ -452,6 +452,10 @@ struct HandlerInner {
     ///
     /// [RFC-2383]: https://rust-lang.github.io/rfcs/2383-lint-reasons.html
     fulfilled_expectations: FxHashSet<LintExpectationId>,
+
+    /// Slugs of warnings that should be reported as errors, e.g. `typeck::unused_extern_crate`.
+    /// (rustc: see `-Z deny-slug`)
+    deny_slugs: Vec<String>,
 }
 
 /// A key denoting where from a diagnostic was stashed.
@@ -460,6 +464,7 @@ pub enum StashKey {
     ItemNoType,
     UnderscoreForArrayLengths,
     EarlySyntaxWarning,
+    SelfParamInFreeFn,
 }
 
 fn default_track_diagnostic(_: &Diagnostic) {}
@@ -593,10 +598,17 @@ pub fn with_emitter_and_flags(
                 check_unstable_expect_diagnostics: false,
                 unstable_expect_diagnostics: Vec::new(),
                 fulfilled_expectations: Default::default(),
+                deny_slugs: Vec::new(),
             }),
         }
     }
 
+    /// Reports warnings whose primary message has one of the given slugs as errors.
+    pub fn with_deny_slugs(mut self, deny_slugs: Vec<String>) -> Self {
+        self.inner.get_mut().deny_slugs = deny_slugs;
+        self
+    }
+
     // This is here to not allow mutation of flags;
     // as of this writing it's only used in tests in librustc_middle.
     pub fn can_emit_warnings(&self) -> bool {
@@ -1171,6 +1183,10 @@ fn emit_diagnostic(&mut self, diagnostic: &mut Diagnostic) -> Option<ErrorGuaran
             self.fulfilled_expectations.insert(expectation_id);
         }
 
+        if matches!(diagnostic.level, Warning(_)) && self.is_denied_slug(diagnostic) {
+            diagnostic.level = Level::Error { lint: diagnostic.is_lint };
+        }
+
         if matches!(diagnostic.level, Warning(_))
             && !self.flags.can_emit_warnings
             && !diagnostic.is_force_warn()
@@ -1239,6 +1255,16 @@ fn emit_diagnostic(&mut self, diagnostic: &mut Diagnostic) -> Option<ErrorGuaran
         }
     }
 
+    /// Whether the primary message of `diagnostic` is a Fluent message whose slug was passed to
+    /// `-Z deny-slug`, either as `typeck::unused_extern_crate` or `typeck_unused_extern_crate`.
+    fn is_denied_slug(&self, diagnostic: &Diagnostic) -> bool {
+        let Some((DiagnosticMessage::FluentIdentifier(id, None), _)) = diagnostic.message.first()
+        else {
+            return false;
+        };
+        self.deny_slugs.iter().any(|slug| slug.replace("::", "_") == **id)
+    }
+
     fn emit_artifact_notification(&mut self, path: &Path, artifact_type: &str) {
         self.emitter.emit_artifact_notification(path, artifact_type);
     }
@@ -310,6 +310,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, associated_type_defaults, "1.2.0", Some(29661), None),
     /// Allows `async || body` closures.
     (active, async_closure, "1.37.0", Some(62290), None),
+    /// Allows `async fn` in trait definitions and trait impls.
+    (incomplete, async_fn_in_trait, "1.65.0", Some(91611), None),
     /// Allows `extern "C-unwind" fn` to enable unwinding across ABI boundaries.
     (active, c_unwind, "1.52.0", Some(74990), None),
     /// Allows using C-variadics.
@@ -370,6 +372,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, default_type_parameter_fallback, "1.3.0", Some(27336), None),
     /// Allows using `#[deprecated_safe]` to deprecate the safeness of a function or trait
     (active, deprecated_safe, "1.61.0", Some(94978), None),
+    /// Allows matching on `String`s and `Box`es through `Deref` in patterns.
+    (incomplete, deref_patterns, "1.65.0", None, None),
     /// Allows having using `suggestion` in the `#[deprecated]` attribute.
     (active, deprecated_suggestion, "1.61.0", Some(94785), None),
     /// Tells rustdoc to automatically generate `#[doc(cfg(...))]`.
@@ -408,6 +412,10 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, half_open_range_patterns, "1.41.0", Some(67264), None),
     /// Allows `if let` guard in match arms.
     (active, if_let_guard, "1.47.0", Some(51114), None),
+    // no-tracking-issue-start
+    /// Allows `impl Trait` in the types of statics and constants, including associated constants.
+    (active, impl_trait_in_consts, "1.65.0", None, None),
+    // no-tracking-issue-end
     /// Allows using imported `main` function
     (active, imported_main, "1.53.0", Some(28937), None),
     /// Allows associated types in inherent impls.
@@ -489,6 +497,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (incomplete, repr128, "1.16.0", Some(56071), None),
     /// Allows `repr(simd)` and importing the various simd intrinsics.
     (active, repr_simd, "1.4.0", Some(27731), None),
+    /// Allows return-position `impl Trait` in traits.
+    (incomplete, return_position_impl_trait_in_trait, "1.65.0", Some(91611), None),
     /// Allows `extern "rust-cold"`.
     (active, rust_cold_cc, "1.63.0", Some(97544), None),
     /// Allows the use of SIMD types in functions declared in `extern` blocks.
@@ -521,6 +531,11 @@ pub fn set(&self, features: &mut Features, span: Span) {
     /// Allows creation of instances of a struct by moving fields that have
     /// not changed from prior instances of the same struct (RFC #2528)
     (active, type_changing_struct_update, "1.58.0", Some(86555), None),
+    /// Allows `typeof(expr)` to be used as the type of `expr`.
+    (incomplete, typeof_expr, "1.65.0", None, None),
+    /// Allows `#[unix_sigpipe]` on `fn main()`. The value is validated but doesn't yet change
+    /// how `SIGPIPE` is set up.
+    (incomplete, unix_sigpipe, "1.65.0", Some(97889), None),
     /// Allows unsized fn parameters.
     (active, unsized_fn_params, "1.49.0", Some(48055), None),
     /// Allows unsized rvalues at arguments and parameters.
@@ -478,6 +478,10 @@ pub struct BuiltinAttribute {
         `impls` and all default bodies as `const`, which may be removed or renamed in the \
         future."
     ),
+    gated!(
+        unix_sigpipe, Normal, template!(NameValueStr: "inherit|sig_ign|sig_dfl"),
+        ErrorFollowing, experimental!(unix_sigpipe)
+    ),
     // lang-team MCP 147
     gated!(
         deprecated_safe, Normal, template!(List: r#"since = "version", note = "...""#), ErrorFollowing,
@@ -612,6 +616,11 @@ pub struct BuiltinAttribute {
     rustc_attr!(
         rustc_conversion_suggestion, Normal, template!(Word), WarnFollowing, INTERNAL_UNSTABLE
     ),
+    // Opts values of the annotated type out of the listed families of typeck suggestions.
+    rustc_attr!(
+        rustc_do_not_suggest, Normal, template!(List: "borrow, deref, clone"), ErrorFollowing,
+        INTERNAL_UNSTABLE
+    ),
     // Prevents field reads in the marked trait or method to be considered
     // during dead code analysis.
     rustc_attr!(
@@ -738,6 +747,16 @@ pub struct BuiltinAttribute {
         rustc_unsafe_specialization_marker, Normal, template!(Word), WarnFollowing,
         "the `#[rustc_unsafe_specialization_marker]` attribute is used to check specializations"
     ),
+    rustc_attr!(
+        rustc_coerce_unsized_multi_field, Normal, template!(Word), WarnFollowing,
+        "the `#[rustc_coerce_unsized_multi_field]` attribute allows a `CoerceUnsized` impl \
+        to coerce several fields, as long as they all undergo the same coercion"
+    ),
+    rustc_attr!(
+        rustc_deny_explicit_impl, Normal, template!(Word), ErrorFollowing,
+        "the `#[rustc_deny_explicit_impl]` attribute marks traits that are implemented \
+        by the compiler and may not be implemented explicitly"
+    ),
     rustc_attr!(
         rustc_specialization_trait, Normal, template!(Word), WarnFollowing,
         "the `#[rustc_specialization_trait]` attribute is used to check specializations"
@@ -2495,6 +2495,8 @@ pub fn from_name(name: Symbol) -> Option<Self> {
 pub struct BareFnTy<'hir> {
     pub unsafety: Unsafety,
     pub abi: Abi,
+    /// The span of the ABI string, if one was written.
+    pub abi_span: Option<Span>,
     pub generic_params: &'hir [GenericParam<'hir>],
     pub decl: &'hir FnDecl<'hir>,
     pub param_names: &'hir [Ident],
@@ -2505,6 +2507,9 @@ pub struct OpaqueTy<'hir> {
     pub generics: &'hir Generics<'hir>,
     pub bounds: GenericBounds<'hir>,
     pub origin: OpaqueTyOrigin,
+    /// Whether this is a return-position `impl Trait` of a trait method, which is
+    /// turned into a projection rather than into an opaque type.
+    pub in_trait: bool,
 }
 
 /// From whence the opaque type came.
@@ -2993,7 +2998,7 @@ pub enum ItemKind<'hir> {
     /// A module.
     Mod(Mod<'hir>),
     /// An external module, e.g. `extern { .. }`.
-    ForeignMod { abi: Abi, items: &'hir [ForeignItemRef] },
+    ForeignMod { abi: Abi, abi_span: Option<Span>, items: &'hir [ForeignItemRef] },
     /// Module-level inline assembly (from `global_asm!`).
     GlobalAsm(&'hir InlineAsm<'hir>),
     /// A type alias, e.g., `type Foo = Bar<u8>`.
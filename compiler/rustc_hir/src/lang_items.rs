@@ -113,6 +113,11 @@ pub fn items(&self) -> &[Option<DefId>] {
                 &*self.items
             }
 
+            /// Returns the [`DefId`] of the given lang item if it is defined.
+            pub fn get(&self, it: LangItem) -> Option<DefId> {
+                self.items[it as usize]
+            }
+
             /// Requires that a given `LangItem` was bound and returns the corresponding `DefId`.
             /// If it wasn't bound, e.g. due to a missing `#[lang = "<it.name()>"]`,
             /// returns an error message as a string.
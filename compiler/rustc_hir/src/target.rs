@@ -102,7 +102,7 @@ pub fn from_def_kind(def_kind: DefKind) -> Target {
             DefKind::ForeignMod => Target::ForeignMod,
             DefKind::GlobalAsm => Target::GlobalAsm,
             DefKind::TyAlias => Target::TyAlias,
-            DefKind::OpaqueTy => Target::OpaqueTy,
+            DefKind::OpaqueTy | DefKind::ImplTraitPlaceholder => Target::OpaqueTy,
             DefKind::Enum => Target::Enum,
             DefKind::Struct => Target::Struct,
             DefKind::Union => Target::Union,
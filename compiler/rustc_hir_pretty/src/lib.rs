@@ -570,7 +570,7 @@ pub fn print_item(&mut self, item: &hir::Item<'_>) {
                 self.print_mod(_mod, attrs);
                 self.bclose(item.span);
             }
-            hir::ItemKind::ForeignMod { abi, items } => {
+            hir::ItemKind::ForeignMod { abi, items, .. } => {
                 self.head("extern");
                 self.word_nbsp(abi.to_string());
                 self.bopen();
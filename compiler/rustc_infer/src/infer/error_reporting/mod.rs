@@ -67,6 +67,7 @@
 use rustc_middle::dep_graph::DepContext;
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::relate::{self, RelateResult, TypeRelation};
+use rustc_middle::ty::subst::GenericArgKind;
 use rustc_middle::ty::{
     self, error::TypeError, Binder, List, Region, Subst, Ty, TyCtxt, TypeFoldable,
     TypeSuperVisitable, TypeVisitable,
@@ -114,6 +115,50 @@ pub(super) fn note_and_explain_region<'tcx>(
     };
 
     emit_msg_span(err, prefix, description, span, suffix);
+    note_shadowed_lifetime_name(tcx, err, region);
+}
+
+/// Nested items can declare a lifetime with the same name as one of an enclosing item, even
+/// though the outer lifetime isn't in scope inside of them. When an error mentions such a name,
+/// point at both declarations to make it clear which one is meant.
+fn note_shadowed_lifetime_name<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    err: &mut Diagnostic,
+    region: ty::Region<'tcx>,
+) {
+    let name = match *region {
+        ty::ReEarlyBound(br) if br.has_name() => br.name,
+        ty::ReFree(ty::FreeRegion { bound_region: ty::BoundRegionKind::BrNamed(_, name), .. })
+            if name != kw::UnderscoreLifetime =>
+        {
+            name
+        }
+        _ => return,
+    };
+    let Some(scope) = region.free_region_binding_scope(tcx).as_local() else { return };
+    let hir = tcx.hir();
+    let Some(param) = hir.get_generics(scope).and_then(|generics| generics.get_named(name)) else {
+        return;
+    };
+    let Some((outer_def_id, outer_param)) =
+        hir.parent_owner_iter(hir.local_def_id_to_hir_id(scope)).find_map(|(def_id, node)| {
+            Some((def_id, node.generics()?.get_named(name)?))
+        })
+    else {
+        return;
+    };
+
+    let mut span: MultiSpan = param.span.into();
+    span.push_span_label(param.span, format!("the `{name}` meant here"));
+    span.push_span_label(outer_param.span, format!("this `{name}` is a different lifetime"));
+    err.span_note_once(
+        span,
+        &format!(
+            "the lifetime name `{name}` is also declared on the enclosing {}, \
+             but it refers to a different lifetime",
+            tcx.def_kind(outer_def_id).descr(outer_def_id.to_def_id()),
+        ),
+    );
 }
 
 fn explain_free_region<'tcx>(
@@ -240,7 +285,8 @@ pub fn unexpected_hidden_region_diagnostic<'tcx>(
     hidden_region: ty::Region<'tcx>,
     opaque_ty: ty::OpaqueTypeKey<'tcx>,
 ) -> DiagnosticBuilder<'tcx, ErrorGuaranteed> {
-    let opaque_ty = tcx.mk_opaque(opaque_ty.def_id.to_def_id(), opaque_ty.substs);
+    let opaque_def_id = opaque_ty.def_id.to_def_id();
+    let opaque_ty = tcx.mk_opaque(opaque_def_id, opaque_ty.substs);
     let mut err = struct_span_err!(
         tcx.sess,
         span,
@@ -316,6 +362,26 @@ pub fn unexpected_hidden_region_diagnostic<'tcx>(
         }
     }
 
+    // Member constraints can only pick a lifetime from the bounds for the captured one if the
+    // hidden type may be shortened to it, which an invariant use of the lifetime rules out. A
+    // hidden type mentioning the opaque type itself is reported elsewhere, and looking through it
+    // would require the hidden type we are still inferring.
+    let mentions_opaque_ty = hidden_ty.walk().any(|arg| {
+        let GenericArgKind::Type(ty) = arg.unpack() else { return false };
+        matches!(*ty.kind(), ty::Opaque(def_id, _) if def_id == opaque_def_id)
+    });
+    if !mentions_opaque_ty
+        && tcx
+            .region_variances_in(hidden_ty)
+            .iter()
+            .any(|&(region, variance)| region == hidden_region && variance == ty::Invariant)
+    {
+        err.note(&format!(
+            "`{hidden_ty}` is invariant over `{hidden_region}`, so `{hidden_region}` cannot be \
+             shortened to a lifetime in the bounds"
+        ));
+    }
+
     err
 }
 
@@ -736,8 +802,9 @@ fn note_error_origin(
                 }
             }
             ObligationCauseCode::LetElse => {
-                err.help("try adding a diverging expression, such as `return` or `panic!(..)`");
-                err.help("...or use `match` instead of `let...else`");
+                err.help(
+                    "if the `else` block should not diverge, use `match` instead of `let...else`",
+                );
             }
             _ => {
                 if let ObligationCauseCode::BindingObligation(_, span)
@@ -2421,7 +2488,7 @@ enum SubOrigin<'hir> {
             GAT(&'hir hir::Generics<'hir>),
             Impl,
             Trait,
-            Fn,
+            Fn(&'hir hir::Generics<'hir>),
             Unknown,
         }
         let sub_origin = 'origin: {
@@ -2444,15 +2511,18 @@ enum SubOrigin<'hir> {
                                     }) => SubOrigin::GAT(generics),
                                     Node::ImplItem(hir::ImplItem {
                                         kind: hir::ImplItemKind::Fn(..),
+                                        generics,
                                         ..
                                     })
                                     | Node::TraitItem(hir::TraitItem {
                                         kind: hir::TraitItemKind::Fn(..),
+                                        generics,
                                         ..
                                     })
                                     | Node::Item(hir::Item {
-                                        kind: hir::ItemKind::Fn(..), ..
-                                    }) => SubOrigin::Fn,
+                                        kind: hir::ItemKind::Fn(_, generics, _),
+                                        ..
+                                    }) => SubOrigin::Fn(generics),
                                     Node::Item(hir::Item {
                                         kind: hir::ItemKind::Trait(..),
                                         ..
@@ -2473,6 +2543,15 @@ enum SubOrigin<'hir> {
         };
         debug!(?sub_origin);
 
+        // Whether the failing bound was required by a specific where clause, and the type
+        // parameter it is about belongs to the impl or trait surrounding the function.
+        let required_of_parent_param = match (&origin, bound_kind) {
+            (Some(infer::RelateParamBound(_, _, Some(_))), GenericKind::Param(param)) => {
+                (param.index as usize) < generics.parent_count
+            }
+            _ => false,
+        };
+
         let mut err = match (*sub, sub_origin) {
             // In the case of GATs, we have to be careful. If we a type parameter `T` on an impl,
             // but a lifetime `'a` on an associated type, then we might need to suggest adding
@@ -2496,6 +2575,28 @@ enum SubOrigin<'hir> {
                 );
                 err
             }
+            // A lifetime of the function can't be named in the generics of the surrounding
+            // impl or trait, so the bound has to be added to the function's where clause.
+            (ty::ReEarlyBound(ty::EarlyBoundRegion { name, .. }), SubOrigin::Fn(fn_generics))
+                if name != kw::UnderscoreLifetime && required_of_parent_param =>
+            {
+                let mut err = struct_span_err!(
+                    self.tcx.sess,
+                    span,
+                    E0309,
+                    "{} may not live long enough",
+                    labeled_user_string
+                );
+                let pred = format!("{}: {}", bound_kind, name);
+                let suggestion = format!("{} {}", fn_generics.add_where_or_trailing_comma(), pred);
+                err.span_suggestion_verbose(
+                    fn_generics.tail_span_for_predicate_suggestion(),
+                    "consider adding a where clause",
+                    suggestion,
+                    Applicability::MaybeIncorrect,
+                );
+                err
+            }
             (
                 ty::ReEarlyBound(ty::EarlyBoundRegion { name, .. })
                 | ty::ReFree(ty::FreeRegion { bound_region: ty::BrNamed(_, name), .. }),
@@ -372,16 +372,35 @@ pub fn emit_inference_failure_err(
                     Applicability::HasPlaceholders,
                 );
             }
-            InferSourceKind::ClosureArg { insert_span, ty } => {
-                err.span_suggestion_verbose(
-                    insert_span,
-                    &format!(
-                        "consider giving this closure parameter an explicit type{}",
-                        arg_data.where_x_is_specified(ty)
-                    ),
-                    format!(": {}", ty_to_string(self, ty)),
-                    Applicability::HasPlaceholders,
-                );
+            InferSourceKind::ClosureArg { insert_span, ty, unannotated_params } => {
+                if unannotated_params.len() > 1 {
+                    // Annotate every parameter of the closure at once, using what we
+                    // were able to infer so far and `_` for everything else.
+                    let suggestion = unannotated_params
+                        .iter()
+                        .map(|&(span, param_ty)| {
+                            (span, format!(": {}", ty_to_string(self, param_ty)))
+                        })
+                        .collect();
+                    err.multipart_suggestion_verbose(
+                        &format!(
+                            "consider giving the closure parameters explicit types{}",
+                            arg_data.where_x_is_specified(ty)
+                        ),
+                        suggestion,
+                        Applicability::HasPlaceholders,
+                    );
+                } else {
+                    err.span_suggestion_verbose(
+                        insert_span,
+                        &format!(
+                            "consider giving this closure parameter an explicit type{}",
+                            arg_data.where_x_is_specified(ty)
+                        ),
+                        format!(": {}", ty_to_string(self, ty)),
+                        Applicability::HasPlaceholders,
+                    );
+                }
             }
             InferSourceKind::GenericArg {
                 insert_span,
@@ -538,6 +557,9 @@ enum InferSourceKind<'tcx> {
     ClosureArg {
         insert_span: Span,
         ty: Ty<'tcx>,
+        /// The insertion point and type of every parameter of the closure which
+        /// does not have an explicit type annotation.
+        unannotated_params: Vec<(Span, Ty<'tcx>)>,
     },
     GenericArg {
         insert_span: Span,
@@ -1006,6 +1028,7 @@ fn visit_local(&mut self, local: &'tcx Local<'tcx>) {
     /// For closures, we first visit the parameters and then the content,
     /// as we prefer those.
     fn visit_body(&mut self, body: &'tcx Body<'tcx>) {
+        let mut unannotated_params = vec![];
         for param in body.params {
             debug!(
                 "param: span {:?}, ty_span {:?}, pat.span {:?}",
@@ -1019,13 +1042,21 @@ fn visit_body(&mut self, body: &'tcx Body<'tcx>) {
             let Some(param_ty) = self.opt_node_type(param.hir_id) else {
                 continue
             };
+            unannotated_params.push((param, param_ty));
+        }
 
+        let suggested_params: Vec<_> = unannotated_params
+            .iter()
+            .map(|&(param, param_ty)| (param.pat.span.shrink_to_hi(), param_ty))
+            .collect();
+        for &(param, param_ty) in &unannotated_params {
             if self.generic_arg_contains_target(param_ty.into()) {
                 self.update_infer_source(InferSource {
                     span: param.pat.span,
                     kind: InferSourceKind::ClosureArg {
                         insert_span: param.pat.span.shrink_to_hi(),
                         ty: param_ty,
+                        unannotated_params: suggested_params.clone(),
                     },
                 })
             }
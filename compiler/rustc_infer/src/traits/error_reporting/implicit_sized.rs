@@ -0,0 +1,101 @@
+//! Explanations for requirements that only exist because of an implicit `Sized` bound.
+//!
+//! Type parameters of impls are `Sized` unless they opt out with `?Sized`, which is easy to
+//! forget when writing impls for smart pointers. Checks that fail because such an impl doesn't
+//! apply to an unsized type use these helpers to point at the parameter that needs `?Sized`.
+
+use rustc_errors::{fluent, AddSubdiagnostic, Applicability, Diagnostic};
+use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
+use rustc_middle::ty::{self, DefIdTree, Ty, TyCtxt};
+use rustc_span::{Span, Symbol, DUMMY_SP};
+
+/// A type parameter that is only required to be `Sized` because of its implicit `Sized` bound.
+pub struct ImplicitSizedBound {
+    param_span: Span,
+    param_name: Symbol,
+    /// Where `?Sized` should be written, along with the separator that needs to precede it.
+    suggestion: (Span, &'static str),
+}
+
+impl AddSubdiagnostic for ImplicitSizedBound {
+    fn add_to_diagnostic(self, diag: &mut Diagnostic) {
+        let (span, separator) = self.suggestion;
+        diag.set_arg("param_name", self.param_name);
+        diag.span_note(self.param_span, fluent::typeck::implicit_sized_bound_note);
+        diag.span_suggestion_verbose(
+            span,
+            fluent::typeck::implicit_sized_bound_suggestion,
+            format!("{separator} ?Sized"),
+            Applicability::MaybeIncorrect,
+        );
+    }
+}
+
+/// Returns the explanation for `param_def_id` if it is a local type parameter that neither
+/// relaxes its implicit `Sized` bound nor restates it explicitly.
+pub fn implicit_sized_bound(tcx: TyCtxt<'_>, param_def_id: DefId) -> Option<ImplicitSizedBound> {
+    let local_def_id = param_def_id.as_local()?;
+    let hir_id = tcx.hir().local_def_id_to_hir_id(local_def_id);
+    let hir::Node::GenericParam(param) = tcx.hir().get(hir_id) else { return None };
+    let hir::GenericParamKind::Type { synthetic: false, .. } = param.kind else { return None };
+    let generics = tcx.hir().get_generics(tcx.local_parent(local_def_id))?;
+
+    // An explicit `Sized` bound is there intentionally, and `?Sized` means the requirement
+    // comes from somewhere else.
+    let sized_trait = tcx.lang_items().sized_trait();
+    let mentions_sized =
+        generics.bounds_for_param(local_def_id).flat_map(|bp| bp.bounds).any(|bound| match bound {
+            hir::GenericBound::Trait(ptr, _) => ptr.trait_ref.trait_def_id() == sized_trait,
+            _ => false,
+        });
+    if mentions_sized {
+        return None;
+    }
+
+    let suggestion = match generics.bounds_span_for_suggestions(local_def_id) {
+        Some(span) => (span, " +"),
+        None => (param.span.shrink_to_hi(), ":"),
+    };
+    Some(ImplicitSizedBound {
+        param_span: param.span,
+        param_name: param.name.ident().name,
+        suggestion,
+    })
+}
+
+/// Looks for a local impl of `trait_def_id` for the ADT `ty` that doesn't apply only because
+/// one of its parameters, instantiated with a type that isn't `Sized` in `param_env`, is
+/// implicitly `Sized`. `impl_arg` selects which type of the impl's trait reference (`0` being
+/// the self type) is matched against `ty`.
+pub fn implicitly_sized_impl_param<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    trait_def_id: DefId,
+    impl_arg: usize,
+    ty: Ty<'tcx>,
+) -> Option<ImplicitSizedBound> {
+    let ty::Adt(def, substs) = *ty.kind() else { return None };
+    let unsized_args: Vec<_> = substs
+        .types()
+        .enumerate()
+        .filter(|&(_, arg)| !arg.is_sized(tcx.at(DUMMY_SP), param_env))
+        .map(|(i, _)| i)
+        .collect();
+    if unsized_args.is_empty() {
+        return None;
+    }
+
+    tcx.hir().trait_impls(trait_def_id).iter().find_map(|&impl_def_id| {
+        let impl_ty = tcx.impl_trait_ref(impl_def_id)?.substs.type_at(impl_arg);
+        let ty::Adt(impl_def, impl_substs) = *impl_ty.kind() else { return None };
+        if impl_def != def {
+            return None;
+        }
+        unsized_args.iter().find_map(|&i| {
+            let ty::Param(param) = *impl_substs.types().nth(i)?.kind() else { return None };
+            let param_def_id = tcx.generics_of(impl_def_id).type_param(&param, tcx).def_id;
+            implicit_sized_bound(tcx, param_def_id)
+        })
+    })
+}
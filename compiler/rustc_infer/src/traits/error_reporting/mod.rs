@@ -1,11 +1,17 @@
-use super::ObjectSafetyViolation;
+pub mod implicit_sized;
 
+use super::{MethodViolationCode, ObjectSafetyViolation};
+
+use self::implicit_sized::implicitly_sized_impl_param;
 use crate::infer::InferCtxt;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_errors::{struct_span_err, DiagnosticBuilder, ErrorGuaranteed, MultiSpan};
+use rustc_errors::{
+    struct_span_err, AddSubdiagnostic, Applicability, Diagnostic, DiagnosticBuilder,
+    ErrorGuaranteed, MultiSpan,
+};
 use rustc_hir as hir;
 use rustc_hir::def_id::{DefId, LocalDefId};
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{self, TyCtxt};
 use rustc_span::Span;
 use std::fmt;
 use std::iter;
@@ -99,10 +105,132 @@ pub fn report_object_safety_error<'tcx>(
     if trait_span.is_some() {
         let mut reported_violations: Vec<_> = reported_violations.into_iter().collect();
         reported_violations.sort();
+        // The implicit `Sized` explanation is translated when the error is emitted, so only one
+        // of them can be added without their `param_name` arguments clashing.
+        let mut explained_implicit_sized = false;
         for violation in reported_violations {
             // Only provide the help if its a local trait, otherwise it's not actionable.
             violation.solution(&mut err);
+            suggest_sized_self_bound(tcx, trait_def_id, &violation, &mut err);
+            note_missing_dispatch_from_dyn(
+                tcx,
+                trait_def_id,
+                &violation,
+                &mut explained_implicit_sized,
+                &mut err,
+            );
         }
     }
     err
 }
+
+/// Suggests a `where Self: Sized` bound on a method that keeps its trait from being object safe,
+/// which makes the method unavailable on trait objects instead.
+fn suggest_sized_self_bound<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    trait_def_id: DefId,
+    violation: &ObjectSafetyViolation,
+    err: &mut Diagnostic,
+) {
+    let ObjectSafetyViolation::Method(name, code, _) = violation else { return };
+    if !matches!(
+        code,
+        MethodViolationCode::ReferencesSelfInput(_)
+            | MethodViolationCode::ReferencesSelfOutput
+            | MethodViolationCode::WhereClauseReferencesSelf
+            | MethodViolationCode::Generic
+    ) {
+        return;
+    }
+    let Some(method) = tcx
+        .associated_items(trait_def_id)
+        .filter_by_name_unhygienic(*name)
+        .find(|item| item.kind == ty::AssocKind::Fn)
+    else {
+        return;
+    };
+    let Some(hir::Node::TraitItem(hir::TraitItem { generics, .. })) =
+        tcx.hir().get_if_local(method.def_id)
+    else {
+        return;
+    };
+    err.span_suggestion(
+        generics.tail_span_for_predicate_suggestion(),
+        &format!(
+            "alternatively, consider constraining `{name}` so it does not apply to trait objects"
+        ),
+        format!("{} Self: Sized", generics.add_where_or_trailing_comma()),
+        Applicability::MachineApplicable,
+    );
+}
+
+/// Points out that a custom pointer used as a method receiver, like `self: MyPtr<Self>`, needs a
+/// `DispatchFromDyn` impl for the method to be callable on a trait object, or that its impl
+/// doesn't apply because of an implicit `Sized` bound.
+fn note_missing_dispatch_from_dyn<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    trait_def_id: DefId,
+    violation: &ObjectSafetyViolation,
+    explained_implicit_sized: &mut bool,
+    err: &mut Diagnostic,
+) {
+    let ObjectSafetyViolation::Method(name, MethodViolationCode::UndispatchableReceiver(_), _) =
+        violation
+    else {
+        return;
+    };
+    let Some(dispatch_from_dyn_trait) = tcx.lang_items().dispatch_from_dyn_trait() else {
+        return;
+    };
+    let Some(method) = tcx
+        .associated_items(trait_def_id)
+        .filter_by_name_unhygienic(*name)
+        .find(|item| item.kind == ty::AssocKind::Fn)
+    else {
+        return;
+    };
+    let receiver_ty = tcx.fn_sig(method.def_id).input(0).skip_binder();
+    let ty::Adt(def, substs) = *receiver_ty.kind() else { return };
+    // Only look at receivers that wrap `Self` directly; `self: &Rc<Self>` and friends are
+    // undispatchable because of the extra level of indirection, not because of a missing impl.
+    if !substs.types().any(|ty| ty == tcx.types.self_param) {
+        return;
+    }
+    let has_impl = tcx.all_impls(dispatch_from_dyn_trait).any(|impl_def_id| {
+        matches!(*tcx.type_of(impl_def_id).kind(), ty::Adt(impl_def, _) if impl_def == def)
+    });
+    if has_impl {
+        if *explained_implicit_sized {
+            return;
+        }
+        // Neither `Self` nor the trait object it is coerced to are `Sized`.
+        let param_env = tcx.param_env(trait_def_id);
+        let implicit_sized = [0, 1].into_iter().find_map(|impl_arg| {
+            implicitly_sized_impl_param(
+                tcx,
+                param_env,
+                dispatch_from_dyn_trait,
+                impl_arg,
+                receiver_ty,
+            )
+        });
+        if let Some(implicit_sized) = implicit_sized {
+            implicit_sized.add_to_diagnostic(err);
+            *explained_implicit_sized = true;
+        }
+        return;
+    }
+    let ptr = tcx.def_path_str(def.did());
+    let mut note_span = MultiSpan::from_span(tcx.def_span(def.did()));
+    note_span.push_span_label(
+        tcx.def_span(def.did()),
+        format!("`{ptr}` does not implement `DispatchFromDyn`"),
+    );
+    err.span_note(
+        note_span,
+        &format!(
+            "`{receiver_ty}` can only be used as the receiver of a trait object method if \
+             `{ptr}<T>` implements `DispatchFromDyn<{ptr}<U>>` where `T: Unsize<U>`"
+        ),
+    );
+}
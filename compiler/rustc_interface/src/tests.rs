@@ -664,6 +664,7 @@ macro_rules! untracked {
     untracked!(incremental_info, true);
     untracked!(incremental_verify_ich, true);
     untracked!(input_stats, true);
+    untracked!(json_diagnostic_records, true);
     untracked!(keep_hygiene_data, true);
     untracked!(link_native_libraries, false);
     untracked!(llvm_time_trace, true);
@@ -734,7 +735,10 @@ macro_rules! tracked {
     tracked!(crate_attr, vec!["abc".to_string()]);
     tracked!(debug_info_for_profiling, true);
     tracked!(debug_macros, true);
+    tracked!(deny_slug, vec![String::from("typeck::unused_extern_crate")]);
     tracked!(dep_info_omit_d_target, true);
+    tracked!(design_hints, true);
+    tracked!(diag_no_budget, true);
     tracked!(drop_tracking, true);
     tracked!(export_executable_symbols, true);
     tracked!(dual_proc_macros, true);
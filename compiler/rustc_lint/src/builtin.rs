@@ -1132,9 +1132,12 @@ fn check_item(&mut self, cx: &EarlyContext<'_>, item: &ast::Item) {
     ///
     /// A function with generics must have its symbol mangled to accommodate
     /// the generic parameter. The [`no_mangle` attribute] has no effect in
-    /// this situation, and should be removed.
+    /// this situation, and should be removed. The same goes for the
+    /// [`export_name` attribute], which would give every instance of the
+    /// function the same symbol.
     ///
     /// [`no_mangle` attribute]: https://doc.rust-lang.org/reference/abi.html#the-no_mangle-attribute
+    /// [`export_name` attribute]: https://doc.rust-lang.org/reference/abi.html#the-export_name-attribute
     NO_MANGLE_GENERIC_ITEMS,
     Warn,
     "generic items must be mangled"
@@ -1145,36 +1148,47 @@ fn check_item(&mut self, cx: &EarlyContext<'_>, item: &ast::Item) {
 impl<'tcx> LateLintPass<'tcx> for InvalidNoMangleItems {
     fn check_item(&mut self, cx: &LateContext<'_>, it: &hir::Item<'_>) {
         let attrs = cx.tcx.hir().attrs(it.hir_id());
+        // Both `#[no_mangle]` and `#[export_name]` give every instance of the function the same
+        // symbol.
+        let find_unmangled_attr = |attrs: &[ast::Attribute]| {
+            cx.sess()
+                .find_by_name(attrs, sym::no_mangle)
+                .or_else(|| cx.sess().find_by_name(attrs, sym::export_name))
+        };
         let check_no_mangle_on_generic_fn = |no_mangle_attr: &ast::Attribute,
                                              impl_generics: Option<&hir::Generics<'_>>,
                                              generics: &hir::Generics<'_>,
                                              span| {
-            for param in
-                generics.params.iter().chain(impl_generics.map(|g| g.params).into_iter().flatten())
-            {
-                match param.kind {
-                    GenericParamKind::Lifetime { .. } => {}
-                    GenericParamKind::Type { .. } | GenericParamKind::Const { .. } => {
-                        cx.struct_span_lint(NO_MANGLE_GENERIC_ITEMS, span, |lint| {
-                            lint.build(fluent::lint::builtin_no_mangle_generic)
-                                .span_suggestion_short(
-                                    no_mangle_attr.span,
-                                    fluent::lint::suggestion,
-                                    "",
-                                    // Use of `#[no_mangle]` suggests FFI intent; correct
-                                    // fix may be to monomorphize source by hand
-                                    Applicability::MaybeIncorrect,
-                                )
-                                .emit();
-                        });
-                        break;
-                    }
-                }
+            let generic_params: Vec<Span> = generics
+                .params
+                .iter()
+                .chain(impl_generics.map(|g| g.params).into_iter().flatten())
+                .filter(|param| !matches!(param.kind, GenericParamKind::Lifetime { .. }))
+                .map(|param| param.span)
+                .collect();
+            if generic_params.is_empty() {
+                return;
             }
+            cx.struct_span_lint(NO_MANGLE_GENERIC_ITEMS, span, |lint| {
+                let mut err = lint.build(fluent::lint::builtin_no_mangle_generic);
+                for param in generic_params {
+                    err.span_label(param, fluent::lint::label);
+                }
+                err.note(fluent::lint::note)
+                    .span_suggestion_short(
+                        no_mangle_attr.span,
+                        fluent::lint::suggestion,
+                        "",
+                        // Use of `#[no_mangle]` suggests FFI intent; correct
+                        // fix may be to monomorphize source by hand
+                        Applicability::MaybeIncorrect,
+                    )
+                    .emit();
+            });
         };
         match it.kind {
             hir::ItemKind::Fn(.., ref generics, _) => {
-                if let Some(no_mangle_attr) = cx.sess().find_by_name(attrs, sym::no_mangle) {
+                if let Some(no_mangle_attr) = find_unmangled_attr(attrs) {
                     check_no_mangle_on_generic_fn(no_mangle_attr, None, generics, it.span);
                 }
             }
@@ -1208,9 +1222,8 @@ fn check_item(&mut self, cx: &LateContext<'_>, it: &hir::Item<'_>) {
             hir::ItemKind::Impl(hir::Impl { generics, items, .. }) => {
                 for it in *items {
                     if let hir::AssocItemKind::Fn { .. } = it.kind {
-                        if let Some(no_mangle_attr) = cx
-                            .sess()
-                            .find_by_name(cx.tcx.hir().attrs(it.id.hir_id()), sym::no_mangle)
+                        if let Some(no_mangle_attr) =
+                            find_unmangled_attr(cx.tcx.hir().attrs(it.id.hir_id()))
                         {
                             check_no_mangle_on_generic_fn(
                                 no_mangle_attr,
@@ -2,7 +2,7 @@
 use rustc_ast as ast;
 use rustc_attr as attr;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_errors::{fluent, Applicability, DiagnosticMessage};
+use rustc_errors::{fluent, Applicability, DecorateLint, DiagnosticMessage, LintDiagnosticBuilder};
 use rustc_hir as hir;
 use rustc_hir::{is_range_literal, Expr, ExprKind, Node};
 use rustc_macros::LintDiagnostic;
@@ -10,7 +10,7 @@
 use rustc_middle::ty::subst::SubstsRef;
 use rustc_middle::ty::{self, AdtKind, DefIdTree, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable};
 use rustc_span::source_map;
-use rustc_span::symbol::sym;
+use rustc_span::symbol::{kw, sym, Ident};
 use rustc_span::{Span, Symbol, DUMMY_SP};
 use rustc_target::abi::{Abi, WrappingRange};
 use rustc_target::abi::{Integer, TagEncoding, Variants};
@@ -658,6 +658,57 @@ struct ImproperCTypesVisitor<'a, 'tcx> {
     mode: CItemKind,
 }
 
+/// Where in a foreign signature a type checked for FFI-safety appears.
+#[derive(Clone, Copy)]
+enum FfiPosition {
+    /// A parameter, along with its name unless it is `_` or a pattern.
+    Param(Option<Ident>),
+    Return,
+    Static,
+}
+
+/// The `improper_ctypes` and `improper_ctypes_definitions` diagnostic for one parameter, return
+/// type or static, pointing at its type and explaining why `ty`, which is or is part of that type,
+/// is not FFI-safe.
+struct ImproperCTypes<'tcx> {
+    ty: Ty<'tcx>,
+    desc: &'static str,
+    label: Span,
+    position: FfiPosition,
+    help: Option<DiagnosticMessage>,
+    note: DiagnosticMessage,
+    span_note: Option<Span>,
+}
+
+// Manual implementation of `DecorateLint` because the reason and help depend on the type.
+impl<'a> DecorateLint<'a, ()> for ImproperCTypes<'_> {
+    fn decorate_lint(self, diag: LintDiagnosticBuilder<'a, ()>) {
+        let mut diag = diag.build(fluent::lint::improper_ctypes);
+        diag.set_arg("ty", self.ty);
+        diag.set_arg("desc", self.desc);
+        match self.position {
+            FfiPosition::Param(Some(name)) => {
+                diag.set_arg("param", name);
+                diag.span_label(self.label, fluent::lint::param_label);
+            }
+            FfiPosition::Return => {
+                diag.span_label(self.label, fluent::lint::return_label);
+            }
+            FfiPosition::Param(None) | FfiPosition::Static => {
+                diag.span_label(self.label, fluent::lint::label);
+            }
+        }
+        if let Some(help) = self.help {
+            diag.help(help);
+        }
+        diag.note(self.note);
+        if let Some(note) = self.span_note {
+            diag.span_note(note, fluent::lint::note);
+        }
+        diag.emit();
+    }
+}
+
 enum FfiResult<'tcx> {
     FfiSafe,
     FfiPhantom(Ty<'tcx>),
@@ -815,11 +866,12 @@ pub(crate) fn repr_nullable_ptr<'tcx>(
 
 impl<'a, 'tcx> ImproperCTypesVisitor<'a, 'tcx> {
     /// Check if the type is array and emit an unsafe type lint.
-    fn check_for_array_ty(&mut self, sp: Span, ty: Ty<'tcx>) -> bool {
+    fn check_for_array_ty(&mut self, sp: Span, position: FfiPosition, ty: Ty<'tcx>) -> bool {
         if let ty::Array(..) = ty.kind() {
             self.emit_ffi_unsafe_type_lint(
                 ty,
                 sp,
+                position,
                 fluent::lint::improper_ctypes_array_reason,
                 Some(fluent::lint::improper_ctypes_array_help),
             );
@@ -1143,6 +1195,7 @@ fn emit_ffi_unsafe_type_lint(
         &mut self,
         ty: Ty<'tcx>,
         sp: Span,
+        position: FfiPosition,
         note: DiagnosticMessage,
         help: Option<DiagnosticMessage>,
     ) {
@@ -1151,29 +1204,23 @@ fn emit_ffi_unsafe_type_lint(
             CItemKind::Definition => IMPROPER_CTYPES_DEFINITIONS,
         };
 
-        self.cx.struct_span_lint(lint, sp, |lint| {
-            let item_description = match self.mode {
-                CItemKind::Declaration => "block",
-                CItemKind::Definition => "fn",
-            };
-            let mut diag = lint.build(fluent::lint::improper_ctypes);
-            diag.set_arg("ty", ty);
-            diag.set_arg("desc", item_description);
-            diag.span_label(sp, fluent::lint::label);
-            if let Some(help) = help {
-                diag.help(help);
-            }
-            diag.note(note);
-            if let ty::Adt(def, _) = ty.kind() {
-                if let Some(sp) = self.cx.tcx.hir().span_if_local(def.did()) {
-                    diag.span_note(sp, fluent::lint::note);
-                }
-            }
-            diag.emit();
-        });
+        let desc = match self.mode {
+            CItemKind::Declaration => "block",
+            CItemKind::Definition => "fn",
+        };
+        let span_note = if let ty::Adt(def, _) = ty.kind() {
+            self.cx.tcx.hir().span_if_local(def.did())
+        } else {
+            None
+        };
+        self.cx.emit_spanned_lint(
+            lint,
+            sp,
+            ImproperCTypes { ty, desc, label: sp, position, help, note, span_note },
+        );
     }
 
-    fn check_for_opaque_ty(&mut self, sp: Span, ty: Ty<'tcx>) -> bool {
+    fn check_for_opaque_ty(&mut self, sp: Span, position: FfiPosition, ty: Ty<'tcx>) -> bool {
         struct ProhibitOpaqueTypes<'a, 'tcx> {
             cx: &'a LateContext<'tcx>,
         }
@@ -1203,7 +1250,13 @@ fn visit_ty(&mut self, ty: Ty<'tcx>) -> ControlFlow<Self::BreakTy> {
         }
 
         if let Some(ty) = ty.visit_with(&mut ProhibitOpaqueTypes { cx: self.cx }).break_value() {
-            self.emit_ffi_unsafe_type_lint(ty, sp, fluent::lint::improper_ctypes_opaque, None);
+            self.emit_ffi_unsafe_type_lint(
+                ty,
+                sp,
+                position,
+                fluent::lint::improper_ctypes_opaque,
+                None,
+            );
             true
         } else {
             false
@@ -1214,12 +1267,13 @@ fn check_type_for_ffi_and_report_errors(
         &mut self,
         sp: Span,
         ty: Ty<'tcx>,
-        is_static: bool,
-        is_return_type: bool,
+        position: FfiPosition,
     ) {
+        let is_return_type = matches!(position, FfiPosition::Return);
+
         // We have to check for opaque types before `normalize_erasing_regions`,
         // which will replace opaque types with their underlying concrete type.
-        if self.check_for_opaque_ty(sp, ty) {
+        if self.check_for_opaque_ty(sp, position, ty) {
             // We've already emitted an error due to an opaque type.
             return;
         }
@@ -1231,7 +1285,7 @@ fn check_type_for_ffi_and_report_errors(
         // C doesn't really support passing arrays by value - the only way to pass an array by value
         // is through a struct. So, first test that the top level isn't an array, and then
         // recursively check the types inside.
-        if !is_static && self.check_for_array_ty(sp, ty) {
+        if !matches!(position, FfiPosition::Static) && self.check_for_array_ty(sp, position, ty) {
             return;
         }
 
@@ -1248,6 +1302,7 @@ fn check_type_for_ffi_and_report_errors(
                 self.emit_ffi_unsafe_type_lint(
                     ty,
                     sp,
+                    position,
                     fluent::lint::improper_ctypes_only_phantomdata,
                     None,
                 );
@@ -1256,30 +1311,44 @@ fn check_type_for_ffi_and_report_errors(
             // argument, which after substitution, is `()`, then this branch can be hit.
             FfiResult::FfiUnsafe { ty, .. } if is_return_type && ty.is_unit() => {}
             FfiResult::FfiUnsafe { ty, reason, help } => {
-                self.emit_ffi_unsafe_type_lint(ty, sp, reason, help);
+                self.emit_ffi_unsafe_type_lint(ty, sp, position, reason, help);
             }
         }
     }
 
-    fn check_foreign_fn(&mut self, id: hir::HirId, decl: &hir::FnDecl<'_>) {
+    /// Checks the parameters and return type of a foreign function. `param_names` has the name
+    /// of each parameter, or `None` if it doesn't have one.
+    fn check_foreign_fn(
+        &mut self,
+        id: hir::HirId,
+        decl: &hir::FnDecl<'_>,
+        param_names: impl Iterator<Item = Option<Ident>>,
+    ) {
         let def_id = self.cx.tcx.hir().local_def_id(id);
         let sig = self.cx.tcx.fn_sig(def_id);
         let sig = self.cx.tcx.erase_late_bound_regions(sig);
 
-        for (input_ty, input_hir) in iter::zip(sig.inputs(), decl.inputs) {
-            self.check_type_for_ffi_and_report_errors(input_hir.span, *input_ty, false, false);
+        for ((input_ty, input_hir), name) in
+            iter::zip(iter::zip(sig.inputs(), decl.inputs), param_names)
+        {
+            let name = name.filter(|name| name.name != kw::Underscore);
+            self.check_type_for_ffi_and_report_errors(
+                input_hir.span,
+                *input_ty,
+                FfiPosition::Param(name),
+            );
         }
 
         if let hir::FnRetTy::Return(ref ret_hir) = decl.output {
             let ret_ty = sig.output();
-            self.check_type_for_ffi_and_report_errors(ret_hir.span, ret_ty, false, true);
+            self.check_type_for_ffi_and_report_errors(ret_hir.span, ret_ty, FfiPosition::Return);
         }
     }
 
     fn check_foreign_static(&mut self, id: hir::HirId, span: Span) {
         let def_id = self.cx.tcx.hir().local_def_id(id);
         let ty = self.cx.tcx.type_of(def_id);
-        self.check_type_for_ffi_and_report_errors(span, ty, true, false);
+        self.check_type_for_ffi_and_report_errors(span, ty, FfiPosition::Static);
     }
 
     fn is_internal_abi(&self, abi: SpecAbi) -> bool {
@@ -1297,8 +1366,8 @@ fn check_foreign_item(&mut self, cx: &LateContext<'_>, it: &hir::ForeignItem<'_>
 
         if !vis.is_internal_abi(abi) {
             match it.kind {
-                hir::ForeignItemKind::Fn(ref decl, _, _) => {
-                    vis.check_foreign_fn(it.hir_id(), decl);
+                hir::ForeignItemKind::Fn(ref decl, param_names, _) => {
+                    vis.check_foreign_fn(it.hir_id(), decl, param_names.iter().copied().map(Some));
                 }
                 hir::ForeignItemKind::Static(ref ty, _) => {
                     vis.check_foreign_static(it.hir_id(), ty.span);
@@ -1315,7 +1384,7 @@ fn check_fn(
         cx: &LateContext<'tcx>,
         kind: hir::intravisit::FnKind<'tcx>,
         decl: &'tcx hir::FnDecl<'_>,
-        _: &'tcx hir::Body<'_>,
+        body: &'tcx hir::Body<'_>,
         _: Span,
         hir_id: hir::HirId,
     ) {
@@ -1329,7 +1398,11 @@ fn check_fn(
 
         let mut vis = ImproperCTypesVisitor { cx, mode: CItemKind::Definition };
         if !vis.is_internal_abi(abi) {
-            vis.check_foreign_fn(hir_id, decl);
+            vis.check_foreign_fn(
+                hir_id,
+                decl,
+                body.params.iter().map(|param| param.pat.simple_ident()),
+            );
         }
     }
 }
@@ -3371,6 +3371,10 @@
         FFI_UNWIND_CALLS,
         REPR_TRANSPARENT_EXTERNAL_PRIVATE_FIELDS,
         NAMED_ARGUMENTS_USED_POSITIONALLY,
+        UNREACHABLE_DROP_IMPLS,
+        UNREACHABLE_COERCE_UNSIZED_IMPLS,
+        UNUSED_WHERE_CLAUSE_LIFETIMES,
+        DEPENDENCY_ON_UNIT_NEVER_TYPE_FALLBACK,
     ]
 }
 
@@ -4105,3 +4109,132 @@
     Warn,
     "named arguments in format used positionally"
 }
+
+declare_lint! {
+    /// The `unreachable_drop_impls` lint detects `Drop` impls whose bounds can
+    /// never be satisfied, so the destructor never runs.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// trait Flush {}
+    ///
+    /// struct Buffer<T: Flush>(T);
+    ///
+    /// impl<T: Flush> Drop for Buffer<T> {
+    ///     fn drop(&mut self) {}
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// The `Drop` impl only applies to types satisfying its where-clauses.
+    /// If one of them names a trait that has no implementations and cannot
+    /// be implemented outside of the current crate, no type can ever satisfy
+    /// it and the destructor is dead code. This usually means that an impl
+    /// of the trait was forgotten or removed.
+    pub UNREACHABLE_DROP_IMPLS,
+    Warn,
+    "detects `Drop` impls whose bounds are never satisfied"
+}
+
+declare_lint! {
+    /// The `unreachable_coerce_unsized_impls` lint detects `CoerceUnsized`
+    /// impls that can only ever be used inside the current crate.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![feature(coerce_unsized, unsize)]
+    /// #![crate_type = "lib"]
+    /// #![deny(unreachable_coerce_unsized_impls)]
+    ///
+    /// use std::marker::Unsize;
+    /// use std::ops::CoerceUnsized;
+    ///
+    /// struct MyBox<T: ?Sized>(*const T);
+    ///
+    /// impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<MyBox<U>> for MyBox<T> {}
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// A `CoerceUnsized` impl is usually written so that users of a smart
+    /// pointer can coerce it to an unsized type. If both the struct and the
+    /// coerced field are private, other crates can never get hold of a value
+    /// to coerce, which often means that the smart pointer was meant to be
+    /// exported. This lint is "allow" by default because such impls are
+    /// perfectly fine for smart pointers that are internal to a crate, and
+    /// the lint cannot tell these apart from pointers that were meant to be
+    /// exported, so a warning would mostly fire on correct code.
+    pub UNREACHABLE_COERCE_UNSIZED_IMPLS,
+    Allow,
+    "detects `CoerceUnsized` impls that cannot be used outside of the current crate"
+}
+
+declare_lint! {
+    /// The `unused_where_clause_lifetimes` lint detects lifetime parameters
+    /// that are only used by outlives bounds in a `where` clause.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// fn print<'a>(s: &str) where 'a: 'static {
+    ///     println!("{s}");
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// A lifetime parameter that is mentioned nowhere but in bounds like
+    /// `'a: 'b` or `T: 'a` can always be chosen so that these bounds hold,
+    /// as long as it is always on the same side of them. Neither the
+    /// parameter nor the bounds have any effect then, and they can be
+    /// removed. This often happens when the type that used the lifetime has
+    /// been changed, but the bounds were left behind.
+    ///
+    /// Items reachable from other crates are not linted, as removing the
+    /// parameter would break callers naming it explicitly.
+    pub UNUSED_WHERE_CLAUSE_LIFETIMES,
+    Warn,
+    "detects lifetime parameters that are only used in `where` clauses"
+}
+
+declare_lint! {
+    /// The `dependency_on_unit_never_type_fallback` lint detects code which
+    /// only compiles because diverging expressions fall back to `()`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![allow(unreachable_code)]
+    /// #![warn(dependency_on_unit_never_type_fallback)]
+    ///
+    /// fn takes_default<T: Default>(_: T) {}
+    ///
+    /// fn main() {
+    ///     let x = panic!();
+    ///     takes_default(x);
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// The type of a diverging expression like `panic!()` or `return` which
+    /// is not otherwise constrained is currently inferred to be `()`. This
+    /// "never type fallback" is expected to change to `!` in the future. Code
+    /// which requires such a type to implement a trait which `!` does not
+    /// implement, like `Default` above, will then stop compiling. Writing down
+    /// `()` explicitly keeps the current behavior.
+    pub DEPENDENCY_ON_UNIT_NEVER_TYPE_FALLBACK,
+    Allow,
+    "detects code which depends on diverging expressions falling back to `()`"
+}
@@ -40,7 +40,7 @@ fn process_item(&mut self, id: rustc_hir::ItemId) {
         }
 
         let it = self.tcx.hir().item(id);
-        let hir::ItemKind::ForeignMod { abi, items: foreign_mod_items } = it.kind else {
+        let hir::ItemKind::ForeignMod { abi, items: foreign_mod_items, .. } = it.kind else {
             return;
         };
 
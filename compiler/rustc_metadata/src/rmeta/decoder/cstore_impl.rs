@@ -223,6 +223,15 @@ fn into_args(self) -> (DefId, SimplifiedType) {
     fn_arg_names => { table }
     generator_kind => { table }
     trait_def => { table }
+    collect_trait_impl_trait_tys => {
+        Ok(cdata
+            .root
+            .tables
+            .trait_impl_trait_tys
+            .get(cdata, def_id.index)
+            .map(|lazy| lazy.decode((cdata, tcx)))
+            .process_decoded(tcx, || panic!("{:?} does not have trait_impl_trait_tys", def_id)))
+    }
 
     adt_def => { cdata.get_adt_def(def_id.index, tcx) }
     adt_destructor => {
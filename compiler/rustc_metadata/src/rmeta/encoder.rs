@@ -841,6 +841,7 @@ fn should_encode_visibility(def_kind: DefKind) -> bool {
         | DefKind::Use
         | DefKind::ForeignMod
         | DefKind::OpaqueTy
+        | DefKind::ImplTraitPlaceholder
         | DefKind::Impl
         | DefKind::Field => true,
         DefKind::TyParam
@@ -873,6 +874,7 @@ fn should_encode_stability(def_kind: DefKind) -> bool {
         | DefKind::ForeignMod
         | DefKind::TyAlias
         | DefKind::OpaqueTy
+        | DefKind::ImplTraitPlaceholder
         | DefKind::Enum
         | DefKind::Union
         | DefKind::Impl
@@ -961,6 +963,7 @@ fn should_encode_variances(def_kind: DefKind) -> bool {
         | DefKind::ForeignMod
         | DefKind::TyAlias
         | DefKind::OpaqueTy
+        | DefKind::ImplTraitPlaceholder
         | DefKind::Impl
         | DefKind::Trait
         | DefKind::TraitAlias
@@ -997,6 +1000,7 @@ fn should_encode_generics(def_kind: DefKind) -> bool {
         | DefKind::AnonConst
         | DefKind::InlineConst
         | DefKind::OpaqueTy
+        | DefKind::ImplTraitPlaceholder
         | DefKind::Impl
         | DefKind::Field
         | DefKind::TyParam
@@ -1338,6 +1342,13 @@ fn encode_info_for_impl_item(&mut self, def_id: DefId) {
             if tcx.is_intrinsic(def_id) {
                 self.tables.is_intrinsic.set(def_id.index, ());
             }
+            if impl_item.trait_item_def_id.is_some() {
+                if let Ok(tys) = tcx.collect_trait_impl_trait_tys(def_id) {
+                    if !tys.is_empty() {
+                        record!(self.tables.trait_impl_trait_tys[def_id] <- tys);
+                    }
+                }
+            }
         }
     }
 
@@ -1583,11 +1594,14 @@ fn encode_info_for_item(&mut self, def_id: DefId, item: &'tcx hir::Item<'tcx>) {
             | hir::ItemKind::Const(..)
             | hir::ItemKind::Fn(..)
             | hir::ItemKind::TyAlias(..)
-            | hir::ItemKind::OpaqueTy(..)
             | hir::ItemKind::Enum(..)
             | hir::ItemKind::Struct(..)
             | hir::ItemKind::Union(..)
             | hir::ItemKind::Impl { .. } => self.encode_item_type(def_id),
+            // Return-position `impl Trait` in traits have no hidden type of their own.
+            hir::ItemKind::OpaqueTy(ref opaque) if !opaque.in_trait => {
+                self.encode_item_type(def_id)
+            }
             _ => {}
         }
         if let hir::ItemKind::Fn(..) = item.kind {
@@ -234,7 +234,13 @@ pub(super) fn opt_def_kind(self, local_def_id: LocalDefId) -> Option<DefKind> {
                 ItemKind::Fn(..) => DefKind::Fn,
                 ItemKind::Macro(_, macro_kind) => DefKind::Macro(macro_kind),
                 ItemKind::Mod(..) => DefKind::Mod,
-                ItemKind::OpaqueTy(..) => DefKind::OpaqueTy,
+                ItemKind::OpaqueTy(ref opaque) => {
+                    if opaque.in_trait {
+                        DefKind::ImplTraitPlaceholder
+                    } else {
+                        DefKind::OpaqueTy
+                    }
+                }
                 ItemKind::TyAlias(..) => DefKind::TyAlias,
                 ItemKind::Enum(..) => DefKind::Enum,
                 ItemKind::Struct(..) => DefKind::Struct,
@@ -802,6 +808,18 @@ pub fn get_enclosing_scope(self, hir_id: HirId) -> Option<HirId> {
 
     /// Returns the defining scope for an opaque type definition.
     pub fn get_defining_scope(self, id: HirId) -> HirId {
+        // `impl Trait` in the type of a constant or static can only be defined by its
+        // initializer, not by the rest of the enclosing module or impl.
+        let const_item = self.parent_iter(id).find_map(|(hir_id, node)| match node {
+            Node::Item(Item { kind: ItemKind::Const(..) | ItemKind::Static(..), .. })
+            | Node::ImplItem(ImplItem { kind: ImplItemKind::Const(..), .. }) => Some(Some(hir_id)),
+            Node::Item(_) | Node::TraitItem(_) | Node::ImplItem(_) | Node::Block(_) => Some(None),
+            _ => None,
+        });
+        if let Some(Some(scope)) = const_item {
+            return scope;
+        }
+
         let mut scope = id;
         loop {
             scope = self.get_enclosing_scope(scope).unwrap_or(CRATE_HIR_ID);
@@ -260,6 +260,16 @@
         desc { |tcx| "elaborating item bounds for `{}`", tcx.def_path_str(key) }
     }
 
+    /// Given the `DefId` of a method in a trait impl, returns the hidden types that the impl
+    /// method's return type assigns to each return-position `impl Trait` of the corresponding
+    /// trait method, keyed by the `DefId` of the `impl Trait` placeholder in the trait.
+    query collect_trait_impl_trait_tys(key: DefId)
+        -> Result<&'tcx FxHashMap<DefId, Ty<'tcx>>, ErrorGuaranteed>
+    {
+        desc { |tcx| "inferring the hidden `impl Trait` types of `{}`", tcx.def_path_str(key) }
+        separate_provide_extern
+    }
+
     query native_libraries(_: CrateNum) -> Vec<NativeLib> {
         storage(ArenaCacheSelector<'tcx>)
         desc { "looking up the native libraries of a linked crate" }
@@ -568,6 +578,11 @@
         cache_on_disk_if { key.is_local() }
         separate_provide_extern
     }
+    /// Computes the discriminant of the enum variant `key`. Returns `None` if evaluating its
+    /// discriminant failed or the value does not fit into the enum's representation.
+    query variant_discriminant(key: DefId) -> Option<ty::util::Discr<'tcx>> {
+        desc { |tcx| "computing the discriminant of `{}`", tcx.def_path_str(key) }
+    }
     query adt_destructor(key: DefId) -> Option<ty::Destructor> {
         desc { |tcx| "computing `Drop` impl for `{}`", tcx.def_path_str(key) }
         cache_on_disk_if { key.is_local() }
@@ -648,6 +663,13 @@
         separate_provide_extern
     }
 
+    /// For each generic parameter of the opaque type `def_id`, computes the variance with which
+    /// its hidden type uses the parameter. This shows which captured lifetimes the hidden type
+    /// could not be shortened in. Must not be called while the hidden type is inferred.
+    query opaque_captured_lifetime_variances(def_id: DefId) -> &'tcx [ty::Variance] {
+        desc { |tcx| "computing how `{}` uses its captured lifetimes", tcx.def_path_str(def_id) }
+    }
+
     /// Maps from thee `DefId` of a type to its (inferred) outlives.
     query inferred_outlives_crate(_: ()) -> ty::CratePredicatesMap<'tcx> {
         storage(ArenaCacheSelector<'tcx>)
@@ -898,6 +920,14 @@
         desc { |tcx| "coherence checking all impls of trait `{}`", tcx.def_path_str(def_id) }
     }
 
+    /// Additional checks that `coherent_trait` runs on the local impls of some traits. This is
+    /// always empty in rustc itself; custom drivers override it to register their own checks.
+    query extra_impl_validators(_: ()) -> &'tcx [ty::ImplValidator] {
+        eval_always
+        no_hash
+        desc { "collecting additional impl validators" }
+    }
+
     /// Borrow-checks the function body. If this is a closure, returns
     /// additional requirements that the closure's creator must verify.
     query mir_borrowck(key: LocalDefId) -> &'tcx mir::BorrowCheckResult<'tcx> {
@@ -1216,6 +1246,14 @@
         desc { |tcx| "trait impls of `{}`", tcx.def_path_str(trait_id) }
     }
 
+    /// Given a trait `trait_id`, return the `impl` blocks of the current crate, bucketed by
+    /// their simplified self type, so that callers only need to look at impls that could
+    /// possibly apply to a given self type.
+    query trait_impls_by_self_simplified(trait_id: DefId) -> ty::trait_def::LocalTraitImpls {
+        storage(ArenaCacheSelector<'tcx>)
+        desc { |tcx| "local trait impls of `{}` by simplified self type", tcx.def_path_str(trait_id) }
+    }
+
     query specialization_graph_of(trait_id: DefId) -> specialization_graph::Graph {
         storage(ArenaCacheSelector<'tcx>)
         desc { |tcx| "building specialization graph of trait `{}`", tcx.def_path_str(trait_id) }
@@ -1225,6 +1263,14 @@
         desc { |tcx| "determine object safety of trait `{}`", tcx.def_path_str(trait_id) }
     }
 
+    /// Checks what would keep the ADT `adt_id` from implementing `Copy` in its own
+    /// parameter environment, using the analysis behind the `impl Copy` coherence check.
+    /// Returns `None` if nothing does. rustdoc uses this for `--show-copy-obstacles`.
+    query copy_impl_obstacle(adt_id: DefId) -> Option<traits::CopyImplObstacle> {
+        storage(ArenaCacheSelector<'tcx>)
+        desc { |tcx| "checking whether `{}` could implement `Copy`", tcx.def_path_str(adt_id) }
+    }
+
     /// Gets the ParameterEnvironment for a given item; this environment
     /// will be in "user-facing" mode, meaning that it is suitable for
     /// type-checking etc, and it does not normalize specializable
@@ -1403,6 +1449,17 @@
     query specializes(_: (DefId, DefId)) -> bool {
         desc { "computing whether impls specialize one another" }
     }
+    /// Whether the headers of two impls of the same trait may apply to the same types. This is
+    /// symmetric, and callers pass the impls ordered by `DefId` so that each pair is only
+    /// computed once. Rebuilding the specialization graph after an incremental change compares
+    /// every pair of impls again, and this lets it reuse the outcome for unchanged impls.
+    query impls_overlap(key: (DefId, DefId)) -> bool {
+        desc { |tcx|
+            "checking whether impls `{}` and `{}` overlap",
+            tcx.def_path_str(key.0),
+            tcx.def_path_str(key.1),
+        }
+    }
     query in_scope_traits_map(_: LocalDefId)
         -> Option<&'tcx FxHashMap<ItemLocalId, Box<[TraitCandidate]>>> {
         desc { "traits in scope at a block" }
@@ -901,6 +901,15 @@ pub fn error_msg(&self) -> Cow<'static, str> {
             ObjectSafetyViolation::Method(name, MethodViolationCode::ReferencesSelfOutput, _) => {
                 format!("method `{}` references the `Self` type in its return type", name).into()
             }
+            ObjectSafetyViolation::Method(name, MethodViolationCode::AsyncFn(_), _) => {
+                format!("method `{}` is `async`", name).into()
+            }
+            ObjectSafetyViolation::Method(
+                name,
+                MethodViolationCode::ReferencesImplTraitInTrait(_),
+                _,
+            ) => format!("method `{}` references an `impl Trait` type in its return type", name)
+                .into(),
             ObjectSafetyViolation::Method(
                 name,
                 MethodViolationCode::WhereClauseReferencesSelf,
@@ -969,6 +978,20 @@ trait objects",
                     Applicability::MachineApplicable,
                 );
             }
+            ObjectSafetyViolation::Method(
+                name,
+                MethodViolationCode::AsyncFn(Some((async_span, (boxed_future, ret_span)))),
+                _,
+            ) => {
+                err.multipart_suggestion(
+                    &format!(
+                        "consider making `{}` return a boxed future instead of being `async`",
+                        name
+                    ),
+                    vec![(*async_span, String::new()), (*ret_span, boxed_future.clone())],
+                    Applicability::MaybeIncorrect,
+                );
+            }
             ObjectSafetyViolation::AssocConst(name, _)
             | ObjectSafetyViolation::GAT(name, _)
             | ObjectSafetyViolation::Method(name, ..) => {
@@ -1007,6 +1030,12 @@ pub enum MethodViolationCode {
     /// e.g., `fn foo(&self) -> Self`
     ReferencesSelfOutput,
 
+    /// e.g., `async fn foo(&self)`
+    AsyncFn(Option<(/* remove `async` */ Span, /* box the future */ (String, Span))>),
+
+    /// e.g., `fn foo(&self) -> impl Sized`
+    ReferencesImplTraitInTrait(Span),
+
     /// e.g., `fn foo(&self) where Self: Clone`
     WhereClauseReferencesSelf,
 
@@ -1034,3 +1063,14 @@ pub enum CodegenObligationError {
     Unimplemented,
     FulfillmentError,
 }
+
+/// What keeps an ADT from implementing `Copy`, as found by the same check coherence performs
+/// for `impl Copy` items. This is the result of the `copy_impl_obstacle` query, which lets
+/// rustdoc explain why a type is not `Copy`.
+#[derive(Clone, Debug, PartialEq, Eq, HashStable, TyEncodable, TyDecodable)]
+pub enum CopyImplObstacle {
+    /// The fields whose types do not implement `Copy`, in declaration order.
+    InfringingFields(Vec<DefId>),
+    /// The type implements `Drop`.
+    HasDestructor,
+}
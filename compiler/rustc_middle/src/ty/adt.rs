@@ -489,8 +489,8 @@ pub fn discriminants(
         let mut prev_discr = None::<Discr<'tcx>>;
         self.variants().iter_enumerated().map(move |(i, v)| {
             let mut discr = prev_discr.map_or(initial, |d| d.wrap_incr(tcx));
-            if let VariantDiscr::Explicit(expr_did) = v.discr {
-                if let Some(new_discr) = self.eval_explicit_discr(tcx, expr_did) {
+            if let VariantDiscr::Explicit(_) = v.discr {
+                if let Some(new_discr) = tcx.variant_discriminant(v.def_id) {
                     discr = new_discr;
                 }
             }
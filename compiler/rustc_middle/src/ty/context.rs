@@ -403,6 +403,9 @@ pub struct GeneratorInteriorTypeCause<'tcx> {
     pub yield_span: Span,
     /// Expr which the type evaluated from.
     pub expr: Option<hir::HirId>,
+    /// Span of the method call or constructor expression that created the captured value as a
+    /// temporary borrow, if any. Unlike `expr`, this survives crate boundaries.
+    pub temporary_span: Option<Span>,
 }
 
 // This type holds diagnostic information on generators and async functions across crate boundaries
@@ -688,6 +691,7 @@ pub fn get_generator_diagnostic_data(&self) -> GeneratorDiagnosticData<'tcx> {
                         scope_span: item.scope_span,
                         yield_span: item.yield_span,
                         expr: None, //FIXME: Passing expression over crate boundaries is impossible at the moment
+                        temporary_span: item.temporary_span,
                     }
                 })
                 .collect::<Vec<_>>()
@@ -33,6 +33,7 @@
 use rustc_data_structures::intern::{Interned, WithStableHash};
 use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
 use rustc_data_structures::tagged_ptr::CopyTaggedPtr;
+use rustc_errors::MultiSpan;
 use rustc_hir as hir;
 use rustc_hir::def::{CtorKind, CtorOf, DefKind, LifetimeRes, Res};
 use rustc_hir::def_id::{CrateNum, DefId, LocalDefId, LocalDefIdMap};
@@ -49,6 +50,7 @@
 
 use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
+use std::iter;
 use std::ops::ControlFlow;
 use std::{fmt, str};
 
@@ -89,7 +91,7 @@
     PolyExistentialTraitRef, PolyFnSig, PolyGenSig, PolyTraitRef, ProjectionTy, Region, RegionKind,
     RegionVid, TraitRef, TyKind, TypeAndMut, UpvarSubsts, VarianceDiagInfo,
 };
-pub use self::trait_def::TraitDef;
+pub use self::trait_def::{ImplValidator, TraitDef};
 
 pub mod _match;
 pub mod abstract_const;
@@ -107,6 +109,7 @@
 pub mod print;
 pub mod query;
 pub mod relate;
+pub mod simd;
 pub mod subst;
 pub mod trait_def;
 pub mod util;
@@ -1205,6 +1208,30 @@ pub fn report_mismatch(&self, other: &Self, tcx: TyCtxt<'tcx>) {
         }
         err.emit();
     }
+
+    /// Reports every use in `others` whose hidden type differs from `self`, the first defining
+    /// use, in a single diagnostic.
+    pub fn report_mismatches(&self, others: &[Self], tcx: TyCtxt<'tcx>) {
+        match others {
+            [] => {}
+            [other] => self.report_mismatch(other, tcx),
+            _ => {
+                let spans = iter::once(self.span).chain(others.iter().map(|other| other.span));
+                let mut err = tcx.sess.struct_span_err(
+                    MultiSpan::from_spans(spans.collect()),
+                    "concrete types differ between defining opaque type uses",
+                );
+                err.span_label(
+                    self.span,
+                    format!("this use defines the hidden type as `{}`", self.ty),
+                );
+                for other in others {
+                    err.span_label(other.span, format!("this use defines it as `{}`", other.ty));
+                }
+                err.emit();
+            }
+        }
+    }
 }
 
 /// The "placeholder index" fully defines a placeholder region, type, or const. Placeholders are
@@ -2476,6 +2503,7 @@ pub fn provide(providers: &mut ty::query::Providers) {
     super::middle::provide(providers);
     *providers = ty::query::Providers {
         trait_impls_of: trait_def::trait_impls_of_provider,
+        trait_impls_by_self_simplified: trait_def::trait_impls_by_self_simplified_provider,
         incoherent_impls: trait_def::incoherent_impls_provider,
         type_uninhabited_from: inhabitedness::type_uninhabited_from,
         const_param_default: consts::const_param_default,
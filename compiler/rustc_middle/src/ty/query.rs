@@ -40,7 +40,7 @@
 use rustc_data_structures::steal::Steal;
 use rustc_data_structures::svh::Svh;
 use rustc_data_structures::sync::Lrc;
-use rustc_errors::ErrorGuaranteed;
+use rustc_errors::{struct_span_err, DiagnosticBuilder, ErrorGuaranteed};
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::{CrateNum, DefId, DefIdMap, DefIdSet, LocalDefId};
@@ -384,3 +384,60 @@ pub fn def_kind(self, def_id: impl IntoQueryParam<DefId>) -> DefKind {
             .unwrap_or_else(|| bug!("def_kind: unsupported node: {:?}", def_id))
     }
 }
+
+/// Reports a cycle hit while resolving associated type paths like `T::Assoc` during type
+/// collection by walking the chain of paths that lead back to the first one, instead of only
+/// listing the queries involved. Each query in `cycle` must be resolving such a path.
+pub fn report_assoc_ty_path_cycle<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    usage: &Option<(Span, QueryStackFrame)>,
+    cycle: &[QueryInfo],
+) -> Option<DiagnosticBuilder<'tcx, ErrorGuaranteed>> {
+    // The path that each query of the cycle was resolving when it started the next one. Bail
+    // out if we don't know where any of them are, as the chain wouldn't be of much use then.
+    let paths = (0..cycle.len())
+        .map(|i| {
+            let span = cycle[(i + 1) % cycle.len()].span;
+            if span.is_dummy() {
+                return None;
+            }
+            let snippet = tcx.sess.source_map().span_to_snippet(span).ok()?;
+            Some((span, snippet))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let (first_span, first_path) = &paths[0];
+    let mut err = struct_span_err!(
+        tcx.sess,
+        *first_span,
+        E0391,
+        "cycle detected when resolving the associated type `{}`",
+        first_path
+    );
+    err.span_label(
+        *first_span,
+        format!("resolving this requires {}...", cycle[1 % cycle.len()].query.description),
+    );
+    for i in 1..cycle.len() {
+        let (span, path) = &paths[i];
+        err.span_note(
+            *span,
+            &format!(
+                "...which requires resolving `{}`, which requires {}...",
+                path,
+                cycle[(i + 1) % cycle.len()].query.description
+            ),
+        );
+    }
+    err.note(&format!("...which requires resolving `{}` again, completing the cycle", first_path));
+    err.help(
+        "fully qualified paths like `<T as Trait>::Assoc` don't require computing the bounds of \
+         `T`, so using one for any of these associated types breaks the cycle",
+    );
+
+    if let Some((span, query)) = usage {
+        err.span_note(query.default_span(*span), &format!("cycle used when {}", query.description));
+    }
+
+    Some(err)
+}
@@ -0,0 +1,303 @@
+// Helpers for validating calls to the `simd_*` platform intrinsics, used in both
+// typeck and codegen.
+
+use crate::ty::{self, Ty, TyCtxt};
+
+use rustc_span::symbol::{sym, Symbol};
+
+/// Returns the number of lanes and the element type of the SIMD vector `ty`, or `None`
+/// if `ty` isn't a SIMD vector or the length of its array field can't be evaluated.
+pub fn simd_lanes_and_element<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    ty: Ty<'tcx>,
+) -> Option<(u64, Ty<'tcx>)> {
+    let ty::Adt(def, substs) = *ty.kind() else { return None };
+    if !def.repr().simd() {
+        return None;
+    }
+    let variant = def.non_enum_variant();
+    let f0_ty = variant.fields.get(0)?.ty(tcx, substs);
+    match *f0_ty.kind() {
+        ty::Array(elem, len) => Some((len.try_eval_usize(tcx, param_env)?, elem)),
+        _ => Some((variant.fields.len() as u64, f0_ty)),
+    }
+}
+
+/// Checks the argument and return types of a call to the `simd_*` platform intrinsic `name`
+/// against the requirements that don't depend on the codegen backend.
+///
+/// On failure, returns a description of the first violated requirement, which callers are
+/// expected to report as `E0511`. Intrinsics and requirements not covered here are left to
+/// the backend.
+pub fn check_simd_intrinsic_types<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    name: Symbol,
+    arg_tys: &[Ty<'tcx>],
+    ret_ty: Ty<'tcx>,
+) -> Result<(), String> {
+    macro_rules! require {
+        ($cond:expr, $($fmt:tt)*) => {
+            if !$cond {
+                return Err(format!($($fmt)*));
+            }
+        };
+    }
+
+    // Requires `ty` to be a SIMD vector and returns its lane count and element type.
+    macro_rules! require_simd {
+        ($ty:expr, $position:expr) => {{
+            let ty = $ty;
+            require!(ty.is_simd(), "expected SIMD {} type, found non-SIMD `{}`", $position, ty);
+            match simd_lanes_and_element(tcx, param_env, ty) {
+                Some(lanes_and_element) => lanes_and_element,
+                None => return Ok(()),
+            }
+        }};
+    }
+
+    // Declarations of the intrinsic with the wrong number of arguments are reported
+    // when checking the declaration itself.
+    let min_inputs = match name {
+        sym::simd_select_bitmask | sym::simd_select | sym::simd_insert => 3,
+        _ if name.as_str().starts_with("simd_shuffle") => 3,
+        _ => 1,
+    };
+    if arg_tys.len() < min_inputs {
+        return Ok(());
+    }
+
+    let is_bitmask = |ty: Ty<'tcx>, int_bits: u64, bytes: u64, allow_signed: bool| {
+        match *ty.kind() {
+            ty::Uint(i) => i.bit_width() == Some(int_bits),
+            ty::Int(i) => allow_signed && i.bit_width() == Some(int_bits),
+            ty::Array(elem, len) => {
+                matches!(elem.kind(), ty::Uint(ty::UintTy::U8))
+                    && len.try_eval_usize(tcx, param_env) == Some(bytes)
+            }
+            _ => false,
+        }
+    };
+
+    if name == sym::simd_select_bitmask {
+        let (len, _) = require_simd!(arg_tys[1], "argument");
+        let expected_int_bits = (len.max(8) - 1).next_power_of_two();
+        let expected_bytes = len / 8 + ((len % 8 > 0) as u64);
+        require!(
+            is_bitmask(arg_tys[0], expected_int_bits, expected_bytes, true),
+            "invalid bitmask `{}`, expected `u{}` or `[u8; {}]`",
+            arg_tys[0],
+            expected_int_bits,
+            expected_bytes
+        );
+        return Ok(());
+    }
+
+    // Every other SIMD intrinsic takes a vector as its first argument.
+    let in_ty = arg_tys[0];
+    let (in_len, in_elem) = require_simd!(in_ty, "input");
+    let is_int = matches!(in_elem.kind(), ty::Int(_) | ty::Uint(_));
+    let is_float = matches!(in_elem.kind(), ty::Float(_));
+
+    match name {
+        sym::simd_eq | sym::simd_ne | sym::simd_lt | sym::simd_le | sym::simd_gt | sym::simd_ge => {
+            let (out_len, out_elem) = require_simd!(ret_ty, "return");
+            require!(
+                in_len == out_len,
+                "expected return type with length {} (same as input type `{}`), \
+                 found `{}` with length {}",
+                in_len,
+                in_ty,
+                ret_ty,
+                out_len
+            );
+            require!(
+                matches!(out_elem.kind(), ty::Int(_) | ty::Uint(_)),
+                "expected return type with integer elements, found `{}` with non-integer `{}`",
+                ret_ty,
+                out_elem
+            );
+        }
+        _ if let Some(suffix) = name.as_str().strip_prefix("simd_shuffle") => {
+            // The older `simd_shuffleN` forms carry the output length in their name, the
+            // newer `simd_shuffle` takes it from the length of its index array.
+            let n = if suffix.is_empty() {
+                let index_len = match *arg_tys[2].kind() {
+                    ty::Array(elem, len) if matches!(elem.kind(), ty::Uint(ty::UintTy::U32)) => {
+                        len.try_eval_usize(tcx, param_env)
+                    }
+                    _ => None,
+                };
+                require!(
+                    index_len.is_some(),
+                    "simd_shuffle index must be an array of `u32`, got `{}`",
+                    arg_tys[2]
+                );
+                index_len.unwrap()
+            } else {
+                let Ok(n) = suffix.parse::<u64>() else { return Ok(()) };
+                n
+            };
+            let (out_len, out_elem) = require_simd!(ret_ty, "return");
+            require!(
+                out_len == n,
+                "expected return type of length {}, found `{}` with length {}",
+                n,
+                ret_ty,
+                out_len
+            );
+            require!(
+                in_elem == out_elem,
+                "expected return element type `{}` (element of input `{}`), \
+                 found `{}` with element type `{}`",
+                in_elem,
+                in_ty,
+                ret_ty,
+                out_elem
+            );
+        }
+        sym::simd_insert => {
+            require!(
+                in_elem == arg_tys[2],
+                "expected inserted type `{}` (element of input `{}`), found `{}`",
+                in_elem,
+                in_ty,
+                arg_tys[2]
+            );
+        }
+        sym::simd_extract
+        | sym::simd_reduce_add_ordered
+        | sym::simd_reduce_mul_ordered
+        | sym::simd_reduce_add_unordered
+        | sym::simd_reduce_mul_unordered
+        | sym::simd_reduce_min
+        | sym::simd_reduce_max
+        | sym::simd_reduce_min_nanless
+        | sym::simd_reduce_max_nanless
+        | sym::simd_reduce_and
+        | sym::simd_reduce_or
+        | sym::simd_reduce_xor => {
+            require!(
+                ret_ty == in_elem,
+                "expected return type `{}` (element of input `{}`), found `{}`",
+                in_elem,
+                in_ty,
+                ret_ty
+            );
+            let is_bitwise =
+                matches!(name, sym::simd_reduce_and | sym::simd_reduce_or | sym::simd_reduce_xor);
+            require!(
+                name == sym::simd_extract || is_int || (is_float && !is_bitwise),
+                "unsupported {} from `{}` with element `{}` to `{}`",
+                name,
+                in_ty,
+                in_elem,
+                ret_ty
+            );
+        }
+        sym::simd_reduce_all | sym::simd_reduce_any => {
+            require!(
+                is_int,
+                "unsupported {} from `{}` with element `{}` to `{}`",
+                name,
+                in_ty,
+                in_elem,
+                ret_ty
+            );
+        }
+        sym::simd_select => {
+            let (v_len, _) = require_simd!(arg_tys[1], "argument");
+            require!(
+                in_len == v_len,
+                "mismatched lengths: mask length `{}` != other vector length `{}`",
+                in_len,
+                v_len
+            );
+            require!(
+                matches!(in_elem.kind(), ty::Int(_)),
+                "mask element type is `{}`, expected `i_`",
+                in_elem
+            );
+        }
+        sym::simd_bitmask => {
+            let expected_int_bits = in_len.max(8);
+            let expected_bytes = expected_int_bits / 8 + ((expected_int_bits % 8 > 0) as u64);
+            require!(
+                is_int,
+                "vector argument `{}`'s element type `{}`, expected integer element type",
+                in_ty,
+                in_elem
+            );
+            require!(
+                is_bitmask(ret_ty, expected_int_bits, expected_bytes, false),
+                "cannot return `{}`, expected `u{}` or `[u8; {}]`",
+                ret_ty,
+                expected_int_bits,
+                expected_bytes
+            );
+        }
+        sym::simd_cast | sym::simd_as => {
+            let (out_len, out_elem) = require_simd!(ret_ty, "return");
+            require!(
+                in_len == out_len,
+                "expected return type with length {} (same as input type `{}`), \
+                 found `{}` with length {}",
+                in_len,
+                in_ty,
+                ret_ty,
+                out_len
+            );
+            // Casting cares about the nominal type, so any element type can be cast to itself.
+            let is_numeric =
+                |ty: Ty<'_>| matches!(ty.kind(), ty::Int(_) | ty::Uint(_) | ty::Float(_));
+            require!(
+                in_elem == out_elem || (is_numeric(in_elem) && is_numeric(out_elem)),
+                "unsupported cast from `{}` with element `{}` to `{}` with element `{}`",
+                in_ty,
+                in_elem,
+                ret_ty,
+                out_elem
+            );
+        }
+        sym::simd_add
+        | sym::simd_sub
+        | sym::simd_mul
+        | sym::simd_div
+        | sym::simd_rem
+        | sym::simd_shl
+        | sym::simd_shr
+        | sym::simd_and
+        | sym::simd_or
+        | sym::simd_xor
+        | sym::simd_fmax
+        | sym::simd_fmin
+        | sym::simd_neg => {
+            let supported = match name {
+                sym::simd_add | sym::simd_sub | sym::simd_mul | sym::simd_div | sym::simd_rem => {
+                    is_int || is_float
+                }
+                sym::simd_fmax | sym::simd_fmin => is_float,
+                sym::simd_neg => matches!(in_elem.kind(), ty::Int(_)) || is_float,
+                _ => is_int,
+            };
+            require!(
+                supported,
+                "unsupported operation on `{}` with element `{}`",
+                in_ty,
+                in_elem
+            );
+        }
+        sym::simd_saturating_add | sym::simd_saturating_sub => {
+            require!(
+                is_int,
+                "expected element type `{}` of vector type `{}` \
+                 to be a signed or unsigned integer type",
+                in_elem,
+                in_ty
+            );
+        }
+        _ => {}
+    }
+    Ok(())
+}
@@ -2,9 +2,11 @@
 use crate::ty::fast_reject::{self, SimplifiedType, TreatParams};
 use crate::ty::visit::TypeVisitable;
 use crate::ty::{Ident, Ty, TyCtxt};
+use either::Either;
 use hir::def_id::LOCAL_CRATE;
 use rustc_hir as hir;
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use std::fmt;
 use std::iter;
 
 use rustc_data_structures::fx::FxIndexMap;
@@ -43,6 +45,10 @@ pub struct TraitDef {
     /// List of functions from `#[rustc_must_implement_one_of]` attribute one of which
     /// must be implemented.
     pub must_implement_one_of: Option<Box<[Ident]>>,
+
+    /// If `true`, then this trait has the `#[rustc_deny_explicit_impl]` attribute, indicating
+    /// that it is implemented by the compiler and can't be implemented explicitly.
+    pub deny_explicit_impl: bool,
 }
 
 /// Whether this trait is treated specially by the standard library
@@ -63,6 +69,26 @@ pub enum TraitSpecializationKind {
     AlwaysApplicable,
 }
 
+/// An extra check run on every local impl of a trait during coherence, next to the builtin
+/// checks for lang item traits like `Copy` or `CoerceUnsized`.
+///
+/// None are registered by default. Custom drivers can register their own by overriding the
+/// `extra_impl_validators` query, e.g. to enforce project-specific rules for marker traits.
+/// Validators report problems by emitting diagnostics on `tcx.sess`.
+#[derive(Copy, Clone)]
+pub struct ImplValidator {
+    pub trait_def_id: DefId,
+    pub validate: for<'tcx> fn(TyCtxt<'tcx>, LocalDefId),
+}
+
+impl fmt::Debug for ImplValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImplValidator")
+            .field("trait_def_id", &self.trait_def_id)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Default, Debug, HashStable)]
 pub struct TraitImpls {
     blanket_impls: Vec<DefId>,
@@ -80,6 +106,36 @@ pub fn non_blanket_impls(&self) -> &FxIndexMap<SimplifiedType, Vec<DefId>> {
     }
 }
 
+/// The impls of a trait in the current crate, indexed by their simplified self type.
+#[derive(Default, Debug, HashStable)]
+pub struct LocalTraitImpls {
+    blanket_impls: Vec<LocalDefId>,
+    non_blanket_impls: FxIndexMap<SimplifiedType, Vec<LocalDefId>>,
+}
+
+impl LocalTraitImpls {
+    pub fn blanket_impls(&self) -> &[LocalDefId] {
+        self.blanket_impls.as_slice()
+    }
+
+    pub fn non_blanket_impls(&self) -> &FxIndexMap<SimplifiedType, Vec<LocalDefId>> {
+        &self.non_blanket_impls
+    }
+
+    /// Returns every impl whose self type could possibly match `simp`. If the self type
+    /// can't be simplified, every impl is returned.
+    pub fn relevant_impls(
+        &self,
+        simp: Option<SimplifiedType>,
+    ) -> impl Iterator<Item = LocalDefId> + '_ {
+        let non_blanket_impls = match simp {
+            Some(simp) => Either::Left(self.non_blanket_impls.get(&simp).into_iter().flatten()),
+            None => Either::Right(self.non_blanket_impls.values().flatten()),
+        };
+        self.blanket_impls.iter().chain(non_blanket_impls).copied()
+    }
+}
+
 impl<'tcx> TraitDef {
     pub fn new(
         def_id: DefId,
@@ -90,6 +146,7 @@ pub fn new(
         skip_array_during_method_dispatch: bool,
         specialization_kind: TraitSpecializationKind,
         must_implement_one_of: Option<Box<[Ident]>>,
+        deny_explicit_impl: bool,
     ) -> TraitDef {
         TraitDef {
             def_id,
@@ -100,6 +157,7 @@ pub fn new(
             skip_array_during_method_dispatch,
             specialization_kind,
             must_implement_one_of,
+            deny_explicit_impl,
         }
     }
 
@@ -235,9 +293,27 @@ pub(super) fn trait_impls_of_provider(tcx: TyCtxt<'_>, trait_id: DefId) -> Trait
         }
     }
 
-    for &impl_def_id in tcx.hir().trait_impls(trait_id) {
-        let impl_def_id = impl_def_id.to_def_id();
+    let local_impls = tcx.trait_impls_by_self_simplified(trait_id);
+    impls.blanket_impls.extend(local_impls.blanket_impls.iter().map(|def_id| def_id.to_def_id()));
+    for (&simplified_self_ty, local) in &local_impls.non_blanket_impls {
+        impls
+            .non_blanket_impls
+            .entry(simplified_self_ty)
+            .or_default()
+            .extend(local.iter().map(|def_id| def_id.to_def_id()));
+    }
+
+    impls
+}
+
+// Query provider for `trait_impls_by_self_simplified`.
+pub(super) fn trait_impls_by_self_simplified_provider(
+    tcx: TyCtxt<'_>,
+    trait_id: DefId,
+) -> LocalTraitImpls {
+    let mut impls = LocalTraitImpls::default();
 
+    for &impl_def_id in tcx.hir().trait_impls(trait_id) {
         let impl_self_ty = tcx.type_of(impl_def_id);
         if impl_self_ty.references_error() {
             continue;
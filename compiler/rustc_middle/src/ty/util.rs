@@ -25,7 +25,7 @@
 use smallvec::SmallVec;
 use std::{fmt, iter};
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, HashStable)]
 pub struct Discr<'tcx> {
     /// Bit representation of the discriminant (e.g., `-128i8` is `0xFF_u128`).
     pub val: u128,
@@ -70,6 +70,17 @@ fn int_size_and_signed<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> (Size, bool) {
 }
 
 impl<'tcx> Discr<'tcx> {
+    /// Returns the smallest and the largest value of the integer type `ty`.
+    pub fn bounds(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> (Self, Self) {
+        let (size, signed) = int_size_and_signed(tcx, ty);
+        let (min, max) = if signed {
+            (size.truncate(size.signed_int_min() as u128), size.signed_int_max() as u128)
+        } else {
+            (0, size.unsigned_int_max())
+        };
+        (Self { val: min, ty }, Self { val: max, ty })
+    }
+
     /// Adds `1` to the value and wraps around if the maximum for the type is reached.
     pub fn wrap_incr(self, tcx: TyCtxt<'tcx>) -> Self {
         self.checked_add(tcx, 1).0
@@ -561,6 +572,17 @@ pub fn typeck_root_def_id(self, def_id: DefId) -> DefId {
         def_id
     }
 
+    /// Given the `DefId` of a return-position `impl Trait` in a trait (possibly nested inside
+    /// of another one, as in `fn iter(&self) -> impl Iterator<Item = impl Debug>`), returns
+    /// the `DefId` of the trait method whose return type it appears in.
+    pub fn impl_trait_in_trait_parent(self, mut def_id: DefId) -> DefId {
+        while self.def_kind(def_id) != DefKind::AssocFn {
+            debug_assert_eq!(self.def_kind(def_id), DefKind::ImplTraitPlaceholder);
+            def_id = self.parent(def_id);
+        }
+        def_id
+    }
+
     /// Given the `DefId` and substs a closure, creates the type of
     /// `self` argument that the closure expects. For example, for a
     /// `Fn` closure, this would return a reference type `&T` where
@@ -648,6 +670,113 @@ pub fn try_expand_impl_trait_type(
         if visitor.found_recursion { Err(expanded_type) } else { Ok(expanded_type) }
     }
 
+    /// Collects the regions that appear in `ty`, along with the variance of the position each
+    /// of them appears in. For example, `'a` is covariant and `'b` invariant in `&'a mut &'b u8`.
+    /// Regions in positions without a structural variance, like projections and closures, are
+    /// not collected. The arguments of opaque types are looked through using
+    /// `opaque_captured_lifetime_variances`, so this must not be called on a type mentioning an
+    /// opaque type whose hidden type is currently being inferred.
+    pub fn region_variances_in(self, ty: Ty<'tcx>) -> Vec<(ty::Region<'tcx>, ty::Variance)> {
+        let mut variances = vec![];
+        self.collect_region_variances(ty, ty::Covariant, &mut variances);
+        variances
+    }
+
+    fn collect_region_variances(
+        self,
+        ty: Ty<'tcx>,
+        variance: ty::Variance,
+        variances: &mut Vec<(ty::Region<'tcx>, ty::Variance)>,
+    ) {
+        match *ty.kind() {
+            ty::Ref(region, ty, mutbl) => {
+                variances.push((region, variance));
+                let ty_variance = match mutbl {
+                    hir::Mutability::Not => ty::Covariant,
+                    hir::Mutability::Mut => ty::Invariant,
+                };
+                self.collect_region_variances(ty, variance.xform(ty_variance), variances);
+            }
+            ty::RawPtr(ty::TypeAndMut { ty, mutbl }) => {
+                let ty_variance = match mutbl {
+                    hir::Mutability::Not => ty::Covariant,
+                    hir::Mutability::Mut => ty::Invariant,
+                };
+                self.collect_region_variances(ty, variance.xform(ty_variance), variances);
+            }
+            ty::Array(ty, _) | ty::Slice(ty) => {
+                self.collect_region_variances(ty, variance, variances);
+            }
+            ty::Tuple(tys) => {
+                for ty in tys {
+                    self.collect_region_variances(ty, variance, variances);
+                }
+            }
+            ty::Adt(def, substs) => {
+                let arg_variances = self.variances_of(def.did());
+                self.collect_substs_region_variances(substs, arg_variances, variance, variances);
+            }
+            ty::FnPtr(sig) => {
+                let sig = sig.skip_binder();
+                for &input in sig.inputs() {
+                    self.collect_region_variances(
+                        input,
+                        variance.xform(ty::Contravariant),
+                        variances,
+                    );
+                }
+                self.collect_region_variances(sig.output(), variance, variances);
+            }
+            ty::Opaque(def_id, substs) => {
+                let captured_variances = self.opaque_captured_lifetime_variances(def_id);
+                // Only the captured lifetimes are tracked, so conservatively treat any other
+                // argument of the opaque type as invariant.
+                let arg_variances: Vec<_> = iter::zip(substs, captured_variances)
+                    .map(|(arg, &captured_variance)| match arg.unpack() {
+                        GenericArgKind::Lifetime(_) => captured_variance,
+                        GenericArgKind::Type(_) | GenericArgKind::Const(_) => ty::Invariant,
+                    })
+                    .collect();
+                self.collect_substs_region_variances(substs, &arg_variances, variance, variances);
+            }
+            ty::Dynamic(predicates, region) => {
+                variances.push((region, variance));
+                for predicate in predicates {
+                    let substs = match predicate.skip_binder() {
+                        ty::ExistentialPredicate::Trait(trait_ref) => trait_ref.substs,
+                        ty::ExistentialPredicate::Projection(projection) => projection.substs,
+                        ty::ExistentialPredicate::AutoTrait(_) => continue,
+                    };
+                    let arg_variances = vec![ty::Invariant; substs.len()];
+                    self.collect_substs_region_variances(
+                        substs,
+                        &arg_variances,
+                        variance,
+                        variances,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_substs_region_variances(
+        self,
+        substs: SubstsRef<'tcx>,
+        arg_variances: &[ty::Variance],
+        variance: ty::Variance,
+        variances: &mut Vec<(ty::Region<'tcx>, ty::Variance)>,
+    ) {
+        for (arg, &arg_variance) in iter::zip(substs, arg_variances) {
+            let variance = variance.xform(arg_variance);
+            match arg.unpack() {
+                GenericArgKind::Lifetime(region) => variances.push((region, variance)),
+                GenericArgKind::Type(ty) => self.collect_region_variances(ty, variance, variances),
+                GenericArgKind::Const(_) => {}
+            }
+        }
+    }
+
     pub fn bound_type_of(self, def_id: DefId) -> ty::EarlyBinder<Ty<'tcx>> {
         ty::EarlyBinder(self.type_of(def_id))
     }
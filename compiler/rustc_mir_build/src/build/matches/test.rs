@@ -251,7 +251,57 @@ pub(super) fn perform_test(
             }
 
             TestKind::Eq { value, ty } => {
-                if !ty.is_scalar() {
+                let tcx = self.tcx;
+                if ty
+                    .ty_adt_def()
+                    .map_or(false, |def| tcx.is_diagnostic_item(sym::String, def.did()))
+                {
+                    // With `#![feature(deref_patterns)]`, string literals can be used to match
+                    // on `String`s. Compare the literal to the result of
+                    // `<String as Deref>::deref`.
+                    if !tcx.features().deref_patterns {
+                        bug!("matching on `String` without enabling `deref_patterns`");
+                    }
+                    let re_erased = tcx.lifetimes.re_erased;
+                    let ref_string = self.temp(tcx.mk_imm_ref(re_erased, ty), test.span);
+                    let ref_str_ty = tcx.mk_imm_ref(re_erased, tcx.types.str_);
+                    let ref_str = self.temp(ref_str_ty, test.span);
+                    let deref = tcx.require_lang_item(LangItem::Deref, None);
+                    let method = trait_method(tcx, deref, sym::deref, ty, &[]);
+                    let eq_block = self.cfg.start_new_block();
+                    self.cfg.push_assign(
+                        block,
+                        source_info,
+                        ref_string,
+                        Rvalue::Ref(re_erased, BorrowKind::Shared, place),
+                    );
+                    self.cfg.terminate(
+                        block,
+                        source_info,
+                        TerminatorKind::Call {
+                            func: Operand::Constant(Box::new(Constant {
+                                span: test.span,
+                                user_ty: None,
+                                literal: method,
+                            })),
+                            args: vec![Operand::Move(ref_string)],
+                            destination: ref_str,
+                            target: Some(eq_block),
+                            cleanup: None,
+                            from_hir_call: false,
+                            fn_span: source_info.span,
+                        },
+                    );
+                    self.diverge_from(block);
+                    self.non_scalar_compare(
+                        eq_block,
+                        make_target_blocks,
+                        source_info,
+                        value,
+                        ref_str,
+                        ref_str_ty,
+                    );
+                } else if !ty.is_scalar() {
                     // Use `PartialEq::eq` instead of `BinOp::Eq`
                     // (the binop can only handle primitives)
                     self.non_scalar_compare(
@@ -706,6 +706,19 @@ pub(crate) struct LeftArrowOperator {
     pub span: Span,
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(parser::binder_on_assoc_binding)]
+#[help]
+pub(crate) struct BinderOnAssocBinding {
+    #[primary_span]
+    #[label]
+    pub binder_span: Span,
+    #[label(parser::binding_label)]
+    pub binding_span: Span,
+    pub binder: String,
+    pub binding: String,
+}
+
 // SnapshotParser is used to create a snapshot of the parser
 // without causing duplicate errors being emitted when the `Parser`
 // is dropped.
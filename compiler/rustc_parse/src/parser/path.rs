@@ -1,3 +1,4 @@
+use super::diagnostics::BinderOnAssocBinding;
 use super::ty::{AllowPlus, RecoverQPath, RecoverReturnSign};
 use super::{Parser, Restrictions, TokenType};
 use crate::maybe_whole;
@@ -522,19 +523,26 @@ fn parse_angle_arg(
                 let separated =
                     self.check_noexpect(&token::Colon) || self.check_noexpect(&token::Eq);
                 if separated && (self.check(&token::Colon) | self.check(&token::Eq)) {
-                    let arg_span = arg.span();
                     let (binder, ident, gen_args) = match self.get_ident_from_generic_arg(&arg) {
                         Ok(ident_gen_args) => ident_gen_args,
                         Err(()) => return Ok(Some(AngleBracketedArg::Arg(arg))),
                     };
-                    if binder {
-                        // FIXME(compiler-errors): this could be improved by suggesting lifting
-                        // this up to the trait, at least before this becomes real syntax.
-                        // e.g. `Trait<for<'a> Assoc = Ty>` -> `for<'a> Trait<Assoc = Ty>`
-                        return Err(self.struct_span_err(
-                            arg_span,
-                            "`for<...>` is not allowed on associated type bounds",
-                        ));
+                    if let Some(binder_span) = binder {
+                        let binding_span = gen_args
+                            .as_ref()
+                            .map_or(ident.span, |gen_args| ident.span.to(gen_args.span()));
+                        let binder = self
+                            .span_to_snippet(binder_span)
+                            .unwrap_or_else(|_| "for<...>".to_string());
+                        let binding = self
+                            .span_to_snippet(binding_span)
+                            .unwrap_or_else(|_| ident.to_string());
+                        return Err(self.sess.create_err(BinderOnAssocBinding {
+                            binder_span,
+                            binding_span,
+                            binder,
+                            binding,
+                        }));
                     }
                     let kind = if self.eat(&token::Colon) {
                         // Parse associated type constraint bound.
@@ -720,24 +728,28 @@ pub(super) fn parse_generic_arg(
 
     /// Given a arg inside of generics, we try to destructure it as if it were the LHS in
     /// `LHS = ...`, i.e. an associated type binding.
-    /// This returns a bool indicating if there are any `for<'a, 'b>` binder args, the
+    /// This returns the span of the `for<'a, 'b>` binder if there is one, the
     /// identifier, and any GAT arguments.
     fn get_ident_from_generic_arg(
         &self,
         gen_arg: &GenericArg,
-    ) -> Result<(bool, Ident, Option<GenericArgs>), ()> {
+    ) -> Result<(Option<Span>, Ident, Option<GenericArgs>), ()> {
         if let GenericArg::Type(ty) = gen_arg {
             if let ast::TyKind::Path(qself, path) = &ty.kind
                 && qself.is_none()
                 && let [seg] = path.segments.as_slice()
             {
-                return Ok((false, seg.ident, seg.args.as_deref().cloned()));
+                return Ok((None, seg.ident, seg.args.as_deref().cloned()));
             } else if let ast::TyKind::TraitObject(bounds, ast::TraitObjectSyntax::None) = &ty.kind
                 && let [ast::GenericBound::Trait(trait_ref, ast::TraitBoundModifier::None)] =
                     bounds.as_slice()
                 && let [seg] = trait_ref.trait_ref.path.segments.as_slice()
             {
-                return Ok((true, seg.ident, seg.args.as_deref().cloned()));
+                let binder_span = self.sess.source_map().span_through_char(
+                    trait_ref.span.until(trait_ref.trait_ref.path.span),
+                    '>',
+                );
+                return Ok((Some(binder_span), seg.ident, seg.args.as_deref().cloned()));
             }
         }
         Err(())
@@ -125,6 +125,7 @@ fn check_attributes(
                 sym::rustc_lint_opt_deny_field_access => {
                     self.check_rustc_lint_opt_deny_field_access(&attr, span, target)
                 }
+                sym::rustc_do_not_suggest => self.check_rustc_do_not_suggest(&attr, span, target),
                 sym::rustc_clean
                 | sym::rustc_dirty
                 | sym::rustc_if_this_changed
@@ -147,6 +148,7 @@ fn check_attributes(
                 | sym::rustc_allowed_through_unstable_modules
                 | sym::rustc_promotable => self.check_stability_promotable(&attr, span, target),
                 sym::link_ordinal => self.check_link_ordinal(&attr, span, target),
+                sym::unix_sigpipe => self.check_unix_sigpipe(hir_id, &attr, target),
                 _ => true,
             };
             is_valid &= attr_is_valid;
@@ -462,6 +464,12 @@ fn check_non_exhaustive(
                 self.inline_attr_str_error_with_macro_def(hir_id, attr, "non_exhaustive");
                 true
             }
+            // These are reported by typeck with a dedicated error and a removal suggestion.
+            Target::Trait
+            | Target::Impl
+            | Target::AssocConst
+            | Target::AssocTy
+            | Target::Method(_) => true,
             _ => {
                 self.tcx.sess.emit_err(errors::NonExhaustiveWrongLocation {
                     attr_span: attr.span,
@@ -1450,6 +1458,28 @@ fn check_rustc_lint_opt_deny_field_access(
         }
     }
 
+    /// Checks that the `#[rustc_do_not_suggest]` attribute is only applied to an ADT and only
+    /// names known suggestion families.
+    fn check_rustc_do_not_suggest(&self, attr: &Attribute, span: Span, target: Target) -> bool {
+        if !matches!(target, Target::Struct | Target::Enum | Target::Union) {
+            self.tcx.sess.emit_err(errors::RustcDoNotSuggest { attr_span: attr.span, span });
+            return false;
+        }
+
+        let mut is_valid = true;
+        for item in attr.meta_item_list().unwrap_or_default() {
+            if !matches!(item.name_or_empty(), sym::borrow | sym::deref | sym::clone)
+                || !item.is_word()
+            {
+                self.tcx
+                    .sess
+                    .emit_err(errors::RustcDoNotSuggestUnknownFamily { span: item.span() });
+                is_valid = false;
+            }
+        }
+        is_valid
+    }
+
     /// Checks that the dep-graph debugging attributes are only present when the query-dep-graph
     /// option is passed to the compiler.
     fn check_rustc_dirty_clean(&self, attr: &Attribute) -> bool {
@@ -1906,6 +1936,22 @@ fn check_link_ordinal(&self, attr: &Attribute, _span: Span, target: Target) -> b
         }
     }
 
+    /// Checks that `#[unix_sigpipe]` is only applied to the crate-root `fn main()`. Its value is
+    /// validated together with the entry function in `entry.rs`.
+    fn check_unix_sigpipe(&self, hir_id: HirId, attr: &Attribute, target: Target) -> bool {
+        let is_main = target == Target::Fn && self.tcx.hir().name(hir_id) == sym::main;
+        if is_main && self.tcx.hir().get_parent_item(hir_id) == CRATE_DEF_ID {
+            return true;
+        }
+        let (span, attr) = (attr.span, sym::unix_sigpipe);
+        if is_main {
+            self.tcx.sess.emit_err(errors::AttrOnlyOnRootMain { span, attr });
+        } else {
+            self.tcx.sess.emit_err(errors::AttrOnlyOnMain { span, attr });
+        }
+        false
+    }
+
     fn check_deprecated(&self, hir_id: HirId, attr: &Attribute, _span: Span, target: Target) {
         match target {
             Target::Closure | Target::Expression | Target::Statement | Target::Arm => {
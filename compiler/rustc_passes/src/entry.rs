@@ -7,9 +7,12 @@
 use rustc_middle::ty::{DefIdTree, TyCtxt};
 use rustc_session::config::{CrateType, EntryFnType};
 use rustc_session::parse::feature_err;
+use rustc_span::lev_distance::find_best_match_for_name;
 use rustc_span::symbol::sym;
 use rustc_span::{Span, Symbol, DUMMY_SP};
 
+use crate::errors::{UnixSigpipeValueSuggestion, UnixSigpipeValues};
+
 struct EntryContext<'tcx> {
     tcx: TyCtxt<'tcx>,
 
@@ -83,6 +86,26 @@ fn err_if_attr_found(ctxt: &EntryContext<'_>, attrs: &[Attribute], sym: Symbol)
     }
 }
 
+/// Validates the value of `#[unix_sigpipe]` on the crate-root `fn main()`. Where the attribute
+/// may be placed at all is checked in `check_attr.rs`.
+fn check_unix_sigpipe_value(ctxt: &EntryContext<'_>, id: ItemId) {
+    let attrs = ctxt.tcx.hir().attrs(id.hir_id());
+    let Some(attr) = ctxt.tcx.sess.find_by_name(attrs, sym::unix_sigpipe) else { return };
+
+    // A missing value is reported as a malformed attribute by the builtin attribute checks.
+    let Some(value) = attr.value_str() else { return };
+    let valid = [sym::inherit, sym::sig_ign, sym::sig_dfl];
+    if valid.contains(&value) {
+        return;
+    }
+    let suggestion = attr
+        .meta()
+        .and_then(|meta| meta.name_value_literal_span())
+        .zip(find_best_match_for_name(&valid, value, None))
+        .map(|(span, value)| UnixSigpipeValueSuggestion { span, value });
+    ctxt.tcx.sess.emit_err(UnixSigpipeValues { span: attr.span, suggestion });
+}
+
 fn find_item(id: ItemId, ctxt: &mut EntryContext<'_>) {
     let at_root = ctxt.tcx.opt_local_parent(id.def_id) == Some(CRATE_DEF_ID);
 
@@ -93,7 +116,7 @@ fn find_item(id: ItemId, ctxt: &mut EntryContext<'_>) {
             err_if_attr_found(ctxt, attrs, sym::start);
             err_if_attr_found(ctxt, attrs, sym::rustc_main);
         }
-        EntryPointType::MainNamed => (),
+        EntryPointType::MainNamed => check_unix_sigpipe_value(ctxt, id),
         EntryPointType::OtherMain => {
             ctxt.non_main_fns.push(ctxt.tcx.def_span(id.def_id));
         }
@@ -1,6 +1,9 @@
-use rustc_errors::{Applicability, MultiSpan};
+use rustc_errors::{
+    error_code, fluent, Applicability, DiagnosticBuilder, ErrorGuaranteed, MultiSpan,
+};
 use rustc_macros::{LintDiagnostic, SessionDiagnostic, SessionSubdiagnostic};
-use rustc_span::{Span, Symbol};
+use rustc_session::{parse::ParseSess, SessionDiagnostic};
+use rustc_span::{symbol::Ident, Span, Symbol};
 
 #[derive(LintDiagnostic)]
 #[diag(passes::outer_crate_level_attr)]
@@ -641,6 +644,23 @@ pub struct RustcLintOptTy {
     pub span: Span,
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(passes::rustc_do_not_suggest)]
+pub struct RustcDoNotSuggest {
+    #[primary_span]
+    pub attr_span: Span,
+    #[label]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(passes::rustc_do_not_suggest_unknown_family)]
+#[help]
+pub struct RustcDoNotSuggestUnknownFamily {
+    #[primary_span]
+    pub span: Span,
+}
+
 #[derive(SessionDiagnostic)]
 #[diag(passes::rustc_lint_opt_deny_field_access)]
 pub struct RustcLintOptDenyFieldAccess {
@@ -649,3 +669,90 @@ pub struct RustcLintOptDenyFieldAccess {
     #[label]
     pub span: Span,
 }
+
+pub struct BreakNonLoop {
+    pub span: Span,
+    /// The header of the `while`/`for` loop the `break` is targeting.
+    pub head: Option<Span>,
+    pub kind: &'static str,
+    pub suggestion: String,
+    /// The loop label, for when the "value" is actually a misspelled label.
+    pub break_expr_label: Option<(Span, Ident)>,
+    /// Turns a `while cond { .. }` loop into `loop { if !(cond) { break; } .. }`.
+    pub loop_suggestion: Option<Vec<(Span, String)>>,
+}
+
+// Manual implementation of `SessionDiagnostic` to be able to offer a multipart suggestion.
+impl<'a> SessionDiagnostic<'a> for BreakNonLoop {
+    fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuaranteed> {
+        let mut err = sess.span_diagnostic.struct_span_err_with_code(
+            self.span,
+            fluent::passes::break_non_loop,
+            error_code!(E0571),
+        );
+        err.set_arg("kind", self.kind);
+        err.span_label(self.span, fluent::passes::label);
+        if let Some(head) = self.head {
+            err.span_label(head, fluent::passes::label_head);
+        }
+        err.span_suggestion(
+            self.span,
+            fluent::passes::suggestion,
+            self.suggestion,
+            Applicability::MaybeIncorrect,
+        );
+        if let Some((span, label)) = self.break_expr_label {
+            err.span_suggestion(
+                span,
+                fluent::passes::break_expr_suggestion,
+                label,
+                Applicability::MaybeIncorrect,
+            );
+        }
+        if let Some(loop_suggestion) = self.loop_suggestion {
+            err.multipart_suggestion(
+                fluent::passes::loop_suggestion,
+                loop_suggestion,
+                Applicability::HasPlaceholders,
+            );
+        }
+        err
+    }
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(passes::attr_only_on_main)]
+pub struct AttrOnlyOnMain {
+    #[primary_span]
+    pub span: Span,
+    pub attr: Symbol,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(passes::attr_only_on_root_main)]
+pub struct AttrOnlyOnRootMain {
+    #[primary_span]
+    pub span: Span,
+    pub attr: Symbol,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(passes::unix_sigpipe_values)]
+pub struct UnixSigpipeValues {
+    #[primary_span]
+    pub span: Span,
+    #[subdiagnostic]
+    pub suggestion: Option<UnixSigpipeValueSuggestion>,
+}
+
+#[derive(SessionSubdiagnostic)]
+#[suggestion(
+    passes::unix_sigpipe_value_suggestion,
+    code = "\"{value}\"",
+    applicability = "maybe-incorrect"
+)]
+pub struct UnixSigpipeValueSuggestion {
+    #[primary_span]
+    pub span: Span,
+    pub value: Symbol,
+}
@@ -1,6 +1,6 @@
 use Context::*;
 
-use rustc_errors::{struct_span_err, Applicability};
+use rustc_errors::struct_span_err;
 use rustc_hir as hir;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::intravisit::{self, Visitor};
@@ -11,7 +11,9 @@
 use rustc_middle::ty::TyCtxt;
 use rustc_session::Session;
 use rustc_span::hygiene::DesugaringKind;
-use rustc_span::Span;
+use rustc_span::{BytePos, Span};
+
+use crate::errors::BreakNonLoop;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum Context {
@@ -101,55 +103,23 @@ fn visit_expr(&mut self, e: &'hir hir::Expr<'hir>) {
                 }
 
                 if let Some(break_expr) = opt_expr {
-                    let (head, loop_label, loop_kind) = if let Some(loop_id) = loop_id {
+                    let (head, loop_label, loop_kind, loop_block) = if let Some(loop_id) = loop_id {
                         match self.hir_map.expect_expr(loop_id).kind {
-                            hir::ExprKind::Loop(_, label, source, sp) => {
-                                (Some(sp), label, Some(source))
+                            hir::ExprKind::Loop(block, label, source, sp) => {
+                                (Some(sp), label, Some(source), Some(block))
                             }
                             ref r => {
                                 span_bug!(e.span, "break label resolved to a non-loop: {:?}", r)
                             }
                         }
                     } else {
-                        (None, None, None)
+                        (None, None, None, None)
                     };
                     match loop_kind {
                         None | Some(hir::LoopSource::Loop) => (),
                         Some(kind) => {
-                            let mut err = struct_span_err!(
-                                self.sess,
-                                e.span,
-                                E0571,
-                                "`break` with value from a `{}` loop",
-                                kind.name()
-                            );
-                            err.span_label(
-                                e.span,
-                                "can only break with a value inside `loop` or breakable block",
-                            );
-                            if let Some(head) = head {
-                                err.span_label(
-                                    head,
-                                    &format!(
-                                        "you can't `break` with a value in a `{}` loop",
-                                        kind.name()
-                                    ),
-                                );
-                            }
-                            err.span_suggestion(
-                                e.span,
-                                &format!(
-                                    "use `break` on its own without a value inside this `{}` loop",
-                                    kind.name(),
-                                ),
-                                format!(
-                                    "break{}",
-                                    break_label
-                                        .label
-                                        .map_or_else(String::new, |l| format!(" {}", l.ident))
-                                ),
-                                Applicability::MaybeIncorrect,
-                            );
+                            let mut is_redundant = false;
+                            let mut break_expr_label = None;
                             if let (Some(label), None) = (loop_label, break_label.label) {
                                 match break_expr.kind {
                                     hir::ExprKind::Path(hir::QPath::Resolved(
@@ -165,20 +135,35 @@ fn visit_expr(&mut self, e: &'hir hir::Expr<'hir>) {
                                         // This error is redundant, we will have already emitted a
                                         // suggestion to use the label when `segment` wasn't found
                                         // (hence the `Res::Err` check).
-                                        err.delay_as_bug();
-                                    }
-                                    _ => {
-                                        err.span_suggestion(
-                                            break_expr.span,
-                                            "alternatively, you might have meant to use the \
-                                             available loop label",
-                                            label.ident,
-                                            Applicability::MaybeIncorrect,
-                                        );
+                                        is_redundant = true;
                                     }
+                                    _ => break_expr_label = Some((break_expr.span, label.ident)),
+                                }
+                            }
+                            let loop_suggestion = match (kind, head, loop_block) {
+                                (hir::LoopSource::While, Some(head), Some(block)) => {
+                                    self.while_to_loop_suggestion(head, block)
                                 }
+                                _ => None,
+                            };
+                            let err = self.sess.create_err(BreakNonLoop {
+                                span: e.span,
+                                head,
+                                kind: kind.name(),
+                                suggestion: format!(
+                                    "break{}",
+                                    break_label
+                                        .label
+                                        .map_or_else(String::new, |l| format!(" {}", l.ident))
+                                ),
+                                break_expr_label,
+                                loop_suggestion,
+                            });
+                            if is_redundant {
+                                err.delay_as_bug();
+                            } else {
+                                err.emit();
                             }
-                            err.emit();
                         }
                     }
                 }
@@ -274,6 +259,34 @@ fn require_label_in_labeled_block(
         }
         false
     }
+
+    /// Suggests turning `while cond { .. }` into `loop { if !(cond) { break; } .. }`, so that
+    /// the loop can be broken out of with a value.
+    fn while_to_loop_suggestion(
+        &self,
+        head: Span,
+        block: &hir::Block<'_>,
+    ) -> Option<Vec<(Span, String)>> {
+        // `while let` loops can't be rewritten as a negated condition.
+        let Some(hir::Expr { kind: hir::ExprKind::If(cond, then, _), .. }) = block.expr else {
+            return None;
+        };
+        let hir::ExprKind::DropTemps(cond) = cond.kind else { return None };
+        let source_map = self.sess.source_map();
+        let cond_snippet = source_map.span_to_snippet(cond.span).ok()?;
+        // The header also covers the loop label, so look for the keyword right before `cond`.
+        let keyword_offset =
+            source_map.span_to_snippet(head.with_hi(cond.span.lo())).ok()?.rfind("while")?;
+        let keyword_span = head.with_lo(head.lo() + BytePos(keyword_offset as u32));
+        Some(vec![
+            (keyword_span, "loop".to_string()),
+            (
+                then.span.with_lo(then.span.lo() + BytePos(1)).shrink_to_lo(),
+                format!(" if !({cond_snippet}) {{ break /* value */; }}"),
+            ),
+        ])
+    }
+
     fn emit_unlabled_cf_in_while_condition(&mut self, span: Span, cf_type: &str) {
         struct_span_err!(
             self.sess,
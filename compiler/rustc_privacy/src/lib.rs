@@ -596,6 +596,7 @@ fn update_macro_reachable_def(
             | DefKind::ForeignTy
             | DefKind::Fn
             | DefKind::OpaqueTy
+            | DefKind::ImplTraitPlaceholder
             | DefKind::AssocFn
             | DefKind::Trait
             | DefKind::TyParam
@@ -706,7 +707,7 @@ fn visit_item(&mut self, item: &'tcx hir::Item<'tcx>) {
             hir::ItemKind::Use(..) => {}
             // The interface is empty.
             hir::ItemKind::GlobalAsm(..) => {}
-            hir::ItemKind::OpaqueTy(..) => {
+            hir::ItemKind::OpaqueTy(ref opaque) => {
                 // HACK(jynelson): trying to infer the type of `impl trait` breaks `async-std` (and `pub async fn` in general)
                 // Since rustdoc never needs to do codegen and doesn't care about link-time reachability,
                 // mark this as unreachable.
@@ -717,7 +718,13 @@ fn visit_item(&mut self, item: &'tcx hir::Item<'tcx>) {
                     // reachable if they are returned via `impl Trait`, even from private functions.
                     let exist_level =
                         cmp::max(item_level, Some(AccessLevel::ReachableFromImplTrait));
-                    self.reach(item.def_id, exist_level).generics().predicates().ty();
+                    let mut reach = self.reach(item.def_id, exist_level);
+                    reach.generics().predicates();
+                    // `impl Trait` in a trait has no underlying type of its own, only the
+                    // hidden types chosen by the impls of the trait.
+                    if !opaque.in_trait {
+                        reach.ty();
+                    }
                 }
             }
             // Visit everything.
@@ -1856,7 +1863,7 @@ pub fn check_item(&mut self, id: ItemId) {
             DefKind::Const | DefKind::Static(_) | DefKind::Fn | DefKind::TyAlias => {
                 self.check(id.def_id, item_visibility).generics().predicates().ty();
             }
-            DefKind::OpaqueTy => {
+            DefKind::OpaqueTy | DefKind::ImplTraitPlaceholder => {
                 // `ty()` for opaque types is the underlying type,
                 // it's not a part of interface, so we skip it.
                 self.check(id.def_id, item_visibility).generics().bounds();
@@ -2,16 +2,20 @@
 //! generate the actual methods on tcx which find and execute the provider,
 //! manage the caches, and so forth.
 
-use crate::{on_disk_cache, Queries};
+use crate::{on_disk_cache, queries, Queries};
 use rustc_middle::dep_graph::{DepNodeIndex, SerializedDepNodeIndex};
+use rustc_middle::ty::query::report_assoc_ty_path_cycle;
 use rustc_middle::ty::tls::{self, ImplicitCtxt};
 use rustc_middle::ty::TyCtxt;
 use rustc_query_system::dep_graph::HasDepContext;
-use rustc_query_system::query::{QueryContext, QueryJobId, QueryMap, QuerySideEffects};
+use rustc_query_system::query::{
+    QueryConfig, QueryContext, QueryInfo, QueryJobId, QueryMap, QuerySideEffects, QueryStackFrame,
+};
 
 use rustc_data_structures::sync::Lock;
 use rustc_data_structures::thin_vec::ThinVec;
-use rustc_errors::{Diagnostic, Handler};
+use rustc_errors::{Diagnostic, DiagnosticBuilder, ErrorGuaranteed, Handler};
+use rustc_span::Span;
 
 use std::any::Any;
 use std::num::NonZeroU64;
@@ -113,6 +117,23 @@ fn start_query<R>(
             })
         })
     }
+
+    fn report_specific_cycle(
+        &self,
+        usage: &Option<(Span, QueryStackFrame)>,
+        cycle: &[QueryInfo],
+    ) -> Option<DiagnosticBuilder<'_, ErrorGuaranteed>> {
+        // Both of these are started to resolve a path like `T::Assoc` in the bounds being
+        // collected, so a cycle made only of them comes from such paths depending on each other.
+        let resolves_assoc_ty_path = |info: &QueryInfo| {
+            info.query.name == queries::type_param_predicates::NAME
+                || info.query.name == queries::super_predicates_that_define_assoc_type::NAME
+        };
+        if cycle.iter().all(resolves_assoc_ty_path) {
+            return report_assoc_ty_path_cycle(**self, usage, cycle);
+        }
+        None
+    }
 }
 
 impl<'tcx> QueryCtxt<'tcx> {
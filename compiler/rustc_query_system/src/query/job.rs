@@ -1,3 +1,4 @@
+use crate::dep_graph::DepContext;
 use crate::query::plumbing::CycleError;
 use crate::query::{QueryContext, QueryStackFrame};
 use rustc_hir::def::DefKind;
@@ -6,7 +7,6 @@
 use rustc_errors::{
     struct_span_err, Diagnostic, DiagnosticBuilder, ErrorGuaranteed, Handler, Level,
 };
-use rustc_session::Session;
 use rustc_span::Span;
 
 use std::hash::Hash;
@@ -529,12 +529,18 @@ pub fn deadlock(query_map: QueryMap, registry: &rayon_core::Registry) {
 
 #[inline(never)]
 #[cold]
-pub(crate) fn report_cycle<'a>(
-    sess: &'a Session,
+pub(crate) fn report_cycle<'a, CTX: QueryContext>(
+    tcx: &'a CTX,
     CycleError { usage, cycle: stack }: CycleError,
 ) -> DiagnosticBuilder<'a, ErrorGuaranteed> {
     assert!(!stack.is_empty());
 
+    if let Some(err) = tcx.report_specific_cycle(&usage, &stack) {
+        return err;
+    }
+
+    let sess = tcx.dep_context().sess();
+
     let span = stack[0].query.default_span(stack[1 % stack.len()].span);
     let mut err =
         struct_span_err!(sess, span, E0391, "cycle detected when {}", stack[0].query.description);
@@ -18,7 +18,7 @@
 
 use rustc_data_structures::sync::Lock;
 use rustc_data_structures::thin_vec::ThinVec;
-use rustc_errors::Diagnostic;
+use rustc_errors::{Diagnostic, DiagnosticBuilder, ErrorGuaranteed};
 use rustc_hir::def::DefKind;
 use rustc_span::Span;
 
@@ -122,4 +122,12 @@ fn start_query<R>(
         diagnostics: Option<&Lock<ThinVec<Diagnostic>>>,
         compute: impl FnOnce() -> R,
     ) -> R;
+
+    /// Reports the cycle between the queries in `cycle` with a diagnostic specific to those
+    /// queries, or returns `None` to fall back to the generic cycle error.
+    fn report_specific_cycle(
+        &self,
+        usage: &Option<(Span, QueryStackFrame)>,
+        cycle: &[QueryInfo],
+    ) -> Option<DiagnosticBuilder<'_, ErrorGuaranteed>>;
 }
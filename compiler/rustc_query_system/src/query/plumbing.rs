@@ -126,7 +126,7 @@ fn mk_cycle<CTX, V, R>(
     V: std::fmt::Debug,
     R: Clone,
 {
-    let error = report_cycle(tcx.dep_context().sess(), error);
+    let error = report_cycle(&tcx, error);
     let value = handle_cycle_error(tcx, error);
     cache.store_nocache(value)
 }
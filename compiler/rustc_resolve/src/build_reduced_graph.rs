@@ -965,6 +965,7 @@ fn build_reduced_graph_for_external_crate_res(&mut self, child: ModChild) {
                 | DefKind::TyAlias
                 | DefKind::ForeignTy
                 | DefKind::OpaqueTy
+                | DefKind::ImplTraitPlaceholder
                 | DefKind::TraitAlias
                 | DefKind::AssocTy,
                 _,
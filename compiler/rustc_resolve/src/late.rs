@@ -535,6 +535,14 @@ struct DiagnosticMetadata<'ast> {
     /// Accumulate the errors due to missed lifetime elision,
     /// and report them all at once for each function.
     current_elision_failures: Vec<MissingLifetime>,
+
+    /// Accumulate the missing lifetimes in the fields of the current ADT, so that they are
+    /// reported with a single suggestion introducing the lifetime parameter.
+    current_adt_missing_lifetimes: Option<Vec<MissingLifetime>>,
+
+    /// Accumulate the elided lifetimes in the paths of the current impl header, so that they
+    /// are reported with a single suggestion.
+    current_impl_header_elided_lifetimes: Option<Vec<(Span, MissingLifetime)>>,
 }
 
 struct LateResolutionVisitor<'a, 'b, 'ast> {
@@ -848,6 +856,10 @@ fn visit_fn(&mut self, fn_kind: FnKind<'ast>, sp: Span, fn_id: NodeId) {
                             },
                         );
 
+                        if let FnKind::Fn(FnCtxt::Free, ..) = fn_kind && declaration.has_self() {
+                            this.report_self_param_in_free_fn(declaration, sp);
+                        }
+
                         // Construct the list of in-scope lifetime parameters for async lowering.
                         // We include all lifetime parameters, either named or "Fresh".
                         // The order of those parameters does not matter, as long as it is
@@ -1570,7 +1582,27 @@ fn resolve_anonymous_lifetime(&mut self, lifetime: &Lifetime, elided: bool) {
             }
         }
         self.record_lifetime_res(lifetime.id, LifetimeRes::Error, elision_candidate);
-        self.report_missing_lifetime_specifiers(vec![missing_lifetime], None);
+        self.report_or_defer_missing_lifetime(missing_lifetime);
+    }
+
+    fn report_or_defer_missing_lifetime(&mut self, missing_lifetime: MissingLifetime) {
+        if let Some(missing) = &mut self.diagnostic_metadata.current_adt_missing_lifetimes {
+            missing.push(missing_lifetime);
+        } else {
+            self.report_missing_lifetime_specifiers(vec![missing_lifetime], None);
+        }
+    }
+
+    fn report_or_defer_elided_lifetime_in_impl_header(
+        &mut self,
+        path_span: Span,
+        missing_lifetime: MissingLifetime,
+    ) {
+        if let Some(elided) = &mut self.diagnostic_metadata.current_impl_header_elided_lifetimes {
+            elided.push((path_span, missing_lifetime));
+        } else {
+            self.report_elided_lifetimes_in_paths(vec![(path_span, missing_lifetime)]);
+        }
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
@@ -1702,23 +1734,10 @@ fn resolve_elided_lifetimes_in_path(
                     //     impl Foo for std::cell::Ref<u32> // note lack of '_
                     //     async fn foo(_: std::cell::Ref<u32>) { ... }
                     LifetimeRibKind::AnonymousCreateParameter { report_in_path: true, .. } => {
-                        let sess = self.r.session;
-                        let mut err = rustc_errors::struct_span_err!(
-                            sess,
-                            path_span,
-                            E0726,
-                            "implicit elided lifetime not allowed here"
-                        );
-                        rustc_errors::add_elided_lifetime_in_path_suggestion(
-                            sess.source_map(),
-                            &mut err,
-                            expected_lifetimes,
+                        self.report_or_defer_elided_lifetime_in_impl_header(
                             path_span,
-                            !segment.has_generic_args,
-                            elided_lifetime_span,
+                            missing_lifetime,
                         );
-                        err.note("assuming a `'static` lifetime...");
-                        err.emit();
                         should_lint = false;
 
                         for id in node_ids {
@@ -1778,7 +1797,7 @@ fn resolve_elided_lifetimes_in_path(
                                 LifetimeElisionCandidate::Ignore,
                             );
                         }
-                        self.report_missing_lifetime_specifiers(vec![missing_lifetime], None);
+                        self.report_or_defer_missing_lifetime(missing_lifetime);
                         break;
                     }
                     LifetimeRibKind::Generics { .. }
@@ -2079,12 +2098,23 @@ fn resolve_adt(&mut self, item: &'ast Item, generics: &'ast Generics) {
                 },
                 |this| {
                     let item_def_id = this.r.local_def_id(item.id).to_def_id();
+                    let outer_missing = replace(
+                        &mut this.diagnostic_metadata.current_adt_missing_lifetimes,
+                        Some(vec![]),
+                    );
                     this.with_self_rib(
                         Res::SelfTy { trait_: None, alias_to: Some((item_def_id, false)) },
                         |this| {
                             visit::walk_item(this, item);
                         },
                     );
+                    let missing = replace(
+                        &mut this.diagnostic_metadata.current_adt_missing_lifetimes,
+                        outer_missing,
+                    );
+                    if let Some(missing) = missing.filter(|missing| !missing.is_empty()) {
+                        this.report_missing_lifetime_specifiers(missing, None);
+                    }
                 },
             );
         });
@@ -2635,6 +2665,10 @@ fn resolve_implementation(
                                         alias_to: Some((item_def_id, false)),
                                     };
                                     this.with_self_rib(res, |this| {
+                                        let outer_elided = replace(
+                                            &mut this.diagnostic_metadata.current_impl_header_elided_lifetimes,
+                                            Some(vec![]),
+                                        );
                                         if let Some(trait_ref) = opt_trait_reference.as_ref() {
                                             // Resolve type arguments in the trait path.
                                             visit::walk_trait_ref(this, trait_ref);
@@ -2643,6 +2677,13 @@ fn resolve_implementation(
                                         this.visit_ty(self_type);
                                         // Resolve the generic parameters.
                                         this.visit_generics(generics);
+                                        let elided = replace(
+                                            &mut this.diagnostic_metadata.current_impl_header_elided_lifetimes,
+                                            outer_elided,
+                                        );
+                                        if let Some(elided) = elided.filter(|elided| !elided.is_empty()) {
+                                            this.report_elided_lifetimes_in_paths(elided);
+                                        }
 
                                         // Resolve the items within the impl.
                                         this.with_current_self_type(self_type, |this| {
@@ -14,7 +14,7 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::{
     pluralize, struct_span_err, Applicability, Diagnostic, DiagnosticBuilder, ErrorGuaranteed,
-    MultiSpan,
+    MultiSpan, StashKey,
 };
 use rustc_hir as hir;
 use rustc_hir::def::Namespace::{self, *};
@@ -1839,6 +1839,52 @@ fn suggest_using_enum_variant(
         }
     }
 
+    /// AST validation stashes the error for a `self` parameter of a free function. Now that the
+    /// receiver type is resolved, suggest moving the function into an `impl` block for it.
+    pub(crate) fn report_self_param_in_free_fn(&self, decl: &ast::FnDecl, item_span: Span) {
+        let Some(param) = decl.inputs.first() else { return };
+        let stashed =
+            self.r.session.diagnostic().steal_diagnostic(param.span, StashKey::SelfParamInFreeFn);
+        let Some(mut err) = stashed else { return };
+
+        // Moving the function only helps if its receiver is `Self` or a reference to it, i.e.
+        // `self`, `&self` or a local type that can be named in the `impl` skeleton.
+        let mut self_ty = &*param.ty;
+        while let TyKind::Rptr(_, mut_ty) = &self_ty.kind {
+            self_ty = &mut_ty.ty;
+        }
+        let self_res = self
+            .r
+            .partial_res_map
+            .get(&self_ty.id)
+            .filter(|partial_res| partial_res.unresolved_segments() == 0)
+            .map(|partial_res| partial_res.base_res());
+        let suggestion = match (&self_ty.kind, self_res) {
+            (TyKind::ImplicitSelf, _) => Some(("Type".to_string(), Applicability::HasPlaceholders)),
+            (
+                TyKind::Path(None, _),
+                Some(Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union, def_id)),
+            ) if def_id.is_local() => {
+                let snippet = self.r.session.source_map().span_to_snippet(self_ty.span).ok();
+                snippet.map(|receiver_ty| (receiver_ty, Applicability::MaybeIncorrect))
+            }
+            _ => None,
+        };
+        if let Some((receiver_ty, applicability)) = suggestion {
+            let indent =
+                self.r.session.source_map().indentation_before(item_span).unwrap_or_default();
+            err.multipart_suggestion(
+                "consider moving this function into an `impl` block",
+                vec![
+                    (item_span.shrink_to_lo(), format!("impl {receiver_ty} {{\n{indent}    ")),
+                    (item_span.shrink_to_hi(), format!("\n{indent}}}")),
+                ],
+                applicability,
+            );
+        }
+        err.emit();
+    }
+
     pub(crate) fn report_missing_type_error(
         &self,
         path: &[Segment],
@@ -2180,6 +2226,55 @@ pub(crate) fn report_missing_lifetime_specifiers(
         err.emit()
     }
 
+    /// Reports elided lifetimes in paths where they aren't allowed, e.g. in an impl header,
+    /// with a single suggestion indicating all of them.
+    pub(crate) fn report_elided_lifetimes_in_paths(
+        &mut self,
+        elided: Vec<(Span, MissingLifetime)>,
+    ) -> ErrorGuaranteed {
+        let sess = self.r.session;
+        let spans: Vec<_> = elided.iter().map(|&(path_span, _)| path_span).collect();
+        let mut err =
+            struct_span_err!(sess, spans, E0726, "implicit elided lifetime not allowed here");
+        if let [(path_span, lt)] = elided[..] {
+            rustc_errors::add_elided_lifetime_in_path_suggestion(
+                sess.source_map(),
+                &mut err,
+                lt.count,
+                path_span,
+                lt.kind == MissingLifetimeKind::Brackets,
+                lt.span,
+            );
+        } else {
+            let mut suggestion = vec![];
+            for &(path_span, lt) in &elided {
+                err.span_label(
+                    path_span,
+                    format!("expected lifetime parameter{}", pluralize!(lt.count)),
+                );
+                // Do not try to suggest anything if generated by a proc-macro.
+                if sess.source_map().is_span_accessible(lt.span) {
+                    let anon_lts = vec!["'_"; lt.count].join(", ");
+                    let code = if lt.kind == MissingLifetimeKind::Brackets {
+                        format!("<{}>", anon_lts)
+                    } else {
+                        format!("{}, ", anon_lts)
+                    };
+                    suggestion.push((lt.span.shrink_to_hi(), code));
+                }
+            }
+            if !suggestion.is_empty() {
+                err.multipart_suggestion_verbose(
+                    "indicate the anonymous lifetimes",
+                    suggestion,
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+        err.note("assuming a `'static` lifetime...");
+        err.emit()
+    }
+
     pub(crate) fn add_missing_lifetime_specifiers_label(
         &mut self,
         err: &mut Diagnostic,
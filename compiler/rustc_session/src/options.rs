@@ -368,6 +368,7 @@ mod desc {
     pub const parse_list_with_polarity: &str =
         "a comma-separated list of strings, with elements beginning with + or -";
     pub const parse_opt_comma_list: &str = "a comma-separated list of strings";
+    pub const parse_comma_list: &str = "a comma-separated list of strings";
     pub const parse_number: &str = "a number";
     pub const parse_opt_number: &str = parse_number;
     pub const parse_threads: &str = parse_number;
@@ -567,6 +568,16 @@ pub(crate) fn parse_location_detail(ld: &mut LocationDetail, v: Option<&str>) ->
         }
     }
 
+    pub(crate) fn parse_comma_list(slot: &mut Vec<String>, v: Option<&str>) -> bool {
+        match v {
+            Some(s) => {
+                slot.extend(s.split(',').map(|s| s.to_string()));
+                true
+            }
+            None => false,
+        }
+    }
+
     pub(crate) fn parse_opt_comma_list(slot: &mut Option<Vec<String>>, v: Option<&str>) -> bool {
         match v {
             Some(s) => {
@@ -1258,12 +1269,21 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "emit line numbers debug info inside macros (default: no)"),
     deduplicate_diagnostics: bool = (true, parse_bool, [UNTRACKED],
         "deduplicate identical diagnostics (default: yes)"),
+    deny_slug: Vec<String> = (Vec::new(), parse_comma_list, [TRACKED],
+        "report warnings with the given diagnostic slugs (e.g. `typeck::unused_extern_crate`) \
+        as errors"),
     dep_info_omit_d_target: bool = (false, parse_bool, [TRACKED],
         "in dep-info output, omit targets for tracking dependencies of the dep-info files \
         themselves (default: no)"),
     dep_tasks: bool = (false, parse_bool, [UNTRACKED],
         "print tasks that execute and the color their dep node gets (requires debug build) \
         (default: no)"),
+    design_hints: bool = (false, parse_bool, [TRACKED],
+        "add notes suggesting alternative type designs to some errors, e.g. using `Cow` \
+        in types that cannot be `Copy` (default: no)"),
+    diag_no_budget: bool = (false, parse_bool, [TRACKED],
+        "never skip expensive additional context in diagnostics, even when computing it \
+        exceeds its budget (default: no)"),
     dlltool: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
         "import library generation tool (windows-gnu only)"),
     dont_buffer_diagnostics: bool = (false, parse_bool, [UNTRACKED],
@@ -1367,6 +1387,9 @@ pub(crate) fn parse_proc_macro_execution_strategy(
     #[rustc_lint_opt_deny_field_access("use `Session::instrument_mcount` instead of this field")]
     instrument_mcount: bool = (false, parse_bool, [TRACKED],
         "insert function instrument code for mcount-based tracing (default: no)"),
+    json_diagnostic_records: bool = (false, parse_bool, [UNTRACKED],
+        "include the Fluent identifier and arguments of each diagnostic in JSON output \
+        (default: no)"),
     keep_hygiene_data: bool = (false, parse_bool, [UNTRACKED],
         "keep hygiene data after analysis (default: no)"),
     link_native_libraries: bool = (true, parse_bool, [UNTRACKED],
@@ -16,7 +16,7 @@
     self, AtomicU64, AtomicUsize, Lock, Lrc, OnceCell, OneThread, Ordering, Ordering::SeqCst,
 };
 use rustc_errors::annotate_snippet_emitter_writer::AnnotateSnippetEmitterWriter;
-use rustc_errors::emitter::{Emitter, EmitterWriter, HumanReadableErrorType};
+use rustc_errors::emitter::{ColorConfig, Emitter, EmitterWriter, HumanReadableErrorType};
 use rustc_errors::json::JsonEmitter;
 use rustc_errors::registry::Registry;
 use rustc_errors::{
@@ -1182,6 +1182,16 @@ pub fn link_dead_code(&self) -> bool {
     }
 }
 
+/// Whether the JSON emitter should include the Fluent identifier and arguments of each
+/// diagnostic. `--json=diagnostic-rendered-ansi` is meant for tools that show the colored
+/// rendering but still inspect the diagnostic, so with `-Z unstable-options` it implies the
+/// unstable structured record as well. Without it, the stable output is left unchanged.
+fn json_diagnostic_records(sopts: &config::Options, json_rendered: HumanReadableErrorType) -> bool {
+    let (_, color_config) = json_rendered.unzip();
+    sopts.unstable_opts.json_diagnostic_records
+        || (sopts.unstable_opts.unstable_options && color_config == ColorConfig::Always)
+}
+
 // JUSTIFICATION: part of session construction
 #[allow(rustc::bad_opt_access)]
 fn default_emitter(
@@ -1244,7 +1254,8 @@ fn default_emitter(
                 sopts.diagnostic_width,
                 macro_backtrace,
             )
-            .ui_testing(sopts.unstable_opts.ui_testing),
+            .ui_testing(sopts.unstable_opts.ui_testing)
+            .diagnostic_records(json_diagnostic_records(sopts, json_rendered)),
         ),
         (config::ErrorOutputType::Json { pretty, json_rendered }, Some(dst)) => Box::new(
             JsonEmitter::new(
@@ -1258,7 +1269,8 @@ fn default_emitter(
                 sopts.diagnostic_width,
                 macro_backtrace,
             )
-            .ui_testing(sopts.unstable_opts.ui_testing),
+            .ui_testing(sopts.unstable_opts.ui_testing)
+            .diagnostic_records(json_diagnostic_records(sopts, json_rendered)),
         ),
     }
 }
@@ -1336,7 +1348,8 @@ pub fn build_session(
     let span_diagnostic = rustc_errors::Handler::with_emitter_and_flags(
         emitter,
         sopts.unstable_opts.diagnostic_handler_flags(can_emit_warnings),
-    );
+    )
+    .with_deny_slugs(sopts.unstable_opts.deny_slug.clone());
 
     let self_profiler = if let SwitchWithOptPath::Enabled(ref d) = sopts.unstable_opts.self_profile
     {
@@ -390,6 +390,7 @@
         assume_init,
         async_await,
         async_closure,
+        async_fn_in_trait,
         atomic,
         atomic_mod,
         atomics,
@@ -421,6 +422,7 @@
         black_box,
         block,
         bool,
+        borrow,
         borrowck_graphviz_format,
         borrowck_graphviz_postflow,
         borrowck_graphviz_preflow,
@@ -606,6 +608,7 @@
         deref,
         deref_method,
         deref_mut,
+        deref_patterns,
         deref_target,
         derive,
         derive_default_enum,
@@ -769,6 +772,7 @@
         generic_associated_types_extended,
         generic_const_exprs,
         generic_param_attrs,
+        get,
         get_context,
         global_allocator,
         global_asm,
@@ -801,6 +805,7 @@
         impl_lint_pass,
         impl_macros,
         impl_trait_in_bindings,
+        impl_trait_in_consts,
         implied_by,
         import,
         import_shadowing,
@@ -818,6 +823,7 @@
         infer_outlives_requirements,
         infer_static_outlives_requirements,
         inherent_associated_types,
+        inherit,
         inlateout,
         inline,
         inline_const,
@@ -1174,6 +1180,7 @@
         require,
         residual,
         result,
+        return_position_impl_trait_in_trait,
         rhs,
         rintf32,
         rintf64,
@@ -1213,17 +1220,20 @@
         rustc_builtin_macro,
         rustc_capture_analysis,
         rustc_clean,
+        rustc_coerce_unsized_multi_field,
         rustc_coherence_is_core,
         rustc_const_stable,
         rustc_const_unstable,
         rustc_conversion_suggestion,
         rustc_deallocator,
         rustc_def_path,
+        rustc_deny_explicit_impl,
         rustc_default_body_unstable,
         rustc_diagnostic_item,
         rustc_diagnostic_macros,
         rustc_dirty,
         rustc_do_not_const_check,
+        rustc_do_not_suggest,
         rustc_dummy,
         rustc_dump_env_program_clauses,
         rustc_dump_program_clauses,
@@ -1298,6 +1308,8 @@
         should_panic,
         shr,
         shr_assign,
+        sig_dfl,
+        sig_ign,
         simd,
         simd_add,
         simd_and,
@@ -1493,6 +1505,7 @@
         type_length_limit,
         type_macros,
         type_name,
+        typeof_expr,
         u128,
         u16,
         u32,
@@ -1516,6 +1529,7 @@
         unit,
         universal_impl_trait,
         unix,
+        unix_sigpipe,
         unlikely,
         unmarked_api,
         unpin,
@@ -1,6 +1,6 @@
+use crate::errors::AutoDerefReachedRecursionLimit;
 use crate::traits::query::evaluate_obligation::InferCtxtExt;
 use crate::traits::{self, TraitEngine};
-use rustc_errors::struct_span_err;
 use rustc_hir as hir;
 use rustc_infer::infer::InferCtxt;
 use rustc_middle::ty::{self, TraitRef, Ty, TyCtxt};
@@ -55,7 +55,14 @@ fn next(&mut self) -> Option<Self::Item> {
         // If we have reached the recursion limit, error gracefully.
         if !tcx.recursion_limit().value_within_limit(self.state.steps.len()) {
             if !self.silence_errors {
-                report_autoderef_recursion_limit_error(tcx, self.span, self.state.cur_ty);
+                let deref_chain: Vec<_> =
+                    self.state.steps.iter().map(|&(ty, _)| ty).chain([self.state.cur_ty]).collect();
+                report_autoderef_recursion_limit_error(
+                    tcx,
+                    self.span,
+                    self.state.cur_ty,
+                    &deref_chain,
+                );
             }
             self.state.reached_recursion_limit = true;
             return None;
@@ -216,25 +223,35 @@ pub fn silence_errors(mut self) -> Self {
     }
 }
 
-pub fn report_autoderef_recursion_limit_error<'tcx>(tcx: TyCtxt<'tcx>, span: Span, ty: Ty<'tcx>) {
+pub fn report_autoderef_recursion_limit_error<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    span: Span,
+    ty: Ty<'tcx>,
+    deref_chain: &[Ty<'tcx>],
+) {
     // We've reached the recursion limit, error gracefully.
     let suggested_limit = match tcx.recursion_limit() {
         Limit(0) => Limit(2),
         limit => limit * 2,
     };
-    struct_span_err!(
-        tcx.sess,
+    tcx.sess.emit_err(AutoDerefReachedRecursionLimit {
         span,
-        E0055,
-        "reached the recursion limit while auto-dereferencing `{:?}`",
-        ty
-    )
-    .span_label(span, "deref recursion limit reached")
-    .help(&format!(
-        "consider increasing the recursion limit by adding a \
-             `#![recursion_limit = \"{}\"]` attribute to your crate (`{}`)",
-        suggested_limit,
-        tcx.crate_name(LOCAL_CRATE),
-    ))
-    .emit();
+        ty,
+        deref_chain: format_deref_chain(deref_chain),
+        suggested_limit: suggested_limit.0,
+        crate_name: tcx.crate_name(LOCAL_CRATE),
+    });
+}
+
+/// Formats the types auto-dereferencing went through, eliding the middle of long chains.
+fn format_deref_chain(deref_chain: &[Ty<'_>]) -> String {
+    const SHOWN: usize = 3;
+    let format =
+        |tys: &[Ty<'_>]| tys.iter().map(|ty| format!("`{ty}`")).collect::<Vec<_>>().join(" -> ");
+    if deref_chain.len() <= 2 * SHOWN + 1 {
+        format(deref_chain)
+    } else {
+        let (head, tail) = (&deref_chain[..SHOWN], &deref_chain[deref_chain.len() - SHOWN..]);
+        format!("{} -> ... -> {}", format(head), format(tail))
+    }
 }
@@ -0,0 +1,17 @@
+use rustc_macros::SessionDiagnostic;
+use rustc_middle::ty::Ty;
+use rustc_span::{Span, Symbol};
+
+#[derive(SessionDiagnostic)]
+#[diag(trait_selection::auto_deref_reached_recursion_limit, code = "E0055")]
+#[note]
+#[help]
+pub struct AutoDerefReachedRecursionLimit<'tcx> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    pub deref_chain: String,
+    pub suggested_limit: usize,
+    pub crate_name: Symbol,
+}
@@ -35,5 +35,6 @@
 extern crate smallvec;
 
 pub mod autoderef;
+mod errors;
 pub mod infer;
 pub mod traits;
@@ -76,6 +76,58 @@ pub fn overlapping_impls<F1, F2, R>(
     F1: FnOnce(OverlapResult<'_>) -> R,
     F2: FnOnce() -> R,
 {
+    // The outcome of unifying the headers of two trait impls under the usual settings only
+    // depends on the impls, so it is cached by the `impls_overlap` query. Anything else is
+    // rarely asked for more than once and computed directly.
+    let trait_def_id = tcx.trait_id_of_impl(impl1_def_id);
+    let overlaps = match trait_def_id {
+        Some(trait_def_id)
+            if skip_leak_check.is_yes() && overlap_mode == OverlapMode::get(tcx, trait_def_id) =>
+        {
+            // Overlap is symmetric, so both orders of a pair share one cache entry.
+            let key = if impl1_def_id < impl2_def_id {
+                (impl1_def_id, impl2_def_id)
+            } else {
+                (impl2_def_id, impl1_def_id)
+            };
+            tcx.impls_overlap(key)
+        }
+        _ => impl_headers_overlap(tcx, impl1_def_id, impl2_def_id, skip_leak_check, overlap_mode),
+    };
+
+    if !overlaps {
+        return no_overlap();
+    }
+
+    // In the case where we detect an error, run the check again, but
+    // this time tracking intercrate ambiguity causes for better
+    // diagnostics. (These take time and can lead to false errors.)
+    tcx.infer_ctxt().enter(|infcx| {
+        let selcx = &mut SelectionContext::intercrate(&infcx);
+        selcx.enable_tracking_intercrate_ambiguity_causes();
+        on_overlap(
+            overlap(selcx, skip_leak_check, impl1_def_id, impl2_def_id, overlap_mode).unwrap(),
+        )
+    })
+}
+
+/// Provider for the `impls_overlap` query: whether the headers of two trait impls can be
+/// unified, skipping the leak check and using the overlap mode of their trait.
+pub(crate) fn impls_overlap(tcx: TyCtxt<'_>, (impl1_def_id, impl2_def_id): (DefId, DefId)) -> bool {
+    let trait_def_id = tcx.trait_id_of_impl(impl1_def_id).unwrap();
+    let overlap_mode = OverlapMode::get(tcx, trait_def_id);
+    impl_headers_overlap(tcx, impl1_def_id, impl2_def_id, SkipLeakCheck::Yes, overlap_mode)
+}
+
+/// Whether there are types that satisfy the headers of both impls, without tracking the
+/// causes of intercrate ambiguity.
+fn impl_headers_overlap(
+    tcx: TyCtxt<'_>,
+    impl1_def_id: DefId,
+    impl2_def_id: DefId,
+    skip_leak_check: SkipLeakCheck,
+    overlap_mode: OverlapMode,
+) -> bool {
     // Before doing expensive operations like entering an inference context, do
     // a quick check via fast_reject to tell if the impl headers could possibly
     // unify.
@@ -96,27 +148,12 @@ pub fn overlapping_impls<F1, F2, R>(
     if !may_overlap {
         // Some types involved are definitely different, so the impls couldn't possibly overlap.
         debug!("overlapping_impls: fast_reject early-exit");
-        return no_overlap();
-    }
-
-    let overlaps = tcx.infer_ctxt().enter(|infcx| {
-        let selcx = &mut SelectionContext::intercrate(&infcx);
-        overlap(selcx, skip_leak_check, impl1_def_id, impl2_def_id, overlap_mode).is_some()
-    });
-
-    if !overlaps {
-        return no_overlap();
+        return false;
     }
 
-    // In the case where we detect an error, run the check again, but
-    // this time tracking intercrate ambiguity causes for better
-    // diagnostics. (These take time and can lead to false errors.)
     tcx.infer_ctxt().enter(|infcx| {
         let selcx = &mut SelectionContext::intercrate(&infcx);
-        selcx.enable_tracking_intercrate_ambiguity_causes();
-        on_overlap(
-            overlap(selcx, skip_leak_check, impl1_def_id, impl2_def_id, overlap_mode).unwrap(),
-        )
+        overlap(selcx, skip_leak_check, impl1_def_id, impl2_def_id, overlap_mode).is_some()
     })
 }
 
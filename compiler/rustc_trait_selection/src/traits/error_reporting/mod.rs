@@ -667,6 +667,11 @@ fn report_selection_error(
                                 obligation.cause.body_id,
                             );
                         } else if !suggested {
+                            self.suggest_impl_with_other_defaulted_args(
+                                &mut err,
+                                &obligation,
+                                trait_predicate,
+                            );
                             // Can't show anything else useful, try to find similar impls.
                             let impl_candidates = self.find_similar_impl_candidates(trait_predicate);
                             if !self.report_similar_impl_candidates(
@@ -1035,6 +1040,26 @@ fn report_selection_error(
                         let mut err =
                             self.tcx.sess.struct_span_err(span, "unconstrained generic constant");
                         let const_span = self.tcx.def_span(uv.def.did);
+                        // When a where clause of this item needs a constant expression from the
+                        // bounds of another item, point out that the where clause itself is
+                        // what is not well-formed.
+                        let in_where_clause = self
+                            .tcx
+                            .hir()
+                            .find(obligation.cause.body_id)
+                            .and_then(|node| node.generics())
+                            .map_or(false, |generics| {
+                                generics.predicates.iter().any(|predicate| {
+                                    predicate.in_where_clause() && predicate.span().contains(span)
+                                })
+                            });
+                        if in_where_clause && !span.contains(const_span) {
+                            err.span_label(
+                                span,
+                                "this where clause requires a constant expression that is not \
+                                 known to be evaluatable",
+                            );
+                        }
                         match self.tcx.sess.source_map().span_to_snippet(const_span) {
                             Ok(snippet) => err.help(&format!(
                                 "try adding a `where` bound using this expression: `where [(); {}]:`",
@@ -1367,6 +1392,16 @@ fn report_similar_impl_candidates(
         err: &mut Diagnostic,
     ) -> bool;
 
+    /// Points at an impl of the unsatisfied trait for the same type that only differs in
+    /// arguments for defaulted parameters, e.g. `impl Add<Feet> for Meters` when `Meters: Add`
+    /// was required, and suggests writing those arguments where the trait was named.
+    fn suggest_impl_with_other_defaulted_args(
+        &self,
+        err: &mut Diagnostic,
+        obligation: &PredicateObligation<'tcx>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    );
+
     /// Gets the parent trait chain start
     fn get_parent_trait_ref(
         &self,
@@ -1406,6 +1441,12 @@ fn predicate_can_apply(
 
     fn note_obligation_cause(&self, err: &mut Diagnostic, obligation: &PredicateObligation<'tcx>);
 
+    fn note_default_method_body(
+        &self,
+        err: &mut Diagnostic,
+        obligation: &PredicateObligation<'tcx>,
+    );
+
     fn suggest_unsized_bound_if_applicable(
         &self,
         err: &mut Diagnostic,
@@ -1909,6 +1950,105 @@ fn report_similar_impl_candidates(
         report(normalized_impl_candidates, err)
     }
 
+    fn suggest_impl_with_other_defaulted_args(
+        &self,
+        err: &mut Diagnostic,
+        obligation: &PredicateObligation<'tcx>,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        let trait_pred = self.resolve_vars_if_possible(trait_pred);
+        if trait_pred.has_escaping_bound_vars() || trait_pred.needs_infer() {
+            return;
+        }
+        let trait_ref = trait_pred.skip_binder().trait_ref;
+        let generics = tcx.generics_of(trait_ref.def_id);
+        let defaulted: Vec<_> = generics
+            .params
+            .iter()
+            .filter(|param| {
+                matches!(param.kind, ty::GenericParamDefKind::Type { has_default: true, .. })
+            })
+            .collect();
+        if defaulted.is_empty() {
+            return;
+        }
+
+        // Only consider local impls that agree with the obligation everywhere except in
+        // parameters that have a default, and that name concrete types for those. Foreign
+        // impls (think of all the `Add` impls for integers) would mostly be noise.
+        let mut candidates = tcx.all_impls(trait_ref.def_id).filter_map(|impl_def_id| {
+            if !impl_def_id.is_local()
+                || tcx.impl_polarity(impl_def_id) == ty::ImplPolarity::Negative
+            {
+                return None;
+            }
+            let impl_trait_ref = tcx.impl_trait_ref(impl_def_id)?;
+            if impl_trait_ref.self_ty() != trait_ref.self_ty() {
+                return None;
+            }
+            let differing: Vec<_> = iter::zip(impl_trait_ref.substs, trait_ref.substs)
+                .enumerate()
+                .filter(|(_, (impl_arg, arg))| impl_arg != arg)
+                .map(|(index, _)| index as u32)
+                .collect();
+            if differing.is_empty()
+                || !differing.iter().all(|index| {
+                    defaulted.iter().any(|param| param.index == *index)
+                        && !impl_trait_ref.substs[*index as usize].needs_subst()
+                })
+            {
+                return None;
+            }
+            Some((impl_def_id, impl_trait_ref, differing))
+        });
+        // Don't guess between several near-misses.
+        let (Some((impl_def_id, impl_trait_ref, differing)), None) =
+            (candidates.next(), candidates.next()) else { return; };
+
+        let impl_path = impl_trait_ref.print_only_trait_path().to_string();
+        let msg = format!(
+            "`{}` implements `{}`, which only differs in the defaulted parameter{}",
+            trait_ref.self_ty(),
+            impl_path,
+            pluralize!(differing.len()),
+        );
+        err.span_note(tcx.def_span(impl_def_id), &msg);
+
+        // If the trait was named in a bound, suggest spelling out the arguments there.
+        let span = match *obligation.cause.code() {
+            ObligationCauseCode::BindingObligation(_, span)
+            | ObligationCauseCode::ExprBindingObligation(_, span, ..) => span,
+            _ => return,
+        };
+        if span.from_expansion() {
+            return;
+        }
+        let trait_name = tcx.item_name(trait_ref.def_id);
+        let Ok(snippet) = tcx.sess.source_map().span_to_snippet(span) else { return };
+        if !snippet.starts_with(trait_name.as_str()) {
+            return;
+        }
+        // The impl may instead be naming the defaults explicitly while the bound
+        // specifies other arguments, in which case they can simply be dropped.
+        let names_defaults = differing.iter().all(|index| {
+            let param = generics.param_at(*index as usize, tcx);
+            tcx.bound_type_of(param.def_id).subst(tcx, impl_trait_ref.substs)
+                == impl_trait_ref.substs.type_at(*index as usize)
+        });
+        let msg = if names_defaults {
+            format!("consider removing the generic argument{}", pluralize!(differing.len()))
+        } else {
+            format!("consider specifying the generic argument{}", pluralize!(differing.len()))
+        };
+        err.span_suggestion_verbose(
+            span,
+            &msg,
+            format!("{}", impl_trait_ref.print_only_trait_path()),
+            Applicability::MaybeIncorrect,
+        );
+    }
+
     /// Gets the parent trait chain start
     fn get_parent_trait_ref(
         &self,
@@ -2511,6 +2651,34 @@ fn note_obligation_cause(&self, err: &mut Diagnostic, obligation: &PredicateObli
             );
             self.suggest_unsized_bound_if_applicable(err, obligation);
         }
+        self.note_default_method_body(err, obligation);
+    }
+
+    /// Points out when an obligation about `Self` comes from the default body of a trait
+    /// method, as that body is shared by every implementor that doesn't override it.
+    fn note_default_method_body(
+        &self,
+        err: &mut Diagnostic,
+        obligation: &PredicateObligation<'tcx>,
+    ) {
+        let hir = self.tcx.hir();
+        let body_id = obligation.cause.body_id;
+        let Some(Node::TraitItem(hir::TraitItem {
+            kind: hir::TraitItemKind::Fn(_, hir::TraitFn::Provided(body)),
+            def_id,
+            ident,
+            ..
+        })) = hir.find_parent_node(body_id).and_then(|parent| hir.find(parent)) else { return };
+        if body.hir_id != body_id
+            || !hir.body(*body).value.span.contains(obligation.cause.span)
+            || !obligation.predicate.walk().any(|arg| arg == self.tcx.types.self_param.into())
+        {
+            return;
+        }
+        err.span_note(
+            self.tcx.def_span(*def_id),
+            &format!("required by the default implementation of `{ident}`"),
+        );
     }
 
     #[instrument(level = "debug", skip_all)]
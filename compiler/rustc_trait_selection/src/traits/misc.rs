@@ -4,6 +4,7 @@
 use crate::traits::{self, ObligationCause};
 
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_middle::ty::{self, Ty, TyCtxt, TypeVisitable};
 
@@ -85,3 +86,25 @@ pub fn can_type_implement_copy<'tcx>(
         Ok(())
     })
 }
+
+/// Provider for the `copy_impl_obstacle` query. This runs the same check as the coherence
+/// check of `impl Copy` items, but for the ADT's own identity substitutions.
+pub(crate) fn copy_impl_obstacle<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    adt_did: DefId,
+) -> Option<traits::CopyImplObstacle> {
+    let self_type = tcx.type_of(adt_did);
+    let param_env = tcx.param_env(adt_did);
+    let cause = ObligationCause::dummy_with_span(tcx.def_span(adt_did));
+    match can_type_implement_copy(tcx, param_env, self_type, cause) {
+        Ok(()) | Err(CopyImplementationError::NotAnAdt) => None,
+        Err(CopyImplementationError::InfrigingFields(fields)) => {
+            Some(traits::CopyImplObstacle::InfringingFields(
+                fields.into_iter().map(|(field, _)| field.did).collect(),
+            ))
+        }
+        Err(CopyImplementationError::HasDestructor) => {
+            Some(traits::CopyImplObstacle::HasDestructor)
+        }
+    }
+}
@@ -71,7 +71,7 @@
     elaborate_obligations, elaborate_predicates, elaborate_predicates_with_span,
     elaborate_trait_ref, elaborate_trait_refs,
 };
-pub use self::util::{expand_trait_aliases, TraitAliasExpander};
+pub use self::util::{expand_trait_aliases, TraitAliasExpander, TraitAliasExpansionInfo};
 pub use self::util::{
     get_vtable_index_of_object_method, impl_item_is_final, predicate_for_trait_def, upcast_choices,
 };
@@ -959,6 +959,8 @@ pub fn provide(providers: &mut ty::query::Providers) {
     *providers = ty::query::Providers {
         specialization_graph_of: specialize::specialization_graph_provider,
         specializes: specialize::specializes,
+        impls_overlap: coherence::impls_overlap,
+        copy_impl_obstacle: misc::copy_impl_obstacle,
         codegen_fulfill_obligation: codegen::codegen_fulfill_obligation,
         own_existential_vtable_entries,
         vtable_entries,
@@ -15,9 +15,10 @@
 use crate::traits::{self, Obligation, ObligationCause};
 use rustc_errors::{FatalError, MultiSpan};
 use rustc_hir as hir;
+use rustc_hir::def::DefKind;
 use rustc_hir::def_id::DefId;
 use rustc_middle::ty::abstract_const::{walk_abstract_const, AbstractConst};
-use rustc_middle::ty::subst::{GenericArg, InternalSubsts, Subst};
+use rustc_middle::ty::subst::{GenericArg, GenericArgKind, InternalSubsts, Subst};
 use rustc_middle::ty::{
     self, EarlyBinder, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitor,
 };
@@ -90,20 +91,14 @@ fn object_safety_violations_for_trait(
         .in_definition_order()
         .filter(|item| item.kind == ty::AssocKind::Fn)
         .filter_map(|item| {
-            object_safety_violation_for_method(tcx, trait_def_id, &item)
-                .map(|(code, span)| ObjectSafetyViolation::Method(item.name, code, span))
-        })
-        .filter(|violation| {
-            if let ObjectSafetyViolation::Method(
-                _,
-                MethodViolationCode::WhereClauseReferencesSelf,
-                span,
-            ) = violation
-            {
-                lint_object_unsafe_trait(tcx, *span, trait_def_id, &violation);
-                false
+            let (code, span) = object_safety_violation_for_method(tcx, trait_def_id, &item)?;
+            let is_lint = matches!(code, MethodViolationCode::WhereClauseReferencesSelf);
+            let violation = ObjectSafetyViolation::Method(item.name, code, span);
+            if is_lint {
+                lint_object_unsafe_trait(tcx, item.def_id, span, trait_def_id, &violation);
+                None
             } else {
-                true
+                Some(violation)
             }
         })
         .collect();
@@ -157,13 +152,18 @@ fn object_safety_violations_for_trait(
 /// Lint object-unsafe trait.
 fn lint_object_unsafe_trait(
     tcx: TyCtxt<'_>,
+    method_def_id: DefId,
     span: Span,
     trait_def_id: DefId,
     violation: &ObjectSafetyViolation,
 ) {
-    // Using `CRATE_NODE_ID` is wrong, but it's hard to get a more precise id.
-    // It's also hard to get a use site span, so we use the method definition span.
-    tcx.struct_span_lint_hir(WHERE_CLAUSES_OBJECT_SAFETY, hir::CRATE_HIR_ID, span, |lint| {
+    // It's hard to get a use site span, so we lint on the method definition instead. This also
+    // lets `#[allow]` and `#[expect]` on the method or the trait apply. For methods of foreign
+    // traits we only have the crate root to lint on.
+    let hir_id = method_def_id
+        .as_local()
+        .map_or(hir::CRATE_HIR_ID, |def_id| tcx.hir().local_def_id_to_hir_id(def_id));
+    tcx.struct_span_lint_hir(WHERE_CLAUSES_OBJECT_SAFETY, hir_id, span, |lint| {
         let mut err = lint.build(&format!(
             "the trait `{}` cannot be made into an object",
             tcx.def_path_str(trait_def_id)
@@ -369,6 +369,7 @@ fn object_safety_violation_for_method(
         let span = match (&v, node) {
             (MethodViolationCode::ReferencesSelfInput(Some(span)), _) => *span,
             (MethodViolationCode::UndispatchableReceiver(Some(span)), _) => *span,
+            (MethodViolationCode::ReferencesImplTraitInTrait(span), _) => *span,
             (MethodViolationCode::ReferencesSelfOutput, Some(node)) => {
                 node.fn_decl().map_or(method.ident(tcx).span, |decl| decl.output.span())
             }
@@ -378,6 +379,56 @@ fn object_safety_violation_for_method(
     })
 }
 
+/// Returns the span of the first return-position `impl Trait` in a trait that `ty` mentions.
+fn impl_trait_in_trait_span<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Span> {
+    ty.walk().find_map(|arg| match arg.unpack() {
+        GenericArgKind::Type(ty) => match *ty.kind() {
+            ty::Projection(proj)
+                if tcx.def_kind(proj.item_def_id) == DefKind::ImplTraitPlaceholder =>
+            {
+                Some(tcx.def_span(proj.item_def_id))
+            }
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// For `async fn foo(&self) -> T` in a local trait, suggests declaring it as
+/// `fn foo(&self) -> Pin<Box<dyn Future<Output = T> + '_>>` instead, leaving out the `'_`
+/// bound if none of the arguments borrow anything.
+fn async_fn_boxed_future_sugg(
+    tcx: TyCtxt<'_>,
+    method: &ty::AssocItem,
+) -> Option<(Span, (String, Span))> {
+    let Some(hir::Node::TraitItem(hir::TraitItem { kind: hir::TraitItemKind::Fn(sig, _), .. })) =
+        tcx.hir().get_if_local(method.def_id)
+    else {
+        return None;
+    };
+    let sm = tcx.sess.source_map();
+    // Trait methods can't be `const`, so `async` comes first in their signature.
+    if !sm.span_to_snippet(sig.span).ok()?.starts_with("async") {
+        return None;
+    }
+    let async_span = sm.span_until_non_whitespace(sig.span);
+    let ret_span = sig.decl.output.span();
+    // The future captures all of the arguments, so it only has to be bounded by their lifetime
+    // if one of them is a borrow.
+    let inputs = tcx.fn_sig(method.def_id).skip_binder().inputs();
+    let captures_borrow = inputs
+        .iter()
+        .any(|ty| ty.walk().any(|arg| matches!(arg.unpack(), GenericArgKind::Lifetime(_))));
+    let bound = if captures_borrow { " + '_" } else { "" };
+    let boxed_future = match sm.span_to_snippet(ret_span) {
+        Ok(output) if !output.is_empty() => {
+            format!("std::pin::Pin<Box<dyn std::future::Future<Output = {output}>{bound}>>")
+        }
+        _ => format!(" -> std::pin::Pin<Box<dyn std::future::Future<Output = ()>{bound}>>"),
+    };
+    Some((async_span, (boxed_future, ret_span)))
+}
+
 /// Returns `Some(_)` if this method cannot be called on a trait
 /// object; this does not necessarily imply that the enclosing trait
 /// is not object safe, because the method might have a where clause
@@ -428,6 +479,12 @@ fn virtual_call_violation_for_method<'tcx>(
             return Some(MethodViolationCode::ReferencesSelfInput(span));
         }
     }
+    if tcx.asyncness(method.def_id) == hir::IsAsync::Async {
+        return Some(MethodViolationCode::AsyncFn(async_fn_boxed_future_sugg(tcx, method)));
+    }
+    if let Some(span) = impl_trait_in_trait_span(tcx, sig.output().skip_binder()) {
+        return Some(MethodViolationCode::ReferencesImplTraitInTrait(span));
+    }
     if contains_illegal_self_type_reference(tcx, trait_def_id, sig.output()) {
         return Some(MethodViolationCode::ReferencesSelfOutput);
     }
@@ -32,10 +32,11 @@
 use rustc_middle::ty::fold::{TypeFoldable, TypeFolder, TypeSuperFoldable};
 use rustc_middle::ty::subst::Subst;
 use rustc_middle::ty::visit::{MaxUniverse, TypeVisitable};
-use rustc_middle::ty::{self, Term, ToPredicate, Ty, TyCtxt};
+use rustc_middle::ty::{self, DefIdTree, Term, ToPredicate, Ty, TyCtxt};
 use rustc_span::symbol::sym;
 
 use std::collections::BTreeMap;
+use std::iter;
 
 pub use rustc_middle::traits::Reveal;
 
@@ -1468,9 +1469,18 @@ fn assemble_candidates_from_impls<'cx, 'tcx>(
                 //
                 // NOTE: This should be kept in sync with the similar code in
                 // `rustc_ty_utils::instance::resolve_associated_item()`.
-                let node_item =
-                    assoc_def(selcx, impl_data.impl_def_id, obligation.predicate.item_def_id)
-                        .map_err(|ErrorGuaranteed { .. }| ())?;
+                // A return-position `impl Trait` in a trait is defined by the
+                // impl's definition of the method it belongs to.
+                let tcx = selcx.tcx();
+                let trait_item_def_id = obligation.predicate.item_def_id;
+                let trait_item_def_id =
+                    if tcx.def_kind(trait_item_def_id) == DefKind::ImplTraitPlaceholder {
+                        tcx.impl_trait_in_trait_parent(trait_item_def_id)
+                    } else {
+                        trait_item_def_id
+                    };
+                let node_item = assoc_def(selcx, impl_data.impl_def_id, trait_item_def_id)
+                    .map_err(|ErrorGuaranteed { .. }| ())?;
 
                 if node_item.is_final() {
                     // Non-specializable items are always projectable.
@@ -1717,7 +1727,15 @@ fn confirm_select_candidate<'cx, 'tcx>(
     impl_source: Selection<'tcx>,
 ) -> Progress<'tcx> {
     match impl_source {
-        super::ImplSource::UserDefined(data) => confirm_impl_candidate(selcx, obligation, data),
+        super::ImplSource::UserDefined(data) => {
+            if selcx.tcx().def_kind(obligation.predicate.item_def_id)
+                == DefKind::ImplTraitPlaceholder
+            {
+                confirm_impl_trait_in_trait_candidate(selcx, obligation, data)
+            } else {
+                confirm_impl_candidate(selcx, obligation, data)
+            }
+        }
         super::ImplSource::Generator(data) => confirm_generator_candidate(selcx, obligation, data),
         super::ImplSource::Closure(data) => confirm_closure_candidate(selcx, obligation, data),
         super::ImplSource::FnPointer(data) => confirm_fn_pointer_candidate(selcx, obligation, data),
@@ -2059,6 +2077,81 @@ fn confirm_impl_candidate<'cx, 'tcx>(
     }
 }
 
+/// Projects a return-position `impl Trait` in a trait out of an impl, using the hidden type
+/// that the impl's definition of the method assigns to it.
+fn confirm_impl_trait_in_trait_candidate<'tcx>(
+    selcx: &mut SelectionContext<'_, 'tcx>,
+    obligation: &ProjectionTyObligation<'tcx>,
+    data: ImplSourceUserDefinedData<'tcx, PredicateObligation<'tcx>>,
+) -> Progress<'tcx> {
+    let tcx = selcx.tcx();
+    let mut obligations = data.nested;
+
+    let trait_fn_def_id = tcx.impl_trait_in_trait_parent(obligation.predicate.item_def_id);
+    let Ok(leaf_def) = assoc_def(selcx, data.impl_def_id, trait_fn_def_id) else {
+        return Progress { term: tcx.ty_error().into(), obligations };
+    };
+    if !leaf_def.item.defaultness(tcx).has_value() {
+        // The impl is missing the method, which `check_impl_items_against_trait` reports.
+        return Progress { term: tcx.ty_error().into(), obligations };
+    }
+
+    // Rebase from `<Trait>::{fn}::{opaque}` onto the impl, keeping the substs of the method
+    // and of the opaque itself, since `data.substs` are the impl's substs.
+    let impl_fn_substs =
+        obligation.predicate.substs.rebase_onto(tcx, tcx.parent(trait_fn_def_id), data.substs);
+
+    let cause = ObligationCause::new(
+        obligation.cause.span,
+        obligation.cause.body_id,
+        super::ItemObligation(leaf_def.item.def_id),
+    );
+    let predicates = normalize_with_depth_to(
+        selcx,
+        obligation.param_env,
+        cause.clone(),
+        obligation.recursion_depth + 1,
+        tcx.predicates_of(leaf_def.item.def_id).instantiate(tcx, impl_fn_substs),
+        &mut obligations,
+    );
+    obligations.extend(iter::zip(predicates.predicates, predicates.spans).map(|(pred, span)| {
+        Obligation::with_depth(
+            ObligationCause::new(
+                obligation.cause.span,
+                obligation.cause.body_id,
+                if span.is_dummy() {
+                    super::ItemObligation(leaf_def.item.def_id)
+                } else {
+                    super::BindingObligation(leaf_def.item.def_id, span)
+                },
+            ),
+            obligation.recursion_depth + 1,
+            obligation.param_env,
+            pred,
+        )
+    }));
+
+    let ty = match tcx.collect_trait_impl_trait_tys(leaf_def.item.def_id) {
+        Ok(tys) => tys.get(&obligation.predicate.item_def_id).copied().unwrap_or_else(|| {
+            tcx.ty_error_with_message(
+                obligation.cause.span,
+                "impl method is missing a hidden type for a return-position `impl Trait`",
+            )
+        }),
+        Err(_) => tcx.ty_error(),
+    };
+    let ty = normalize_with_depth_to(
+        selcx,
+        obligation.param_env,
+        cause,
+        obligation.recursion_depth + 1,
+        ty::EarlyBinder(ty).subst(tcx, impl_fn_substs),
+        &mut obligations,
+    );
+
+    Progress { term: ty.into(), obligations }
+}
+
 // Get obligations corresponding to the predicates from the where-clause of the
 // associated type itself.
 // Note: `feature(generic_associated_types)` is required to write such
@@ -2069,11 +2162,10 @@ fn assoc_ty_own_obligations<'cx, 'tcx>(
     nested: &mut Vec<PredicateObligation<'tcx>>,
 ) {
     let tcx = selcx.tcx();
-    for predicate in tcx
-        .predicates_of(obligation.predicate.item_def_id)
-        .instantiate_own(tcx, obligation.predicate.substs)
-        .predicates
-    {
+    let item_def_id = obligation.predicate.item_def_id;
+    let predicates =
+        tcx.predicates_of(item_def_id).instantiate_own(tcx, obligation.predicate.substs);
+    for (predicate, span) in iter::zip(predicates.predicates, predicates.spans) {
         let normalized = normalize_with_depth_to(
             selcx,
             obligation.param_env,
@@ -2082,8 +2174,22 @@ fn assoc_ty_own_obligations<'cx, 'tcx>(
             predicate,
             nested,
         );
+        // Outlives bounds are only ever reported as region errors, so point
+        // those at the bound that introduced them.
+        let cause = match predicate.kind().skip_binder() {
+            ty::PredicateKind::TypeOutlives(..) | ty::PredicateKind::RegionOutlives(..)
+                if !span.is_dummy() =>
+            {
+                ObligationCause::new(
+                    obligation.cause.span,
+                    obligation.cause.body_id,
+                    super::BindingObligation(item_def_id, span),
+                )
+            }
+            _ => obligation.cause.clone(),
+        };
         nested.push(Obligation::with_depth(
-            obligation.cause.clone(),
+            cause,
             obligation.recursion_depth + 1,
             obligation.param_env,
             normalized,
@@ -102,10 +102,19 @@ fn candidate_from_obligation_no_cache<'o>(
                             };
                             (trait_desc, self_desc)
                         });
+                        let trait_def_id = trait_ref.def_id;
                         let cause = if let Conflict::Upstream = conflict {
-                            IntercrateAmbiguityCause::UpstreamCrateUpdate { trait_desc, self_desc }
+                            IntercrateAmbiguityCause::UpstreamCrateUpdate {
+                                trait_def_id,
+                                trait_desc,
+                                self_desc,
+                            }
                         } else {
-                            IntercrateAmbiguityCause::DownstreamCrate { trait_desc, self_desc }
+                            IntercrateAmbiguityCause::DownstreamCrate {
+                                trait_def_id,
+                                trait_desc,
+                                self_desc,
+                            }
                         };
                         debug!(?cause, "evaluate_stack: pushing cause");
                         self.intercrate_ambiguity_causes.as_mut().unwrap().insert(cause);
@@ -41,6 +41,7 @@
 use rustc_middle::ty::{self, EarlyBinder, PolyProjectionPredicate, ToPolyTraitRef, ToPredicate};
 use rustc_middle::ty::{Ty, TyCtxt, TypeFoldable, TypeVisitable};
 use rustc_span::symbol::sym;
+use rustc_span::Span;
 
 use std::cell::{Cell, RefCell};
 use std::cmp;
@@ -54,21 +55,29 @@
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum IntercrateAmbiguityCause {
-    DownstreamCrate { trait_desc: String, self_desc: Option<String> },
-    UpstreamCrateUpdate { trait_desc: String, self_desc: Option<String> },
-    ReservationImpl { message: String },
+    DownstreamCrate { trait_def_id: DefId, trait_desc: String, self_desc: Option<String> },
+    UpstreamCrateUpdate { trait_def_id: DefId, trait_desc: String, self_desc: Option<String> },
+    /// The overlap relies on a `#[rustc_reservation_impl]`, whose span is known if it is local.
+    ReservationImpl { message: String, span: Option<Span> },
 }
 
 impl IntercrateAmbiguityCause {
     /// Emits notes when the overlap is caused by complex intercrate ambiguities.
     /// See #23980 for details.
     pub fn add_intercrate_ambiguity_hint(&self, err: &mut Diagnostic) {
-        err.note(&self.intercrate_ambiguity_hint());
+        match self {
+            IntercrateAmbiguityCause::ReservationImpl { message, span: Some(span) } => {
+                err.span_note(*span, message);
+            }
+            _ => {
+                err.note(&self.intercrate_ambiguity_hint());
+            }
+        }
     }
 
     pub fn intercrate_ambiguity_hint(&self) -> String {
         match self {
-            IntercrateAmbiguityCause::DownstreamCrate { trait_desc, self_desc } => {
+            IntercrateAmbiguityCause::DownstreamCrate { trait_desc, self_desc, .. } => {
                 let self_desc = if let Some(ty) = self_desc {
                     format!(" for type `{}`", ty)
                 } else {
@@ -76,7 +85,7 @@ pub fn intercrate_ambiguity_hint(&self) -> String {
                 };
                 format!("downstream crates may implement trait `{}`{}", trait_desc, self_desc)
             }
-            IntercrateAmbiguityCause::UpstreamCrateUpdate { trait_desc, self_desc } => {
+            IntercrateAmbiguityCause::UpstreamCrateUpdate { trait_desc, self_desc, .. } => {
                 let self_desc = if let Some(ty) = self_desc {
                     format!(" for type `{}`", ty)
                 } else {
@@ -88,7 +97,19 @@ pub fn intercrate_ambiguity_hint(&self) -> String {
                     trait_desc, self_desc
                 )
             }
-            IntercrateAmbiguityCause::ReservationImpl { message } => message.clone(),
+            IntercrateAmbiguityCause::ReservationImpl { message, .. } => message.clone(),
+        }
+    }
+
+    /// Returns the trait whose implementations might change in other crates, if the ambiguity
+    /// is caused by one.
+    pub fn trait_def_id(&self) -> Option<DefId> {
+        match self {
+            IntercrateAmbiguityCause::DownstreamCrate { trait_def_id, .. }
+            | IntercrateAmbiguityCause::UpstreamCrateUpdate { trait_def_id, .. } => {
+                Some(*trait_def_id)
+            }
+            IntercrateAmbiguityCause::ReservationImpl { .. } => None,
         }
     }
 }
@@ -950,6 +971,7 @@ fn evaluate_stack<'o>(
                             let self_ty = trait_ref.self_ty();
                             let cause = with_no_trimmed_paths!({
                                 IntercrateAmbiguityCause::DownstreamCrate {
+                                    trait_def_id: trait_ref.def_id,
                                     trait_desc: trait_ref.print_only_trait_path().to_string(),
                                     self_desc: if self_ty.has_concrete_skeleton() {
                                         Some(self_ty.to_string())
@@ -1255,6 +1277,7 @@ fn filter_reservation_impls(
                         intercrate_ambiguity_clauses.insert(
                             IntercrateAmbiguityCause::ReservationImpl {
                                 message: value.to_string(),
+                                span: tcx.span_of_impl(def_id).ok(),
                             },
                         );
                     }
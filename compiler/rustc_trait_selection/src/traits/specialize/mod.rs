@@ -16,12 +16,12 @@
 use crate::traits::select::IntercrateAmbiguityCause;
 use crate::traits::{self, coherence, FutureCompatOverlapErrorKind, ObligationCause};
 use rustc_data_structures::fx::{FxHashSet, FxIndexSet};
-use rustc_errors::{struct_span_err, EmissionGuarantee, LintDiagnosticBuilder};
+use rustc_errors::{
+    struct_span_err, Diagnostic, EmissionGuarantee, LintDiagnosticBuilder, MultiSpan,
+};
 use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_middle::ty::subst::{InternalSubsts, Subst, SubstsRef};
 use rustc_middle::ty::{self, ImplSubject, TyCtxt};
-use rustc_session::lint::builtin::COHERENCE_LEAK_CHECK;
-use rustc_session::lint::builtin::ORDER_DEPENDENT_TRAIT_OBJECTS;
 use rustc_span::{Span, DUMMY_SP};
 
 use super::util;
@@ -375,6 +375,7 @@ fn decorate<G: EmissionGuarantee>(
         tcx: TyCtxt<'_>,
         overlap: OverlapError,
         used_to_be_allowed: Option<FutureCompatOverlapErrorKind>,
+        impl_def_id: LocalDefId,
         impl_span: Span,
         err: LintDiagnosticBuilder<'_, G>,
     ) -> G {
@@ -412,6 +413,18 @@ fn decorate<G: EmissionGuarantee>(
             }
         }
 
+        if let Some(note) = used_to_be_allowed.and_then(|kind| {
+            kind.previously_disjoint_note(tcx, overlap.with_impl, impl_def_id.to_def_id())
+        }) {
+            err.note(&note);
+        }
+
+        note_ambiguous_bounds(
+            tcx,
+            &mut err,
+            &[overlap.with_impl, impl_def_id.to_def_id()],
+            &overlap.intercrate_ambiguity_causes,
+        );
         for cause in &overlap.intercrate_ambiguity_causes {
             cause.add_intercrate_ambiguity_hint(&mut err);
         }
@@ -424,39 +437,69 @@ fn decorate<G: EmissionGuarantee>(
 
     match used_to_be_allowed {
         None => {
-            let reported = if overlap.with_impl.is_local()
-                || tcx.orphan_check_impl(impl_def_id).is_ok()
-            {
-                let err = struct_span_err!(tcx.sess, impl_span, E0119, "");
-                Some(decorate(
-                    tcx,
-                    overlap,
-                    used_to_be_allowed,
-                    impl_span,
-                    LintDiagnosticBuilder::new(err),
-                ))
-            } else {
-                Some(tcx.sess.delay_span_bug(impl_span, "impl should have failed the orphan check"))
-            };
-            sg.has_errored = reported;
+            // An impl that breaks the orphan rules often also overlaps with an upstream impl.
+            // Report both, but make sure the orphan check error comes first as it is usually
+            // the one to fix.
+            let _ = tcx.orphan_check_impl(impl_def_id);
+            let err = struct_span_err!(tcx.sess, impl_span, E0119, "");
+            sg.has_errored = Some(decorate(
+                tcx,
+                overlap,
+                used_to_be_allowed,
+                impl_def_id,
+                impl_span,
+                LintDiagnosticBuilder::new(err),
+            ));
         }
         Some(kind) => {
-            let lint = match kind {
-                FutureCompatOverlapErrorKind::Issue33140 => ORDER_DEPENDENT_TRAIT_OBJECTS,
-                FutureCompatOverlapErrorKind::LeakCheck => COHERENCE_LEAK_CHECK,
-            };
             tcx.struct_span_lint_hir(
-                lint,
+                kind.lint(),
                 tcx.hir().local_def_id_to_hir_id(impl_def_id),
                 impl_span,
                 |ldb| {
-                    decorate(tcx, overlap, used_to_be_allowed, impl_span, ldb);
+                    decorate(tcx, overlap, used_to_be_allowed, impl_def_id, impl_span, ldb);
                 },
             );
         }
     };
 }
 
+/// Points at the bounds written on the local overlapping impls that could not be ruled out for
+/// the overlapping type, because other crates may implement their traits for it.
+fn note_ambiguous_bounds(
+    tcx: TyCtxt<'_>,
+    err: &mut Diagnostic,
+    impl_def_ids: &[DefId],
+    causes: &FxIndexSet<IntercrateAmbiguityCause>,
+) {
+    let ambiguous_traits: FxHashSet<_> =
+        causes.iter().filter_map(|cause| cause.trait_def_id()).collect();
+    if ambiguous_traits.is_empty() {
+        return;
+    }
+    let mut spans = vec![];
+    let mut labels = vec![];
+    for &impl_def_id in impl_def_ids.iter().filter(|def_id| def_id.is_local()) {
+        for &(predicate, span) in tcx.predicates_of(impl_def_id).predicates {
+            let Some(trait_pred) = predicate.to_opt_poly_trait_pred() else { continue };
+            // Bounds added by derives are not something the user can point at.
+            if span.from_expansion() || !ambiguous_traits.contains(&trait_pred.def_id()) {
+                continue;
+            }
+            spans.push(span);
+            labels.push((span, format!("`{predicate}` may hold for the overlapping type")));
+        }
+    }
+    if spans.is_empty() {
+        return;
+    }
+    let mut multi_span = MultiSpan::from_spans(spans);
+    for (span, label) in labels {
+        multi_span.push_span_label(span, label);
+    }
+    err.span_note(multi_span, "the overlap cannot be ruled out because of these bounds");
+}
+
 /// Recovers the "impl X for Y" signature from `impl_def_id` and returns it as a
 /// string.
 pub(crate) fn to_pretty_impl_header(tcx: TyCtxt<'_>, impl_def_id: DefId) -> Option<String> {
@@ -5,6 +5,8 @@
 use rustc_middle::ty::fast_reject::{self, SimplifiedType, TreatParams};
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self, TyCtxt, TypeVisitable};
+use rustc_session::lint::builtin::{COHERENCE_LEAK_CHECK, ORDER_DEPENDENT_TRAIT_OBJECTS};
+use rustc_session::lint::Lint;
 
 pub use rustc_middle::traits::specialization_graph::*;
 
@@ -14,6 +16,61 @@ pub enum FutureCompatOverlapErrorKind {
     LeakCheck,
 }
 
+impl FutureCompatOverlapErrorKind {
+    /// Overlaps that are only errors because the overlap check became stricter. When we
+    /// find an overlap, the previous behavior of each of these is retried in order, and if
+    /// the impls didn't use to overlap we report the overlap through the lint of that kind
+    /// rather than as a hard error, giving users a migration period.
+    const TRANSITIONAL: &'static [FutureCompatOverlapErrorKind] =
+        &[FutureCompatOverlapErrorKind::LeakCheck];
+
+    /// How the overlap check was configured before overlaps of this kind became errors.
+    fn previous_overlap_check(
+        self,
+        overlap_mode: OverlapMode,
+    ) -> Option<(traits::SkipLeakCheck, OverlapMode)> {
+        match self {
+            // Not detected by re-running the overlap check, see `impls_are_allowed_to_overlap`.
+            FutureCompatOverlapErrorKind::Issue33140 => None,
+            FutureCompatOverlapErrorKind::LeakCheck => {
+                Some((traits::SkipLeakCheck::No, overlap_mode))
+            }
+        }
+    }
+
+    /// The future-compatibility lint used to report overlaps of this kind.
+    pub fn lint(self) -> &'static Lint {
+        match self {
+            FutureCompatOverlapErrorKind::Issue33140 => ORDER_DEPENDENT_TRAIT_OBJECTS,
+            FutureCompatOverlapErrorKind::LeakCheck => COHERENCE_LEAK_CHECK,
+        }
+    }
+
+    /// Explains why the two impls of an overlap of this kind used to be considered disjoint.
+    pub fn previously_disjoint_note(
+        self,
+        tcx: TyCtxt<'_>,
+        impl1_def_id: DefId,
+        impl2_def_id: DefId,
+    ) -> Option<String> {
+        match self {
+            // Reported for impls that are still allowed to overlap, not for ones that used to be
+            // considered disjoint.
+            FutureCompatOverlapErrorKind::Issue33140 => None,
+            FutureCompatOverlapErrorKind::LeakCheck => {
+                let (ty1, ty2) = with_no_trimmed_paths!((
+                    tcx.type_of(impl1_def_id).to_string(),
+                    tcx.type_of(impl2_def_id).to_string(),
+                ));
+                Some(format!(
+                    "the impls for `{ty1}` and `{ty2}` used to be considered disjoint, as their \
+                     types only unify by equating a higher-ranked lifetime with another lifetime"
+                ))
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct FutureCompatOverlapError {
     pub error: OverlapError,
@@ -131,27 +188,29 @@ fn insert(
                 // Found overlap, but no specialization; error out or report future-compat warning.
 
                 // Do we *still* get overlap if we disable the future-incompatible modes?
-                let should_err = traits::overlapping_impls(
-                    tcx,
-                    possible_sibling,
-                    impl_def_id,
-                    traits::SkipLeakCheck::default(),
-                    overlap_mode,
-                    |_| true,
-                    || false,
-                );
+                let used_to_be_allowed =
+                    FutureCompatOverlapErrorKind::TRANSITIONAL.iter().copied().find(|kind| {
+                        let Some((skip_leak_check, overlap_mode)) =
+                            kind.previous_overlap_check(overlap_mode) else { return false };
+                        !traits::overlapping_impls(
+                            tcx,
+                            possible_sibling,
+                            impl_def_id,
+                            skip_leak_check,
+                            overlap_mode,
+                            |_| true,
+                            || false,
+                        )
+                    });
 
                 let error = create_overlap_error(overlap);
 
-                if should_err {
-                    Err(error)
-                } else {
-                    *last_lint = Some(FutureCompatOverlapError {
-                        error,
-                        kind: FutureCompatOverlapErrorKind::LeakCheck,
-                    });
-
-                    Ok((false, false))
+                match used_to_be_allowed {
+                    Some(kind) => {
+                        *last_lint = Some(FutureCompatOverlapError { error, kind });
+                        Ok((false, false))
+                    }
+                    None => Err(error),
                 }
             };
 
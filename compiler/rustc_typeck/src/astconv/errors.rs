@@ -21,6 +21,7 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
     pub(crate) fn complain_about_missing_type_params(
         &self,
         missing_type_params: Vec<Symbol>,
+        suggested_args: Vec<String>,
         def_id: DefId,
         span: Span,
         empty_generic_args: bool,
@@ -33,6 +34,7 @@ pub(crate) fn complain_about_missing_type_params(
             span,
             def_span: self.tcx().def_span(def_id),
             missing_type_params,
+            suggested_args,
             empty_generic_args,
         });
     }
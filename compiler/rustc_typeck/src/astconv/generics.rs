@@ -613,7 +613,7 @@ pub(crate) fn check_generic_arg_count(
 
     /// Emits an error regarding forbidden type binding associations
     pub fn prohibit_assoc_ty_binding(tcx: TyCtxt<'_>, span: Span) {
-        tcx.sess.emit_err(AssocTypeBindingNotAllowed { span });
+        tcx.sess.emit_err(AssocTypeBindingNotAllowed { span, where_clause_suggestion: None });
     }
 
     /// Prohibits explicit lifetime arguments if late-bound lifetime parameters
@@ -9,8 +9,9 @@
 use crate::collect::HirPlaceholderCollector;
 use crate::errors::{
     AmbiguousAssociatedType, AmbiguousAssociatedTypeFixSuggestion, AmbiguousLifetimeBound,
-    EnumVariantNotFound, EnumVariantNotFoundFixOrInfo, MultipleRelaxedDefaultBounds,
-    TraitObjectDeclaredWithNoTraits, TypeofReservedKeywordUsed,
+    AssocTypeBindingNotAllowed, EnumVariantNotFound, EnumVariantNotFoundFixOrInfo,
+    MultipleRelaxedDefaultBounds, ObjectSafePrincipalCandidates, PrincipalTraitCandidates,
+    RemoveNonAutoTrait, TraitObjectDeclaredWithNoTraits, TypeofReservedKeywordUsed,
     ValueOfAssociatedStructAlreadySpecified,
 };
 use crate::middle::resolve_lifetime as rl;
@@ -27,16 +28,21 @@
 use rustc_hir::intravisit::{walk_generics, Visitor as _};
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{GenericArg, GenericArgs, OpaqueTyOrigin};
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_infer::traits::error_reporting::implicit_sized::{
+    implicitly_sized_impl_param, ImplicitSizedBound,
+};
 use rustc_middle::middle::stability::AllowUnstable;
 use rustc_middle::ty::subst::{self, GenericArgKind, InternalSubsts, Subst, SubstsRef};
 use rustc_middle::ty::GenericParamDefKind;
 use rustc_middle::ty::{
-    self, Const, DefIdTree, EarlyBinder, IsSuggestable, Ty, TyCtxt, TypeVisitable,
+    self, Const, DefIdTree, EarlyBinder, IsSuggestable, ToPredicate, Ty, TyCtxt, TypeVisitable,
 };
 use rustc_session::lint::builtin::{AMBIGUOUS_ASSOCIATED_ITEMS, BARE_TRAIT_OBJECTS};
+use rustc_session::parse::add_feature_diagnostics;
 use rustc_span::edition::Edition;
 use rustc_span::lev_distance::find_best_match_for_name;
-use rustc_span::symbol::{kw, Ident, Symbol};
+use rustc_span::symbol::{kw, sym, Ident, Symbol};
 use rustc_span::Span;
 use rustc_target::spec::abi;
 use rustc_trait_selection::traits;
@@ -44,10 +50,12 @@
 use rustc_trait_selection::traits::error_reporting::{
     report_object_safety_error, suggestions::NextTypeParamName,
 };
+use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt as _;
 use rustc_trait_selection::traits::wf::object_region_bounds;
 
 use smallvec::{smallvec, SmallVec};
 use std::collections::BTreeSet;
+use std::iter;
 use std::slice;
 
 #[derive(Debug)]
@@ -804,11 +812,72 @@ fn ast_path_to_mono_trait_ref(
         );
         let assoc_bindings = self.create_assoc_bindings_for_generic_args(trait_segment.args());
         if let Some(b) = assoc_bindings.first() {
-            Self::prohibit_assoc_ty_binding(self.tcx(), b.span);
+            let where_clause_suggestion = if is_impl {
+                None
+            } else {
+                self.assoc_binding_where_clause_suggestion(trait_def_id, self_ty, trait_segment)
+            };
+            self.tcx().sess.emit_err(AssocTypeBindingNotAllowed {
+                span: b.span,
+                where_clause_suggestion,
+            });
         }
         ty::TraitRef::new(trait_def_id, substs)
     }
 
+    /// For an associated type binding on the trait of a qualified path, like the `A = Bar` in
+    /// `<T as Foo<A = Bar>>::B`, suggests moving the binding into a `where` clause of the
+    /// enclosing item, as `T: Foo<A = Bar>`.
+    fn assoc_binding_where_clause_suggestion(
+        &self,
+        trait_def_id: DefId,
+        self_ty: Ty<'tcx>,
+        trait_segment: &hir::PathSegment<'_>,
+    ) -> Option<Vec<(Span, String)>> {
+        let tcx = self.tcx();
+        let args = trait_segment.args();
+        let [binding] = args.bindings else { return None };
+        if !matches!(binding.kind, hir::TypeBindingKind::Equality { .. })
+            || !matches!(self_ty.kind(), ty::Param(_))
+            || args.span_ext.from_expansion()
+        {
+            return None;
+        }
+        // Only items whose `where` clauses are actually enforced.
+        let item_def_id = self.item_def_id()?.as_local()?;
+        if !matches!(
+            tcx.def_kind(item_def_id),
+            DefKind::Fn
+                | DefKind::AssocFn
+                | DefKind::Struct
+                | DefKind::Enum
+                | DefKind::Union
+                | DefKind::Trait
+                | DefKind::Impl
+        ) {
+            return None;
+        }
+        let generics = tcx.hir().get_generics(item_def_id)?;
+        let trait_args = tcx.sess.source_map().span_to_snippet(args.span_ext).ok()?;
+        // Remove the whole `<A = Bar>` if the binding is the only argument, and the `, A = Bar`
+        // after the other arguments otherwise.
+        let removal = match args.args.last() {
+            Some(arg) => arg.span().shrink_to_hi().to(binding.span),
+            None => args.span_ext,
+        };
+        Some(vec![
+            (removal, String::new()),
+            (
+                generics.tail_span_for_predicate_suggestion(),
+                format!(
+                    "{} {self_ty}: {}{trait_args}",
+                    generics.add_where_or_trailing_comma(),
+                    tcx.def_path_str(trait_def_id),
+                ),
+            ),
+        ])
+    }
+
     #[tracing::instrument(level = "debug", skip(self, span))]
     fn create_substs_for_ast_trait_ref<'a>(
         &self,
@@ -1257,6 +1326,74 @@ fn ast_path_to_ty(
         )
     }
 
+    /// Walks the expansion of a trait alias that does not name any trait, pointing out each
+    /// nested trait alias it expands to and the aliases at the end of the chain that are empty.
+    fn note_trait_alias_expansion(&self, err: &mut Diagnostic, trait_alias: DefId) {
+        let tcx = self.tcx();
+        let mut visited = FxHashSet::default();
+        visited.insert(trait_alias);
+        let mut stack = vec![trait_alias];
+        while let Some(alias) = stack.pop() {
+            let nested: Vec<_> = tcx
+                .super_predicates_of(alias)
+                .predicates
+                .iter()
+                .filter_map(|(pred, span)| {
+                    let def_id = pred.to_opt_poly_trait_pred()?.def_id();
+                    tcx.is_trait_alias(def_id).then_some((def_id, *span))
+                })
+                .filter(|&(def_id, _)| visited.insert(def_id))
+                .collect();
+            if nested.is_empty() {
+                // The label on the outermost alias already says that it is empty.
+                if alias != trait_alias {
+                    err.span_note(
+                        tcx.def_span(alias),
+                        &format!("`{}` does not contain a trait either", tcx.def_path_str(alias)),
+                    );
+                }
+                continue;
+            }
+            for &(def_id, span) in &nested {
+                err.span_note(
+                    span,
+                    &format!(
+                        "`{}` expands to the trait alias `{}`",
+                        tcx.def_path_str(alias),
+                        tcx.def_path_str(def_id),
+                    ),
+                );
+            }
+            stack.extend(nested.into_iter().rev().map(|(def_id, _)| def_id));
+        }
+    }
+
+    /// Returns the span covering a non-auto trait of a trait object along with the `+` that
+    /// separates it from its neighbour, if the trait is written directly in the trait object
+    /// rather than coming from a trait alias.
+    fn non_auto_trait_removal_span(
+        &self,
+        trait_bounds: &[hir::PolyTraitRef<'_>],
+        info: &traits::TraitAliasExpansionInfo<'_>,
+    ) -> Option<Span> {
+        if info.path.len() != 1 {
+            return None;
+        }
+        let span = info.bottom().1;
+        let idx = trait_bounds.iter().position(|bound| bound.span == span)?;
+        let (between, removal) = if idx > 0 {
+            let prev = trait_bounds[idx - 1].span;
+            (prev.between(span), prev.shrink_to_hi().to(span))
+        } else {
+            let next = trait_bounds.get(1)?.span;
+            (span.between(next), span.to(next.shrink_to_lo()))
+        };
+        // Only suggest this when the neighbouring bound is separated by a lone `+`, so that we
+        // don't mangle parenthesized bounds or remove lifetimes and `?Sized` along with it.
+        let snippet = self.tcx().sess.source_map().span_to_snippet(between).ok()?;
+        (snippet.trim() == "+").then_some(removal)
+    }
+
     fn conv_object_ty_poly_trait_ref(
         &self,
         span: Span,
@@ -1317,22 +1454,78 @@ trait here instead: `trait NewTrait: {} {{}}`",
                     .collect::<Vec<_>>()
                     .join(" + "),
             ));
+            let mut principal_candidates = Vec::new();
+            for info in &regular_traits {
+                let def_id = info.trait_ref().def_id();
+                if !principal_candidates.contains(&def_id) {
+                    principal_candidates.push(def_id);
+                }
+            }
+            // Whether a trait is object safe depends on the signatures of its methods, so asking
+            // for it while converting a type inside of a trait could end up in a cycle, e.g. for
+            // `dyn Self + Other` in one of its own methods. Only classify the traits elsewhere.
+            let in_trait = self.item_def_id().map_or(false, |item_def_id| {
+                iter::successors(Some(item_def_id), |&def_id| tcx.opt_parent(def_id)).any(
+                    |def_id| matches!(tcx.def_kind(def_id), DefKind::Trait | DefKind::TraitAlias),
+                )
+            });
+            if !in_trait {
+                principal_candidates.retain(|&def_id| tcx.is_object_safe(def_id));
+            }
+            let mut names: Vec<_> = principal_candidates
+                .iter()
+                .map(|&def_id| format!("`{}`", tcx.item_name(def_id)))
+                .collect();
+            let count = principal_candidates.len();
+            if in_trait {
+                let last = names.pop().unwrap();
+                let traits =
+                    if names.is_empty() { last } else { format!("{} or {last}", names.join(", ")) };
+                err.subdiagnostic(PrincipalTraitCandidates { traits, count });
+            } else {
+                let traits = match names.pop() {
+                    Some(last) if !names.is_empty() => format!("{} and {last}", names.join(", ")),
+                    Some(last) => last,
+                    None => String::new(),
+                };
+                err.subdiagnostic(ObjectSafePrincipalCandidates { traits, count });
+            }
             err.note(
                 "auto-traits like `Send` and `Sync` are traits that have special properties; \
                  for more information on them, visit \
                  <https://doc.rust-lang.org/reference/special-types-and-traits.html#auto-traits>",
             );
+            // Prefer keeping the first trait, unless it is the one that can't be the principal.
+            let to_remove = if !in_trait
+                && !principal_candidates.contains(&first_trait.trait_ref().def_id())
+                && principal_candidates.contains(&additional_trait.trait_ref().def_id())
+            {
+                first_trait
+            } else {
+                additional_trait
+            };
+            if let Some(span) = self.non_auto_trait_removal_span(trait_bounds, to_remove) {
+                err.subdiagnostic(RemoveNonAutoTrait {
+                    span,
+                    trait_name: tcx.item_name(to_remove.trait_ref().def_id()),
+                });
+            }
             err.emit();
         }
 
         if regular_traits.is_empty() && auto_traits.is_empty() {
-            let trait_alias_span = bounds
+            let trait_alias = bounds
                 .trait_bounds
                 .iter()
                 .map(|&(trait_ref, _, _)| trait_ref.def_id())
-                .find(|&trait_ref| tcx.is_trait_alias(trait_ref))
-                .map(|trait_ref| tcx.def_span(trait_ref));
-            tcx.sess.emit_err(TraitObjectDeclaredWithNoTraits { span, trait_alias_span });
+                .find(|&trait_ref| tcx.is_trait_alias(trait_ref));
+            let trait_alias_span = trait_alias.map(|trait_alias| tcx.def_span(trait_alias));
+            let mut err =
+                tcx.sess.create_err(TraitObjectDeclaredWithNoTraits { span, trait_alias_span });
+            if let Some(trait_alias) = trait_alias {
+                self.note_trait_alias_expansion(&mut err, trait_alias);
+            }
+            err.emit();
             return tcx.ty_error();
         }
 
@@ -1468,6 +1661,31 @@ trait here instead: `trait NewTrait: {} {{}}`",
                     .collect();
                 let substs = tcx.intern_substs(&substs[..]);
 
+                // Generic arguments are positional, so naming the missing parameters also
+                // requires spelling out the defaults of any parameters that come before them.
+                let suggested_args = trait_ref
+                    .substs
+                    .iter()
+                    .rposition(|arg| arg == dummy_self.into())
+                    .and_then(|last_missing| {
+                        trait_ref
+                            .substs
+                            .iter()
+                            .enumerate()
+                            .take(last_missing + 1)
+                            .skip(1)
+                            .filter_map(|(index, arg)| match arg.unpack() {
+                                _ if arg == dummy_self.into() => {
+                                    Some(Some(generics.params[index].name.to_string()))
+                                }
+                                _ if arg.walk().any(|arg| arg == dummy_self.into()) => Some(None),
+                                GenericArgKind::Lifetime(_) => None,
+                                _ => Some(Some(arg.to_string())),
+                            })
+                            .collect::<Option<Vec<_>>>()
+                    })
+                    .unwrap_or_default();
+
                 let span = i.bottom().1;
                 let empty_generic_args = trait_bounds.iter().any(|hir_bound| {
                     hir_bound.trait_ref.path.res == Res::Def(DefKind::Trait, trait_ref.def_id)
@@ -1475,6 +1693,7 @@ trait here instead: `trait NewTrait: {} {{}}`",
                 });
                 self.complain_about_missing_type_params(
                     missing_type_params,
+                    suggested_args,
                     trait_ref.def_id,
                     span,
                     empty_generic_args,
@@ -1589,8 +1808,10 @@ trait here instead: `trait NewTrait: {} {{}}`",
     fn report_ambiguous_associated_type(
         &self,
         span: Span,
-        type_str: &str,
-        trait_str: &str,
+        type_str: Option<&str>,
+        trait_strs: Vec<String>,
+        has_placeholders: bool,
+        implicit_sized: Option<ImplicitSizedBound>,
         name: Symbol,
     ) -> ErrorGuaranteed {
         let fix = if self
@@ -1605,12 +1826,124 @@ fn report_ambiguous_associated_type(
             AmbiguousAssociatedTypeFixSuggestion::UseFullyQualifiedSyntax {
                 span,
                 type_str,
-                trait_str,
+                trait_strs,
+                has_placeholders,
                 name,
             }
         };
 
-        self.tcx().sess.emit_err(AmbiguousAssociatedType { span, possible_fix: fix })
+        self.tcx().sess.emit_err(AmbiguousAssociatedType { span, possible_fix: fix, implicit_sized })
+    }
+
+    /// Returns the paths of the traits accessible from `hir_ref_id` that define an associated
+    /// type named `assoc_ident` and may be implemented by `qself_ty`.
+    ///
+    /// The generic parameters of the traits that need to be specified are printed as
+    /// placeholders, in which case the returned `bool` is `true`. If no trait is implemented,
+    /// but one of them has a local impl for `qself_ty` that only doesn't apply because of an
+    /// implicit `Sized` bound, that trait is returned along with the explanation.
+    fn probe_traits_that_match_assoc_ty(
+        &self,
+        hir_ref_id: hir::HirId,
+        span: Span,
+        qself_ty: Ty<'tcx>,
+        assoc_ident: Ident,
+    ) -> (Vec<String>, bool, Option<ImplicitSizedBound>) {
+        let tcx = self.tcx();
+        // Inference variables of the caller can't be related in a fresh inference context, and
+        // types under a binder can't be checked for trait impls without instantiating it.
+        if qself_ty.needs_infer() || qself_ty.has_escaping_bound_vars() {
+            return (vec![], false, None);
+        }
+        let module = tcx.hir().parent_module(hir_ref_id).to_def_id();
+
+        let candidates: Vec<_> = tcx
+            .all_traits()
+            .filter(|&trait_def_id| {
+                tcx.associated_items(trait_def_id)
+                    .filter_by_name_unhygienic(assoc_ident.name)
+                    .any(|item| item.kind == ty::AssocKind::Type)
+                    && tcx.visibility(trait_def_id).is_accessible_from(module, tcx)
+            })
+            .filter(|&trait_def_id| {
+                // Skip unstable traits, as well as the traits of crates that can only be
+                // reached through the standard library.
+                match tcx.lookup_stability(trait_def_id) {
+                    Some(stability) => stability.is_stable(),
+                    None => {
+                        trait_def_id.is_local()
+                            || tcx
+                                .extern_crate(trait_def_id.krate.as_def_id())
+                                .map_or(false, |extern_crate| extern_crate.is_direct())
+                    }
+                }
+            })
+            .collect();
+        let mut matching: Vec<_> = tcx.infer_ctxt().enter(|infcx| {
+            candidates
+                .iter()
+                .copied()
+                .filter(|&trait_def_id| {
+                    let substs = infcx.fresh_substs_for_item(span, trait_def_id);
+                    let substs = tcx.mk_substs_trait(qself_ty, &substs[1..]);
+                    let trait_ref = ty::Binder::dummy(ty::TraitRef::new(trait_def_id, substs));
+                    let obligation = traits::Obligation::new(
+                        traits::ObligationCause::dummy_with_span(span),
+                        ty::ParamEnv::empty(),
+                        trait_ref.without_const().to_predicate(tcx),
+                    );
+                    infcx.predicate_may_hold(&obligation)
+                })
+                .collect()
+        });
+
+        // Whether a type is `Sized` can only be decided in an empty `ParamEnv` if it doesn't
+        // mention any generic parameters.
+        let mut implicit_sized = None;
+        if matching.is_empty() && !qself_ty.needs_subst() {
+            implicit_sized = candidates.iter().find_map(|&trait_def_id| {
+                let implicit_sized = implicitly_sized_impl_param(
+                    tcx,
+                    ty::ParamEnv::empty(),
+                    trait_def_id,
+                    0,
+                    qself_ty,
+                )?;
+                matching.push(trait_def_id);
+                Some(implicit_sized)
+            });
+        }
+
+        let mut has_placeholders = false;
+        let mut traits: Vec<_> = matching
+            .into_iter()
+            .map(|trait_def_id| {
+                let path = tcx.def_path_str(trait_def_id);
+                // Lifetimes can be elided and defaulted parameters left out, everything else
+                // has to be filled in by the user.
+                let params: Vec<_> = tcx
+                    .generics_of(trait_def_id)
+                    .params
+                    .iter()
+                    .filter(|param| match param.kind {
+                        GenericParamDefKind::Lifetime => false,
+                        GenericParamDefKind::Type { has_default, .. } => {
+                            !has_default && param.name != kw::SelfUpper
+                        }
+                        GenericParamDefKind::Const { has_default } => !has_default,
+                    })
+                    .map(|param| format!("/* {} */", param.name))
+                    .collect();
+                if params.is_empty() {
+                    path
+                } else {
+                    has_placeholders = true;
+                    format!("{path}<{}>", params.join(", "))
+                }
+            })
+            .collect();
+        traits.sort();
+        (traits, has_placeholders, implicit_sized)
     }
 
     // Search for a bound on a type parameter which includes the associated item
@@ -1969,10 +2302,14 @@ pub fn associated_path_to_ty(
                     reported
                 } else {
                     // Don't print `TyErr` to the user.
+                    let (traits, has_placeholders, implicit_sized) = self
+                        .probe_traits_that_match_assoc_ty(hir_ref_id, span, qself_ty, assoc_ident);
                     self.report_ambiguous_associated_type(
                         span,
-                        &qself_ty.to_string(),
-                        "Trait",
+                        Some(&qself_ty.to_string()),
+                        traits,
+                        has_placeholders,
+                        implicit_sized,
                         assoc_ident.name,
                     )
                 };
@@ -2076,15 +2413,17 @@ fn qpath_to_ty(
             let is_part_of_fn_in_self_trait = parent_def_id == Some(trait_def_id);
 
             let type_name = if is_part_of_self_trait_constraints || is_part_of_fn_in_self_trait {
-                "Self"
+                Some("Self")
             } else {
-                "Type"
+                None
             };
 
             self.report_ambiguous_associated_type(
                 span,
                 type_name,
-                &path_str,
+                vec![path_str],
+                false,
+                None,
                 item_segment.ident.name,
             );
             return tcx.ty_error();
@@ -2607,7 +2946,9 @@ fn ast_ty_to_ty_inner(&self, ast_ty: &hir::Ty<'_>, borrowed: bool, in_path: bool
             hir::TyKind::Never => tcx.types.never,
             hir::TyKind::Tup(fields) => tcx.mk_tup(fields.iter().map(|t| self.ast_ty_to_ty(t))),
             hir::TyKind::BareFn(bf) => {
-                require_c_abi_if_c_variadic(tcx, bf.decl, bf.abi, ast_ty.span);
+                let abi_suggestion =
+                    bf.abi_span.map(|span| (span, Applicability::MachineApplicable));
+                require_c_abi_if_c_variadic(tcx, bf.decl, bf.abi, ast_ty.span, abi_suggestion);
 
                 tcx.mk_fn_ptr(self.ty_of_fn(
                     ast_ty.hir_id,
@@ -2632,8 +2973,8 @@ fn ast_ty_to_ty_inner(&self, ast_ty: &hir::Ty<'_>, borrowed: bool, in_path: bool
                 let def_id = item_id.def_id.to_def_id();
 
                 match opaque_ty.kind {
-                    hir::ItemKind::OpaqueTy(hir::OpaqueTy { origin, .. }) => {
-                        self.impl_trait_ty_to_ty(def_id, lifetimes, origin)
+                    hir::ItemKind::OpaqueTy(hir::OpaqueTy { origin, in_trait, .. }) => {
+                        self.impl_trait_ty_to_ty(def_id, lifetimes, origin, in_trait)
                     }
                     ref i => bug!("`impl Trait` pointed to non-opaque type?? {:#?}", i),
                 }
@@ -2672,14 +3013,19 @@ fn ast_ty_to_ty_inner(&self, ast_ty: &hir::Ty<'_>, borrowed: bool, in_path: bool
                 self.normalize_ty(ast_ty.span, array_ty)
             }
             hir::TyKind::Typeof(ref e) => {
+                // The expression is type-checked as an anonymous constant, see `type_of`.
                 let ty = tcx.type_of(tcx.hir().local_def_id(e.hir_id));
-                let span = ast_ty.span;
-                tcx.sess.emit_err(TypeofReservedKeywordUsed {
-                    span,
-                    ty,
-                    opt_sugg: Some((span, Applicability::MachineApplicable))
-                        .filter(|_| ty.is_suggestable(tcx, false)),
-                });
+                if !tcx.features().typeof_expr {
+                    let span = ast_ty.span;
+                    let mut err = tcx.sess.create_err(TypeofReservedKeywordUsed {
+                        span,
+                        ty,
+                        opt_sugg: Some((span, Applicability::MachineApplicable))
+                            .filter(|_| ty.is_suggestable(tcx, false)),
+                    });
+                    add_feature_diagnostics(&mut err, &tcx.sess.parse_sess, sym::typeof_expr);
+                    err.emit();
+                }
 
                 ty
             }
@@ -2704,6 +3050,7 @@ fn impl_trait_ty_to_ty(
         def_id: DefId,
         lifetimes: &[hir::GenericArg<'_>],
         origin: OpaqueTyOrigin,
+        in_trait: bool,
     ) -> Ty<'tcx> {
         debug!("impl_trait_ty_to_ty(def_id={:?}, lifetimes={:?})", def_id, lifetimes);
         let tcx = self.tcx();
@@ -2733,11 +3080,17 @@ fn impl_trait_ty_to_ty(
                     // For `impl Trait` in the types of statics, constants,
                     // locals and type aliases. These capture all parent
                     // lifetimes, so they can use their identity subst.
+                    //
+                    // `impl Trait` in the return type of a trait method is a
+                    // projection out of the trait rather than an opaque type,
+                    // so it also uses the identity subst of its parents.
                     GenericParamDefKind::Lifetime
-                        if matches!(
-                            origin,
-                            hir::OpaqueTyOrigin::FnReturn(..) | hir::OpaqueTyOrigin::AsyncFn(..)
-                        ) =>
+                        if !in_trait
+                            && matches!(
+                                origin,
+                                hir::OpaqueTyOrigin::FnReturn(..)
+                                    | hir::OpaqueTyOrigin::AsyncFn(..)
+                            ) =>
                     {
                         tcx.lifetimes.re_static.into()
                     }
@@ -2747,7 +3100,11 @@ fn impl_trait_ty_to_ty(
         });
         debug!("impl_trait_ty_to_ty: substs={:?}", substs);
 
-        let ty = tcx.mk_opaque(def_id, substs);
+        let ty = if in_trait {
+            tcx.mk_projection(def_id, substs)
+        } else {
+            tcx.mk_opaque(def_id, substs)
+        };
         debug!("impl_trait_ty_to_ty: {}", ty);
         ty
     }
@@ -2983,7 +3340,14 @@ fn compute_object_lifetime_bound(
         // error.
         let r = derived_region_bounds[0];
         if derived_region_bounds[1..].iter().any(|r1| r != *r1) {
-            tcx.sess.emit_err(AmbiguousLifetimeBound { span });
+            let mut lifetimes = vec![];
+            for region in &derived_region_bounds {
+                let lifetime = region.to_string();
+                if !lifetimes.contains(&lifetime) {
+                    lifetimes.push(lifetime);
+                }
+            }
+            tcx.sess.emit_err(AmbiguousLifetimeBound { span, lifetimes });
         }
         Some(r)
     }
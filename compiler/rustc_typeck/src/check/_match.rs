@@ -5,7 +5,7 @@
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
 use rustc_infer::traits::Obligation;
 use rustc_middle::ty::{self, ToPredicate, Ty};
-use rustc_span::Span;
+use rustc_span::{Span, DUMMY_SP};
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt;
 use rustc_trait_selection::traits::{
     IfExpressionCause, MatchExpressionArmCause, ObligationCause, ObligationCauseCode,
@@ -53,7 +53,7 @@ pub fn check_match(
         // on any empty type and is therefore unreachable; should the flow
         // of execution reach it, we will panic, so bottom is an appropriate
         // type in that case)
-        let mut all_arms_diverge = Diverges::WarnedAlways;
+        let mut all_arms_diverge = Diverges::WarnedAlways { span: DUMMY_SP };
 
         let expected = orig_expected.adjust_for_branches(self);
         debug!(?expected);
@@ -1,5 +1,8 @@
 use super::method::MethodCallee;
 use super::{Expectation, FnCtxt, TupleArgumentsFlag};
+use crate::errors::{
+    CmseNonSecureCallInputsTooLarge, CmseNonSecureCallOutputTooLarge, ConstUnstableIntrinsic,
+};
 use crate::type_error_struct;
 
 use rustc_errors::{struct_span_err, Applicability, Diagnostic};
@@ -352,6 +355,38 @@ fn maybe_suggest_bad_array_definition(
         false
     }
 
+    /// Calling a const-unstable intrinsic in a const context requires the intrinsic's feature
+    /// gate. Report a missing gate here, pointing at the call, rather than leaving it to const
+    /// checking.
+    fn check_intrinsic_const_stability(
+        &self,
+        call_expr: &'tcx hir::Expr<'tcx>,
+        callee_expr: &'tcx hir::Expr<'tcx>,
+        def_id: DefId,
+    ) {
+        let tcx = self.tcx;
+        let owner = tcx.hir().enclosing_body_owner(call_expr.hir_id);
+        let Some(const_context) = tcx.hir().body_const_context(owner) else { return };
+        if !tcx.is_const_fn_raw(def_id) {
+            return;
+        }
+        let Some(const_stab) = tcx.lookup_const_stability(def_id) else { return };
+        let feature = const_stab.feature;
+        if !const_stab.is_const_unstable()
+            || call_expr.span.allows_unstable(feature)
+            || tcx.features().declared_lib_features.iter().any(|&(sym, _)| sym == feature)
+        {
+            return;
+        }
+        tcx.sess.emit_err(ConstUnstableIntrinsic {
+            span: callee_expr.span,
+            name: tcx.item_name(def_id),
+            kind: const_context,
+            feature,
+            feature_help: tcx.sess.is_nightly_build().then_some(()),
+        });
+    }
+
     fn confirm_builtin_call(
         &self,
         call_expr: &'tcx hir::Expr<'tcx>,
@@ -389,6 +424,22 @@ fn confirm_builtin_call(
                             .emit();
                     }
                 }
+
+                // The `simd_*` platform intrinsics are declared with fully generic signatures,
+                // so we can only check that they are called with suitable vectors once all
+                // inference variables have been resolved.
+                if self.tcx.fn_sig(def_id).abi() == abi::Abi::PlatformIntrinsic
+                    && self.tcx.item_name(def_id).as_str().starts_with("simd_")
+                {
+                    self.deferred_simd_intrinsic_checks.borrow_mut().push((
+                        self.tcx.item_name(def_id),
+                        fn_sig,
+                        call_expr.span,
+                    ));
+                }
+                if self.tcx.is_intrinsic(def_id) {
+                    self.check_intrinsic_const_stability(call_expr, callee_expr, def_id);
+                }
                 (fn_sig, Some(def_id))
             }
             ty::FnPtr(sig) => (sig, None),
@@ -552,9 +603,61 @@ fn confirm_builtin_call(
             def_id,
         );
 
+        if fn_sig.abi == abi::Abi::CCmseNonSecureCall {
+            self.check_cmse_nonsecure_call_sizes(call_expr, arg_exprs, fn_sig);
+        }
+
         fn_sig.output()
     }
 
+    /// Checks that the arguments and the return value of a call through a
+    /// `"C-cmse-nonsecure-call"` function pointer can be passed in registers, as the non-secure
+    /// callee can't access the secure stack. Types whose layout isn't known are skipped.
+    fn check_cmse_nonsecure_call_sizes(
+        &self,
+        call_expr: &'tcx hir::Expr<'tcx>,
+        arg_exprs: &'tcx [hir::Expr<'tcx>],
+        fn_sig: ty::FnSig<'tcx>,
+    ) {
+        let layout_of = |ty: Ty<'tcx>| {
+            let ty = self.tcx.erase_regions(self.resolve_vars_if_possible(ty));
+            if ty.needs_infer() || ty.needs_subst() || ty.references_error() {
+                return None;
+            }
+            self.tcx.layout_of(self.param_env.and(ty)).ok()
+        };
+
+        // Arguments are passed in the four 32-bit registers `r0`-`r3`, each one starting at a
+        // register boundary that satisfies its alignment.
+        let mut used_bytes = 0;
+        for (&ty, arg) in iter::zip(fn_sig.inputs(), arg_exprs) {
+            let Some(layout) = layout_of(ty) else { return };
+            let align = layout.align.abi.bytes().max(4);
+            let start = (used_bytes + align - 1) / align * align;
+            used_bytes = (start + layout.size.bytes() + 3) / 4 * 4;
+            if used_bytes > 16 {
+                self.tcx.sess.emit_err(CmseNonSecureCallInputsTooLarge {
+                    span: arg.span,
+                    ty: layout.ty,
+                    size: layout.size.bytes(),
+                });
+                return;
+            }
+        }
+
+        // The return value is passed in `r0`, or in `r0` and `r1` for 64-bit scalars.
+        let Some(layout) = layout_of(fn_sig.output()) else { return };
+        let size = layout.size.bytes();
+        let is_wide_scalar = size <= 8 && matches!(layout.abi, rustc_target::abi::Abi::Scalar(_));
+        if size > 4 && !is_wide_scalar {
+            self.tcx.sess.emit_err(CmseNonSecureCallOutputTooLarge {
+                span: call_expr.span,
+                ty: layout.ty,
+                size,
+            });
+        }
+    }
+
     fn confirm_deferred_closure_call(
         &self,
         call_expr: &'tcx hir::Expr<'tcx>,
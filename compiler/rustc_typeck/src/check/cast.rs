@@ -487,22 +487,34 @@ fn report_cast_error(&self, fcx: &FnCtxt<'a, 'tcx>, e: CastError) {
                     ty::FnDef(..) | ty::FnPtr(..) | ty::Closure(..)
                 ) {
                     let mut label = true;
-                    // Check `impl From<self.expr_ty> for self.cast_ty {}` for accurate suggestion:
-                    if let Ok(snippet) = fcx.tcx.sess.source_map().span_to_snippet(self.expr_span)
-                        && let Some(from_trait) = fcx.tcx.get_diagnostic_item(sym::From)
-                    {
+                    // Check `impl From<self.expr_ty> for self.cast_ty {}` for accurate suggestion,
+                    // falling back to `impl TryFrom<self.expr_ty> for self.cast_ty {}` and
+                    // `impl TryInto<self.cast_ty> for self.expr_ty {}`:
+                    if let Ok(snippet) = fcx.tcx.sess.source_map().span_to_snippet(self.expr_span) {
                         let ty = fcx.resolve_vars_if_possible(self.cast_ty);
                         // Erase regions to avoid panic in `prove_value` when calling
                         // `type_implements_trait`.
                         let ty = fcx.tcx.erase_regions(ty);
                         let expr_ty = fcx.resolve_vars_if_possible(self.expr_ty);
                         let expr_ty = fcx.tcx.erase_regions(expr_ty);
-                        let ty_params = fcx.tcx.mk_substs_trait(expr_ty, &[]);
-                        if fcx
-                            .infcx
-                            .type_implements_trait(from_trait, ty, ty_params, fcx.param_env)
-                            .must_apply_modulo_regions()
-                        {
+                        let implements = |trait_name, self_ty, param_ty| {
+                            fcx.tcx.get_diagnostic_item(trait_name).map_or(false, |trait_did| {
+                                let ty_params = fcx.tcx.mk_substs_trait(param_ty, &[]);
+                                fcx.infcx
+                                    .type_implements_trait(
+                                        trait_did,
+                                        self_ty,
+                                        ty_params,
+                                        fcx.param_env,
+                                    )
+                                    .must_apply_modulo_regions()
+                            })
+                        };
+                        // `TryFrom` and `TryInto` are only in the prelude since the 2021 edition,
+                        // and their methods can't be called in constants.
+                        let suggest_fallible = fcx.tcx.sess.rust_2021()
+                            && !fcx.tcx.hir().is_inside_const_context(self.expr.hir_id);
+                        if implements(sym::From, ty, expr_ty) {
                             label = false;
                             err.span_suggestion(
                                 self.span,
@@ -510,6 +522,37 @@ fn report_cast_error(&self, fcx: &FnCtxt<'a, 'tcx>, e: CastError) {
                                 format!("{}::from({})", self.cast_ty, snippet),
                                 Applicability::MaybeIncorrect,
                             );
+                        } else if suggest_fallible && implements(sym::TryFrom, ty, expr_ty) {
+                            label = false;
+                            err.span_suggestion(
+                                self.span,
+                                "consider using the `TryFrom` trait instead",
+                                format!("{}::try_from({})", self.cast_ty, snippet),
+                                Applicability::MaybeIncorrect,
+                            );
+                            err.note(
+                                "`TryFrom` returns a `Result`, which has to be handled in case \
+                                 the conversion fails",
+                            );
+                        } else if suggest_fallible && implements(sym::TryInto, expr_ty, ty) {
+                            label = false;
+                            let snippet = if self.expr.precedence().order()
+                                < rustc_ast::util::parser::PREC_POSTFIX
+                            {
+                                format!("({})", snippet)
+                            } else {
+                                snippet
+                            };
+                            err.span_suggestion(
+                                self.span,
+                                "consider using the `TryInto` trait instead",
+                                format!("{}.try_into()", snippet),
+                                Applicability::MaybeIncorrect,
+                            );
+                            err.note(
+                                "`TryInto` returns a `Result`, which has to be handled in case \
+                                 the conversion fails",
+                            );
                         }
                     }
                     let msg = "an `as` expression can only be used to convert between primitive \
@@ -1,4 +1,5 @@
 use crate::check::intrinsicck::InlineAsmCtxt;
+use crate::errors::{ConflictingPackedAndAlignRepr, ConflictingPackedRepr, NonExhaustiveNoEffect};
 
 use super::coercion::CoerceMany;
 use super::compare_method::check_type_bounds;
@@ -692,7 +693,9 @@ pub(super) fn check_opaque_for_cycles<'tcx>(
 ) -> Result<(), ErrorGuaranteed> {
     if tcx.try_expand_impl_trait_type(def_id.to_def_id(), substs).is_err() {
         let reported = match origin {
-            hir::OpaqueTyOrigin::AsyncFn(..) => async_opaque_type_cycle_error(tcx, span),
+            hir::OpaqueTyOrigin::AsyncFn(fn_def_id) => {
+                async_opaque_type_cycle_error(tcx, *fn_def_id, span)
+            }
             _ => opaque_type_cycle_error(tcx, def_id, span),
         };
         Err(reported)
@@ -779,6 +782,18 @@ fn check_opaque_meets_bounds<'tcx>(
     );
 }
 
+/// `#[non_exhaustive]` is accepted on traits, impls and their items by attribute
+/// checking, but has no meaning there, so reject it with a dedicated error.
+fn check_non_exhaustive_attr(tcx: TyCtxt<'_>, def_id: LocalDefId) {
+    if let Some(attr) = tcx.get_attr(def_id.to_def_id(), sym::non_exhaustive) {
+        tcx.sess.emit_err(NonExhaustiveNoEffect {
+            attr_span: attr.span,
+            item_span: tcx.def_span(def_id),
+            kind: tcx.def_kind(def_id).descr(def_id.to_def_id()),
+        });
+    }
+}
+
 fn check_item_type<'tcx>(tcx: TyCtxt<'tcx>, id: hir::ItemId) {
     debug!(
         "check_item_type(it.def_id={:?}, it.name={})",
@@ -809,6 +824,10 @@ fn check_item_type<'tcx>(tcx: TyCtxt<'tcx>, id: hir::ItemId) {
                 return;
             };
             debug!("ItemKind::Impl {} with id {:?}", it.ident, it.def_id);
+            check_non_exhaustive_attr(tcx, it.def_id);
+            for item in impl_.items {
+                check_non_exhaustive_attr(tcx, item.id.def_id);
+            }
             if let Some(impl_trait_ref) = tcx.impl_trait_ref(it.def_id) {
                 check_impl_items_against_trait(
                     tcx,
@@ -826,9 +845,11 @@ fn check_item_type<'tcx>(tcx: TyCtxt<'tcx>, id: hir::ItemId) {
                 return;
             };
             check_on_unimplemented(tcx, it);
+            check_non_exhaustive_attr(tcx, it.def_id);
 
             for item in items.iter() {
                 let item = tcx.hir().trait_item(item.id);
+                check_non_exhaustive_attr(tcx, item.def_id);
                 match item.kind {
                     hir::TraitItemKind::Fn(ref sig, _) => {
                         let abi = sig.header.abi;
@@ -877,7 +898,7 @@ fn check_item_type<'tcx>(tcx: TyCtxt<'tcx>, id: hir::ItemId) {
         }
         DefKind::ForeignMod => {
             let it = tcx.hir().item(id);
-            let hir::ItemKind::ForeignMod { abi, items } = it.kind else {
+            let hir::ItemKind::ForeignMod { abi, abi_span, items } = it.kind else {
                 return;
             };
             check_abi(tcx, it.hir_id(), it.span, abi);
@@ -893,6 +914,8 @@ fn check_item_type<'tcx>(tcx: TyCtxt<'tcx>, id: hir::ItemId) {
                     intrinsic::check_platform_intrinsic_type(tcx, item);
                 }
             } else {
+                // Changing the ABI of the block affects all of its items, so only suggest it once.
+                let mut abi_suggestion = abi_span.map(|span| (span, Applicability::MaybeIncorrect));
                 for item in items {
                     let def_id = item.id.def_id;
                     let generics = tcx.generics_of(def_id);
@@ -928,7 +951,16 @@ fn check_item_type<'tcx>(tcx: TyCtxt<'tcx>, id: hir::ItemId) {
                     let item = tcx.hir().foreign_item(item.id);
                     match item.kind {
                         hir::ForeignItemKind::Fn(ref fn_decl, _, _) => {
-                            require_c_abi_if_c_variadic(tcx, fn_decl, abi, item.span);
+                            require_c_abi_if_c_variadic(
+                                tcx,
+                                fn_decl,
+                                abi,
+                                item.span,
+                                abi_suggestion,
+                            );
+                            if fn_decl.c_variadic {
+                                abi_suggestion = None;
+                            }
                         }
                         hir::ForeignItemKind::Static(..) => {
                             check_static_inhabited(tcx, def_id);
@@ -1147,6 +1179,7 @@ fn check_impl_items_against_trait<'tcx>(
             missing_items_must_implement_one_of_err(
                 tcx,
                 tcx.def_span(impl_id),
+                impl_trait_ref.def_id,
                 missing_items,
                 attr_span,
             );
@@ -1193,6 +1226,21 @@ pub fn check_simd(tcx: TyCtxt<'_>, sp: Span, def_id: LocalDefId) {
             return;
         }
 
+        if let ty::Array(..) = e.kind() && fields.len() > 1 {
+            let mut err = struct_span_err!(
+                tcx.sess,
+                sp,
+                E0791,
+                "SIMD vector with an array field cannot have any other fields"
+            );
+            err.span_label(sp, "the lanes of this SIMD vector must be given by a single array");
+            for field in &fields[1..] {
+                err.span_label(tcx.def_span(field.did), "additional field");
+            }
+            err.emit();
+            return;
+        }
+
         let len = if let ty::Array(_ty, c) = e.kind() {
             c.try_eval_usize(tcx, tcx.param_env(def.did()))
         } else {
@@ -1211,6 +1259,11 @@ pub fn check_simd(tcx: TyCtxt<'_>, sp: Span, def_id: LocalDefId) {
                 )
                 .emit();
                 return;
+            } else if !len.is_power_of_two() {
+                struct_span_err!(tcx.sess, sp, E0792, "SIMD vector length must be a power of two")
+                    .span_label(sp, format!("this SIMD vector has {len} lanes"))
+                    .emit();
+                return;
             }
         }
 
@@ -1246,30 +1299,44 @@ pub fn check_simd(tcx: TyCtxt<'_>, sp: Span, def_id: LocalDefId) {
 pub(super) fn check_packed(tcx: TyCtxt<'_>, sp: Span, def: ty::AdtDef<'_>) {
     let repr = def.repr();
     if repr.packed() {
-        for attr in tcx.get_attrs(def.did(), sym::repr) {
-            for r in attr::parse_repr_attr(&tcx.sess, attr) {
-                if let attr::ReprPacked(pack) = r
-                && let Some(repr_pack) = repr.pack
-                && pack as u64 != repr_pack.bytes()
-            {
-                        struct_span_err!(
-                            tcx.sess,
-                            sp,
-                            E0634,
-                            "type has conflicting packed representation hints"
-                        )
-                        .emit();
-            }
+        let hints = repr_hints_with_spans(tcx, def.did());
+        let packed_hints: Vec<_> = hints
+            .iter()
+            .filter_map(|&(ref hint, spans)| match *hint {
+                attr::ReprPacked(pack) => Some((pack as u64, spans)),
+                _ => None,
+            })
+            .collect();
+        if let Some(repr_pack) = repr.pack {
+            // The smallest of the packed hints is the one that is used.
+            let first_span = packed_hints
+                .iter()
+                .find(|&&(pack, _)| pack == repr_pack.bytes())
+                .and_then(|&(_, spans)| spans)
+                .map(|(span, _)| span);
+            for &(pack, spans) in &packed_hints {
+                if pack != repr_pack.bytes() {
+                    tcx.sess.emit_err(ConflictingPackedRepr {
+                        span: sp,
+                        first_span,
+                        conflicting_span: spans.map(|(span, _)| span),
+                        removal_span: spans.map(|(_, removal_span)| removal_span),
+                    });
+                }
             }
         }
         if repr.align.is_some() {
-            struct_span_err!(
-                tcx.sess,
-                sp,
-                E0587,
-                "type has conflicting packed and align representation hints"
-            )
-            .emit();
+            let packed_span = packed_hints.iter().find_map(|&(_, spans)| spans);
+            let align_spans = hints
+                .iter()
+                .find(|(hint, _)| matches!(hint, attr::ReprAlign(_)))
+                .and_then(|&(_, spans)| spans);
+            tcx.sess.emit_err(ConflictingPackedAndAlignRepr {
+                span: sp,
+                packed_span: packed_span.map(|(span, _)| span),
+                align_span: align_spans.map(|(span, _)| span),
+                removal_span: align_spans.map(|(_, removal_span)| removal_span),
+            });
         } else {
             if let Some(def_spans) = check_packed_inner(tcx, def.did(), &mut vec![]) {
                 let mut err = struct_span_err!(
@@ -1313,6 +1380,36 @@ pub(super) fn check_packed(tcx: TyCtxt<'_>, sp: Span, def: ty::AdtDef<'_>) {
     }
 }
 
+/// Returns the hints of the `#[repr]` attributes of `def_id`, along with the span of each hint
+/// and the span removing it, if they can be determined.
+fn repr_hints_with_spans(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+) -> Vec<(attr::ReprAttr, Option<(Span, Span)>)> {
+    let mut hints = vec![];
+    for attr in tcx.get_attrs(def_id, sym::repr) {
+        let reprs = attr::parse_repr_attr(&tcx.sess, attr);
+        let items = attr.meta_item_list().unwrap_or_default();
+        // Invalid hints are reported elsewhere and left out of `reprs`, in which case we can't
+        // tell which item each of the parsed hints comes from.
+        if reprs.len() != items.len() {
+            hints.extend(reprs.into_iter().map(|repr| (repr, None)));
+            continue;
+        }
+        for (idx, (repr, item)) in reprs.into_iter().zip(&items).enumerate() {
+            let removal_span = if items.len() == 1 {
+                attr.span
+            } else if idx + 1 < items.len() {
+                item.span().until(items[idx + 1].span())
+            } else {
+                items[idx - 1].span().shrink_to_hi().to(item.span())
+            };
+            hints.push((repr, Some((item.span(), removal_span))));
+        }
+    }
+    hints
+}
+
 pub(super) fn check_packed_inner(
     tcx: TyCtxt<'_>,
     def_id: DefId,
@@ -1669,10 +1766,66 @@ pub(super) fn check_mod_item_types(tcx: TyCtxt<'_>, module_def_id: LocalDefId) {
     }
 }
 
-fn async_opaque_type_cycle_error(tcx: TyCtxt<'_>, span: Span) -> ErrorGuaranteed {
-    struct_span_err!(tcx.sess, span, E0733, "recursion in an `async fn` requires boxing")
-        .span_label(span, "recursive `async fn`")
-        .note("a recursive `async fn` must be rewritten to return a boxed `dyn Future`")
+/// Emit an error for an `async fn` whose future contains itself.
+///
+/// For direct recursion, point at the calls to the `async fn` from within its own body.
+fn async_opaque_type_cycle_error(
+    tcx: TyCtxt<'_>,
+    fn_def_id: LocalDefId,
+    span: Span,
+) -> ErrorGuaranteed {
+    struct RecursiveCallVisitor<'tcx> {
+        tcx: TyCtxt<'tcx>,
+        typeck_results: &'tcx ty::TypeckResults<'tcx>,
+        fn_def_id: DefId,
+        calls: Vec<Span>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for RecursiveCallVisitor<'tcx> {
+        type NestedFilter = nested_filter::OnlyBodies;
+
+        fn nested_visit_map(&mut self) -> Self::Map {
+            self.tcx.hir()
+        }
+
+        fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+            // Resolve through the typeck results so that `Self::f(..)` and `self.f(..)` are
+            // found as well.
+            let callee_def_id = match expr.kind {
+                hir::ExprKind::Call(callee, _) => match callee.kind {
+                    hir::ExprKind::Path(ref qpath) => {
+                        self.typeck_results.qpath_res(qpath, callee.hir_id).opt_def_id()
+                    }
+                    _ => None,
+                },
+                hir::ExprKind::MethodCall(..) => {
+                    self.typeck_results.type_dependent_def_id(expr.hir_id)
+                }
+                _ => None,
+            };
+            if callee_def_id == Some(self.fn_def_id) {
+                self.calls.push(expr.span);
+            }
+            hir::intravisit::walk_expr(self, expr);
+        }
+    }
+
+    let mut err =
+        struct_span_err!(tcx.sess, span, E0733, "recursion in an `async fn` requires boxing");
+    err.span_label(span, "recursive `async fn`");
+    if let Some(body_id) = tcx.hir().maybe_body_owned_by(fn_def_id) {
+        let mut visitor = RecursiveCallVisitor {
+            tcx,
+            typeck_results: tcx.typeck(fn_def_id),
+            fn_def_id: fn_def_id.to_def_id(),
+            calls: vec![],
+        };
+        visitor.visit_body(tcx.hir().body(body_id));
+        for call in visitor.calls {
+            err.span_label(call, "recursive call here");
+        }
+    }
+    err.note("a recursive `async fn` must be rewritten to return a boxed `dyn Future`")
         .note(
             "consider using the `async_recursion` crate: https://crates.io/crates/async_recursion",
         )
@@ -1280,6 +1280,8 @@ pub struct CoerceMany<'tcx, 'exprs, E: AsCoercionSite> {
     final_ty: Option<Ty<'tcx>>,
     expressions: Expressions<'tcx, 'exprs, E>,
     pushed: usize,
+    /// The spans of the forced-unit coercion sites seen so far, like `break`s without a value.
+    forced_unit_spans: Vec<Span>,
 }
 
 /// The type of a `CoerceMany` that is storing up the expressions into
@@ -1309,7 +1311,13 @@ pub fn with_coercion_sites(expected_ty: Ty<'tcx>, coercion_sites: &'exprs [E]) -
     }
 
     fn make(expected_ty: Ty<'tcx>, expressions: Expressions<'tcx, 'exprs, E>) -> Self {
-        CoerceMany { expected_ty, final_ty: None, expressions, pushed: 0 }
+        CoerceMany {
+            expected_ty,
+            final_ty: None,
+            expressions,
+            pushed: 0,
+            forced_unit_spans: vec![],
+        }
     }
 
     /// Returns the "expected type" with which this coercion was
@@ -1476,6 +1484,8 @@ pub(crate) fn coerce_inner<'a>(
                         }
                     }
                     self.pushed += 1;
+                } else {
+                    self.forced_unit_spans.push(cause.span);
                 }
             }
             Err(coercion_error) => {
@@ -1576,6 +1586,20 @@ pub(crate) fn coerce_inner<'a>(
                     );
                 }
 
+                // If the expected type wasn't known up front and there are no earlier
+                // expressions, it was inferred from the forced-unit sites.
+                if self.expected_ty.is_ty_var()
+                    && self.pushed == 0
+                    && expression.is_some()
+                    && !self.forced_unit_spans.is_empty()
+                {
+                    fcx.note_unreachable_due_to_diverging_expr(
+                        &mut err,
+                        expected,
+                        &self.forced_unit_spans,
+                    );
+                }
+
                 if visitor.ret_exprs.len() > 0 && let Some(expr) = expression {
                     self.note_unreachable_loop_return(&mut err, &expr, &visitor.ret_exprs);
                 }
@@ -1,21 +1,26 @@
 use super::potentially_plural_count;
-use crate::errors::LifetimesOrBoundsMismatchOnTrait;
+use crate::errors::{
+    ImplTraitInTraitBoundNotSatisfied, LifetimeBoundMismatch, LifetimesOrBoundsMismatchOnTrait,
+};
 use crate::outlives::outlives_bounds::InferCtxtExt as _;
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::{pluralize, struct_span_err, Applicability, DiagnosticId, ErrorGuaranteed};
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit;
 use rustc_hir::{GenericParamKind, ImplItemKind, TraitItemKind};
 use rustc_infer::infer::outlives::env::OutlivesEnvironment;
-use rustc_infer::infer::{self, TyCtxtInferExt};
+use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
+use rustc_infer::infer::{self, InferCtxt, TyCtxtInferExt};
 use rustc_infer::traits::util;
 use rustc_middle::ty::error::{ExpectedFound, TypeError};
-use rustc_middle::ty::subst::{InternalSubsts, Subst};
+use rustc_middle::ty::fold::{TypeFoldable, TypeFolder, TypeSuperFoldable};
+use rustc_middle::ty::subst::{InternalSubsts, Subst, SubstsRef};
 use rustc_middle::ty::util::ExplicitSelf;
-use rustc_middle::ty::{self, DefIdTree};
+use rustc_middle::ty::{self, DefIdTree, Ty};
 use rustc_middle::ty::{GenericParamDefKind, ToPredicate, TyCtxt};
-use rustc_span::Span;
+use rustc_span::{Span, Symbol};
 use rustc_trait_selection::traits::error_reporting::InferCtxtExt;
 use rustc_trait_selection::traits::{
     self, ObligationCause, ObligationCauseCode, ObligationCtxt, Reveal,
@@ -64,6 +69,10 @@ pub(crate) fn compare_impl_method<'tcx>(
         return;
     }
 
+    if let Err(_) = tcx.collect_trait_impl_trait_tys(impl_m.def_id) {
+        return;
+    }
+
     if let Err(_) = compare_predicate_entailment(tcx, impl_m, impl_m_span, trait_m, impl_trait_ref)
     {
         return;
@@ -415,6 +424,259 @@ fn compare_predicate_entailment<'tcx>(
     })
 }
 
+/// Infers the hidden types of the return-position `impl Trait`s of `trait_m` by equating the
+/// return type of the trait method with that of the implementing method `def_id`.
+///
+/// Every `impl Trait` in the trait method's return type is replaced by a fresh inference
+/// variable, and the bounds written on it are registered against that variable, so that a
+/// hidden type which doesn't satisfy the trait's bounds is reported here, against the impl.
+pub fn collect_trait_impl_trait_tys<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+) -> Result<&'tcx FxHashMap<DefId, Ty<'tcx>>, ErrorGuaranteed> {
+    let impl_m = tcx.associated_item(def_id);
+    let Some(trait_m_def_id) = impl_m.trait_item_def_id else {
+        return Ok(tcx.arena.alloc(FxHashMap::default()));
+    };
+    let trait_m = tcx.associated_item(trait_m_def_id);
+    let impl_trait_ref = tcx.impl_trait_ref(impl_m.container_id(tcx)).unwrap();
+    let param_env = tcx.param_env(def_id);
+
+    let impl_m_hir_id = tcx.hir().local_def_id_to_hir_id(def_id.expect_local());
+    let return_span = tcx.hir().fn_decl_by_hir_id(impl_m_hir_id).unwrap().output.span();
+    let cause = ObligationCause::new(
+        return_span,
+        impl_m_hir_id,
+        ObligationCauseCode::CompareImplItemObligation {
+            impl_item_def_id: def_id.expect_local(),
+            trait_item_def_id: trait_m.def_id,
+            kind: impl_m.kind,
+        },
+    );
+
+    // Create mapping from impl to placeholder.
+    let impl_to_placeholder_substs = InternalSubsts::identity_for_item(tcx, def_id);
+
+    // Create mapping from trait to placeholder.
+    let trait_to_placeholder_substs = impl_to_placeholder_substs.rebase_onto(
+        tcx,
+        impl_m.container_id(tcx),
+        impl_trait_ref.substs,
+    );
+
+    tcx.infer_ctxt().enter(|ref infcx| {
+        let ocx = ObligationCtxt::new(infcx);
+        let norm_cause = ObligationCause::misc(return_span, impl_m_hir_id);
+
+        let trait_return_ty = tcx
+            .liberate_late_bound_regions(
+                def_id,
+                tcx.bound_fn_sig(trait_m.def_id).subst(tcx, trait_to_placeholder_substs),
+            )
+            .output();
+        let mut collector = ImplTraitInTraitCollector::new(infcx, return_span);
+        let trait_return_ty = trait_return_ty.fold_with(&mut collector);
+        if collector.types.is_empty() {
+            return Ok(&*tcx.arena.alloc(FxHashMap::default()));
+        }
+        let trait_return_ty = ocx.normalize(norm_cause.clone(), param_env, trait_return_ty);
+
+        let impl_return_ty = infcx.replace_bound_vars_with_fresh_vars(
+            return_span,
+            infer::HigherRankedType,
+            tcx.fn_sig(def_id).output(),
+        );
+        let impl_return_ty = ocx.normalize(norm_cause, param_env, impl_return_ty);
+
+        match infcx.at(&cause, param_env).eq(trait_return_ty, impl_return_ty) {
+            Ok(infer_ok) => ocx.register_infer_ok_obligations(infer_ok),
+            Err(terr) => {
+                let mut diag = struct_span_err!(
+                    tcx.sess,
+                    cause.span(),
+                    E0053,
+                    "method `{}` has an incompatible return type for trait",
+                    trait_m.name
+                );
+                let trait_return_span = tcx
+                    .hir()
+                    .get_if_local(trait_m.def_id)
+                    .and_then(|node| node.fn_decl())
+                    .map(|decl| (decl.output.span(), "return type in trait".to_owned()));
+                infcx.note_type_err(
+                    &mut diag,
+                    &cause,
+                    trait_return_span,
+                    Some(infer::ValuePairs::Terms(ExpectedFound {
+                        expected: trait_return_ty.into(),
+                        found: impl_return_ty.into(),
+                    })),
+                    terr,
+                    false,
+                    false,
+                );
+                return Err(diag.emit());
+            }
+        }
+
+        let errors = ocx.select_all_or_error();
+        if !errors.is_empty() {
+            return Err(infcx.report_fulfillment_errors(&errors, None, false));
+        }
+
+        // Only now that the hidden types are known do we check them against the bounds
+        // written on the `impl Trait`s in the trait, so that these errors can point at both.
+        let bounds_ocx = ObligationCtxt::new(infcx);
+        for (_, (_, bounds)) in &collector.types {
+            for &(predicate, span) in bounds {
+                bounds_ocx.register_obligation(traits::Obligation::new(
+                    ObligationCause::new(
+                        span,
+                        impl_m_hir_id,
+                        ObligationCauseCode::BindingObligation(trait_m.def_id, span),
+                    ),
+                    param_env,
+                    predicate,
+                ));
+            }
+        }
+        let errors = bounds_ocx.select_all_or_error();
+        if let Some(error) = errors.first() {
+            let predicate = infcx.resolve_vars_if_possible(error.root_obligation.predicate);
+            let (hidden_ty, bound) = match predicate.kind().skip_binder() {
+                ty::PredicateKind::Trait(pred) => {
+                    (pred.self_ty(), pred.trait_ref.print_only_trait_path().to_string())
+                }
+                ty::PredicateKind::Projection(pred) => (
+                    pred.projection_ty.self_ty(),
+                    format!("{} == {}", pred.projection_ty, pred.term),
+                ),
+                _ => (impl_return_ty, predicate.to_string()),
+            };
+            return Err(tcx.sess.emit_err(ImplTraitInTraitBoundNotSatisfied {
+                span: return_span,
+                bound_span: error.root_obligation.cause.span,
+                hidden_ty,
+                bound,
+            }));
+        }
+
+        // Finally, resolve all regions. This catches wily misuses of
+        // lifetime parameters.
+        let outlives_environment = OutlivesEnvironment::new(param_env);
+        infcx.check_region_obligations_and_report_errors(
+            def_id.expect_local(),
+            &outlives_environment,
+        );
+
+        // The hidden types refer to the free regions we created when liberating the trait
+        // method's signature. Projection substitutes them with the impl method's substs
+        // followed by the placeholder's own lifetimes, so rewrite those free regions into
+        // early-bound regions at the matching index.
+        let trait_m_count = tcx.generics_of(trait_m.def_id).count();
+        let impl_m_count = tcx.generics_of(def_id).count();
+        let mut collected_tys = FxHashMap::default();
+        for (opaque_def_id, (ty, _)) in collector.types {
+            let ty = match infcx.fully_resolve(ty) {
+                Ok(ty) => ty,
+                Err(err) => {
+                    tcx.sess.delay_span_bug(
+                        return_span,
+                        format!("could not fully resolve: {ty} => {err:?}"),
+                    );
+                    tcx.ty_error()
+                }
+            };
+            let id_substs = InternalSubsts::identity_for_item(tcx, opaque_def_id);
+            let map: FxHashMap<ty::Region<'tcx>, ty::Region<'tcx>> =
+                iter::zip(collector.substs[&opaque_def_id], id_substs)
+                    .enumerate()
+                    .skip(trait_m_count)
+                    .filter_map(|(index, (arg, id_arg))| {
+                        let ty::GenericArgKind::Lifetime(region) = arg.unpack() else {
+                            return None;
+                        };
+                        let ty::ReEarlyBound(param) = *id_arg.expect_region() else {
+                            return None;
+                        };
+                        let index = (impl_m_count + index - trait_m_count) as u32;
+                        Some((region, tcx.mk_region(ty::ReEarlyBound(ty::EarlyBoundRegion {
+                            index,
+                            ..param
+                        }))))
+                    })
+                    .collect();
+            let ty = tcx.fold_regions(ty, |region, _| match region.kind() {
+                ty::ReFree(_) => map.get(&region).copied().unwrap_or(region),
+                _ => region,
+            });
+            collected_tys.insert(opaque_def_id, ty);
+        }
+
+        Ok(&*tcx.arena.alloc(collected_tys))
+    })
+}
+
+/// Replaces every projection to a return-position `impl Trait` placeholder with an inference
+/// variable, remembering the placeholder's bounds for that variable.
+struct ImplTraitInTraitCollector<'a, 'tcx> {
+    infcx: &'a InferCtxt<'a, 'tcx>,
+    types: FxHashMap<DefId, (Ty<'tcx>, Vec<(ty::Predicate<'tcx>, Span)>)>,
+    substs: FxHashMap<DefId, SubstsRef<'tcx>>,
+    span: Span,
+}
+
+impl<'a, 'tcx> ImplTraitInTraitCollector<'a, 'tcx> {
+    fn new(infcx: &'a InferCtxt<'a, 'tcx>, span: Span) -> Self {
+        ImplTraitInTraitCollector {
+            infcx,
+            types: FxHashMap::default(),
+            substs: FxHashMap::default(),
+            span,
+        }
+    }
+}
+
+impl<'tcx> TypeFolder<'tcx> for ImplTraitInTraitCollector<'_, 'tcx> {
+    fn tcx<'a>(&'a self) -> TyCtxt<'tcx> {
+        self.infcx.tcx
+    }
+
+    fn fold_ty(&mut self, ty: Ty<'tcx>) -> Ty<'tcx> {
+        let ty::Projection(proj) = *ty.kind() else {
+            return ty.super_fold_with(self);
+        };
+        if self.tcx().def_kind(proj.item_def_id) != DefKind::ImplTraitPlaceholder {
+            return ty.super_fold_with(self);
+        }
+        if let Some(&(infer_ty, _)) = self.types.get(&proj.item_def_id) {
+            return infer_ty;
+        }
+
+        let infer_ty = self.infcx.next_ty_var(TypeVariableOrigin {
+            span: self.span,
+            kind: TypeVariableOriginKind::MiscVariable,
+        });
+        self.types.insert(proj.item_def_id, (infer_ty, vec![]));
+        self.substs.insert(proj.item_def_id, proj.substs);
+
+        // The bounds mention the placeholder itself (and possibly nested ones), which
+        // folding them replaces with the inference variables created here.
+        let bounds = self
+            .tcx()
+            .explicit_item_bounds(proj.item_def_id)
+            .iter()
+            .map(|&(pred, span)| {
+                let pred = ty::EarlyBinder(pred).subst(self.tcx(), proj.substs);
+                (pred.fold_with(self), span)
+            })
+            .collect();
+        self.types.get_mut(&proj.item_def_id).unwrap().1 = bounds;
+
+        infer_ty
+    }
+}
+
 fn check_region_bounds_on_impl_item<'tcx>(
     tcx: TyCtxt<'tcx>,
     impl_m: &ty::AssocItem,
@@ -442,27 +704,40 @@ fn check_region_bounds_on_impl_item<'tcx>(
     // are zero. Since I don't quite know how to phrase things at
     // the moment, give a kind of vague error message.
     if trait_params != impl_params {
-        let span = tcx
+        let impl_hir_generics = tcx
             .hir()
             .get_generics(impl_m.def_id.expect_local())
-            .expect("expected impl item to have generics or else we can't compare them")
-            .span;
-        let generics_span = if let Some(local_def_id) = trait_m.def_id.as_local() {
-            Some(
-                tcx.hir()
-                    .get_generics(local_def_id)
-                    .expect("expected trait item to have generics or else we can't compare them")
-                    .span,
-            )
-        } else {
-            None
-        };
+            .expect("expected impl item to have generics or else we can't compare them");
+        let trait_hir_generics = trait_m.def_id.as_local().map(|local_def_id| {
+            tcx.hir()
+                .get_generics(local_def_id)
+                .expect("expected trait item to have generics or else we can't compare them")
+        });
+
+        // Point at every outlives bound that only one of the two items declares, as those
+        // are what changes which lifetimes end up being early-bound.
+        let mut bound_mismatches = vec![];
+        if let Some(trait_hir_generics) = trait_hir_generics {
+            let trait_bounds = lifetime_bounds(trait_hir_generics);
+            let impl_bounds = lifetime_bounds(impl_hir_generics);
+            for (bound, span) in &trait_bounds {
+                if !impl_bounds.iter().any(|(impl_bound, _)| impl_bound == bound) {
+                    bound_mismatches.push(LifetimeBoundMismatch::OnlyOnTrait { span: *span });
+                }
+            }
+            for (bound, span) in &impl_bounds {
+                if !trait_bounds.iter().any(|(trait_bound, _)| trait_bound == bound) {
+                    bound_mismatches.push(LifetimeBoundMismatch::OnlyOnImpl { span: *span });
+                }
+            }
+        }
 
         let reported = tcx.sess.emit_err(LifetimesOrBoundsMismatchOnTrait {
-            span,
+            span: impl_hir_generics.span,
             item_kind: assoc_item_kind_str(impl_m),
             ident: impl_m.ident(tcx),
-            generics_span,
+            generics_span: trait_hir_generics.map(|generics| generics.span),
+            bound_mismatches,
         });
         return Err(reported);
     }
@@ -470,6 +745,45 @@ fn check_region_bounds_on_impl_item<'tcx>(
     Ok(())
 }
 
+/// A lifetime in an outlives bound on an associated item, identified in a way that can be
+/// compared between the trait and impl versions of the item.
+#[derive(PartialEq, Eq)]
+enum BoundLifetime {
+    /// The n-th lifetime parameter of the item itself, whatever it is named.
+    Own(usize),
+    /// Any other lifetime, such as `'static` or one from the enclosing impl or trait.
+    Other(Symbol),
+}
+
+/// Collects the `'a: 'b` bounds declared on `generics`, whether inline or in a where clause,
+/// along with the span of the outlived lifetime of each bound.
+fn lifetime_bounds(generics: &hir::Generics<'_>) -> Vec<((BoundLifetime, BoundLifetime), Span)> {
+    let own_lifetimes: Vec<Symbol> = generics
+        .params
+        .iter()
+        .filter(|param| matches!(param.kind, GenericParamKind::Lifetime { .. }))
+        .map(|param| param.name.ident().name)
+        .collect();
+    let lifetime = |lifetime: &hir::Lifetime| {
+        let name = lifetime.name.ident().name;
+        match own_lifetimes.iter().position(|&own| own == name) {
+            Some(index) => BoundLifetime::Own(index),
+            None => BoundLifetime::Other(name),
+        }
+    };
+
+    let mut bounds = vec![];
+    for predicate in generics.predicates {
+        let hir::WherePredicate::RegionPredicate(predicate) = predicate else { continue };
+        for bound in predicate.bounds {
+            if let hir::GenericBound::Outlives(outlived) = bound {
+                bounds.push(((lifetime(&predicate.lifetime), lifetime(outlived)), outlived.span));
+            }
+        }
+    }
+    bounds
+}
+
 #[instrument(level = "debug", skip(infcx))]
 fn extract_spans_for_error_reporting<'a, 'tcx>(
     infcx: &infer::InferCtxt<'a, 'tcx>,
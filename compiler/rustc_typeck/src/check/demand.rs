@@ -39,6 +39,7 @@ pub fn emit_coerce_suggestions(
             return;
         }
         self.suggest_no_capture_closure(err, expected, expr_ty);
+        self.suggest_fn_item_to_fn_ptr_adapter(err, expr, expected, expr_ty);
         self.suggest_boxing_when_appropriate(err, expr, expected, expr_ty);
         self.suggest_missing_parentheses(err, expr);
         self.suggest_block_to_brackets_peeling_refs(err, expr, expr_ty, expected);
@@ -512,10 +513,12 @@ pub fn get_conversion_methods(
         checked_ty: Ty<'tcx>,
         hir_id: hir::HirId,
     ) -> Vec<AssocItem> {
+        let suppress_clones = self.is_suggestion_suppressed(checked_ty, sym::clone);
         let mut methods =
             self.probe_for_return_type(span, probe::Mode::MethodCall, expected, checked_ty, hir_id);
         methods.retain(|m| {
             self.has_only_self_parameter(m)
+                && !(suppress_clones && self.is_clone_like(m))
                 && self
                     .tcx
                     // This special internal attribute is used to permit
@@ -545,6 +548,13 @@ fn has_only_self_parameter(&self, method: &AssocItem) -> bool {
         }
     }
 
+    /// Whether the method returns a copy of its receiver, i.e. it has the shape of
+    /// `fn(&self) -> Self`. Those are the conversions `#[rustc_do_not_suggest(clone)]` opts out of.
+    fn is_clone_like(&self, method: &AssocItem) -> bool {
+        let sig = self.tcx.fn_sig(method.def_id).skip_binder();
+        sig.inputs()[0].peel_refs() == sig.output()
+    }
+
     /// Identify some cases where `as_ref()` would be appropriate and suggest it.
     ///
     /// Given the following code:
@@ -675,6 +685,18 @@ pub(crate) fn is_else_if_block(&self, expr: &hir::Expr<'_>) -> bool {
         false
     }
 
+    /// Returns whether the type of a value, once references are peeled, opted out of the given
+    /// family of suggestions (`borrow`, `deref` or `clone`) through `#[rustc_do_not_suggest]`.
+    pub(crate) fn is_suggestion_suppressed(&self, ty: Ty<'tcx>, family: Symbol) -> bool {
+        let ty::Adt(adt, _) = self.resolve_vars_if_possible(ty).peel_refs().kind() else {
+            return false;
+        };
+        self.tcx.get_attrs(adt.did(), sym::rustc_do_not_suggest).any(|attr| {
+            attr.meta_item_list()
+                .map_or(false, |families| families.iter().any(|item| item.has_name(family)))
+        })
+    }
+
     /// This function is used to determine potential "simple" improvements or users' errors and
     /// provide them useful help. For example:
     ///
@@ -764,7 +786,9 @@ pub fn check_ref(
                         self.tcx.mk_imm_ref(self.tcx.mk_region(ty::ReStatic), checked_ty)
                     }
                 };
-                if self.can_coerce(ref_ty, expected) {
+                if self.can_coerce(ref_ty, expected)
+                    && !self.is_suggestion_suppressed(checked_ty, sym::borrow)
+                {
                     let mut sugg_sp = sp;
                     if let hir::ExprKind::MethodCall(ref segment, ref args, _) = expr.kind {
                         let clone_trait =
@@ -887,6 +911,7 @@ pub fn check_ref(
                 if let Some(steps) = self.deref_steps(ty_a, ty_b)
                     // Only suggest valid if dereferencing needed.
                     && steps > 0
+                    && !self.is_suggestion_suppressed(ty_a, sym::deref)
                     // The pointer type implements `Copy` trait so the suggestion is always valid.
                     && let Ok(src) = sm.span_to_snippet(sp)
                 {
@@ -985,6 +1010,10 @@ pub fn check_ref(
                         ));
                     }
 
+                    if self.is_suggestion_suppressed(checked_ty, sym::deref) {
+                        return None;
+                    }
+
                     // For this suggestion to make sense, the type would need to be `Copy`,
                     // or we have to be moving out of a `Box<T>`
                     if self.type_is_copy_modulo_regions(self.param_env, expected, sp)
@@ -32,7 +32,11 @@ pub enum Diverges {
 
     /// Same as `Always` but with a reachability
     /// warning already emitted.
-    WarnedAlways,
+    WarnedAlways {
+        /// The `Span` points to the expression
+        /// that caused us to diverge, as for `Always`.
+        span: Span,
+    },
 }
 
 // Convenience impls for combining `Diverges`.
@@ -1,13 +1,16 @@
 // FIXME(@lcnr): Move this module out of `rustc_typeck`.
 //
 // We don't do any drop checking during hir typeck.
+use crate::errors::UnreachableDropImpl;
 use crate::hir::def_id::{DefId, LocalDefId};
 use rustc_errors::{struct_span_err, ErrorGuaranteed};
+use rustc_hir::LangItem;
 use rustc_middle::ty::error::TypeError;
 use rustc_middle::ty::relate::{Relate, RelateResult, TypeRelation};
 use rustc_middle::ty::subst::SubstsRef;
 use rustc_middle::ty::util::IgnoreRegions;
 use rustc_middle::ty::{self, Predicate, Ty, TyCtxt};
+use rustc_session::lint::{builtin::UNREACHABLE_DROP_IMPLS, Level};
 
 /// This function confirms that the `Drop` implementation identified by
 /// `drop_impl_did` is not any more specialized than the type it is
@@ -43,7 +46,15 @@ pub fn check_drop_impl(tcx: TyCtxt<'_>, drop_impl_did: DefId) -> Result<(), Erro
                 dtor_predicates,
                 adt_def.did().expect_local(),
                 self_to_impl_substs,
-            )
+            )?;
+
+            lint_unsatisfiable_drop_predicates(
+                tcx,
+                drop_impl_did.expect_local(),
+                dtor_predicates,
+                dtor_self_type,
+            );
+            Ok(())
         }
         _ => {
             // Destructors only work on nominal types.  This was
@@ -229,6 +240,68 @@ fn ensure_drop_predicates_are_implied_by_item_defn<'tcx>(
     result
 }
 
+/// Warns if one of the predicates of the `Drop` impl can never hold, meaning the
+/// destructor never runs. We only look for bounds on type parameters naming a trait
+/// that has no impls and cannot gain any outside of this crate, which coherence
+/// already gives us a complete list of.
+fn lint_unsatisfiable_drop_predicates<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    drop_impl_did: LocalDefId,
+    dtor_predicates: ty::GenericPredicates<'tcx>,
+    dtor_self_type: Ty<'tcx>,
+) {
+    let hir_id = tcx.hir().local_def_id_to_hir_id(drop_impl_did);
+    let (level, _) = tcx.lint_level_at_node(UNREACHABLE_DROP_IMPLS, hir_id);
+    if level == Level::Allow {
+        return;
+    }
+
+    let sized_trait = tcx.lang_items().require(LangItem::Sized).ok();
+    let is_sized = |ty: Ty<'tcx>| {
+        dtor_predicates.predicates.iter().any(|&(predicate, _)| {
+            matches!(
+                predicate.kind().skip_binder(),
+                ty::PredicateKind::Trait(pred)
+                    if Some(pred.def_id()) == sized_trait && pred.self_ty() == ty
+            )
+        })
+    };
+
+    for &(predicate, predicate_sp) in dtor_predicates.predicates {
+        let ty::PredicateKind::Trait(pred) = predicate.kind().skip_binder() else { continue };
+        let trait_def_id = pred.def_id();
+        if pred.polarity != ty::ImplPolarity::Positive
+            || !matches!(pred.self_ty().kind(), ty::Param(_))
+            || !trait_def_id.is_local()
+            || tcx.visibility(trait_def_id).is_public()
+            || tcx.trait_is_auto(trait_def_id)
+        {
+            continue;
+        }
+        // `dyn Trait: Trait` holds without any impl.
+        if tcx.is_object_safe(trait_def_id) && !is_sized(pred.self_ty()) {
+            continue;
+        }
+        let impls = tcx.trait_impls_of(trait_def_id);
+        if !impls.blanket_impls().is_empty() || !impls.non_blanket_impls().is_empty() {
+            continue;
+        }
+
+        tcx.emit_spanned_lint(
+            UNREACHABLE_DROP_IMPLS,
+            hir_id,
+            tcx.def_span(drop_impl_did),
+            UnreachableDropImpl {
+                bound_span: predicate_sp,
+                bound: predicate.to_string(),
+                trait_name: tcx.def_path_str(trait_def_id),
+                self_ty: dtor_self_type,
+            },
+        );
+        return;
+    }
+}
+
 // This is an implementation of the TypeRelation trait with the
 // aim of simply comparing for equality (without side-effects).
 // It is not intended to be used anywhere else other than here.
@@ -6,6 +6,7 @@
 use crate::check::cast;
 use crate::check::coercion::CoerceMany;
 use crate::check::fatally_break_rust;
+use crate::check::method::probe::{self, IsSuggestion, ProbeScope};
 use crate::check::method::SelfSource;
 use crate::check::report_unexpected_variant_res;
 use crate::check::BreakableCtxt;
@@ -2421,6 +2422,31 @@ fn ban_private_field_access(
                 expr,
                 None,
             );
+        } else if !self.expr_in_place(expr.hir_id) {
+            // Otherwise, look for an accessible getter following the `get_<field>` convention.
+            let getter = Ident::new(Symbol::intern(&format!("get_{field}")), field.span);
+            let getter_takes_no_args = self
+                .probe_for_name(
+                    getter.span,
+                    probe::Mode::MethodCall,
+                    getter,
+                    IsSuggestion(true),
+                    expr_t,
+                    expr.hir_id,
+                    ProbeScope::TraitsInScope,
+                )
+                .map_or(false, |pick| {
+                    pick.item.fn_has_self_parameter
+                        && self.tcx.fn_sig(pick.item.def_id).inputs().skip_binder().len() == 1
+                });
+            if getter_takes_no_args {
+                err.span_suggestion_verbose(
+                    field.span,
+                    &format!("a method `{getter}` exists, consider calling it instead"),
+                    format!("{getter}()"),
+                    Applicability::MaybeIncorrect,
+                );
+            }
         }
         err.emit();
     }
@@ -2773,6 +2799,8 @@ fn check_expr_index(
                                         syntax (e.g., `tuple.0`)",
                             );
                         }
+                    } else {
+                        self.suggest_get_for_unsupported_index(&mut err, base, base_t, idx, expr);
                     }
                     err.emit();
                     self.tcx.ty_error()
@@ -2781,6 +2809,48 @@ fn check_expr_index(
         }
     }
 
+    /// Suggests rewriting `base[idx]` into `base.get(idx)` when `base_t` doesn't implement
+    /// `Index`, but has a `get` method taking a single argument.
+    fn suggest_get_for_unsupported_index(
+        &self,
+        err: &mut Diagnostic,
+        base: &'tcx hir::Expr<'tcx>,
+        base_t: Ty<'tcx>,
+        idx: &'tcx hir::Expr<'tcx>,
+        expr: &'tcx hir::Expr<'tcx>,
+    ) {
+        let Ok(pick) = self.lookup_probe(
+            expr.span,
+            Ident::new(sym::get, expr.span),
+            base_t,
+            expr,
+            ProbeScope::TraitsInScope,
+        ) else {
+            return;
+        };
+        if !pick.item.fn_has_self_parameter {
+            return;
+        }
+        let sig = self.tcx.fn_sig(pick.item.def_id).skip_binder();
+        // `self` and the index.
+        if sig.inputs().len() != 2 {
+            return;
+        }
+        err.multipart_suggestion_verbose(
+            "to access an element of this collection, use its `get` method",
+            vec![
+                (base.span.between(idx.span), ".get(".to_string()),
+                (idx.span.shrink_to_hi().with_hi(expr.span.hi()), ")".to_string()),
+            ],
+            Applicability::MaybeIncorrect,
+        );
+        if let ty::Adt(def, _) = sig.output().kind()
+            && self.tcx.is_diagnostic_item(sym::Option, def.did())
+        {
+            err.note("`get` returns an `Option`, which is `None` if there is no such element");
+        }
+    }
+
     fn point_at_index_if_possible(
         &self,
         errors: &mut Vec<traits::FulfillmentError<'tcx>>,
@@ -4,7 +4,15 @@
     graph::WithSuccessors,
     graph::{iterate::DepthFirstSearch, vec_graph::VecGraph},
 };
-use rustc_middle::ty::{self, Ty};
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::ty::{self, GenericParamDefKind, ToPredicate, Ty};
+use rustc_session::lint::{builtin::DEPENDENCY_ON_UNIT_NEVER_TYPE_FALLBACK, Level};
+use rustc_span::Span;
+use rustc_trait_selection::traits::{self, query::evaluate_obligation::InferCtxtExt as _};
 
 impl<'tcx> FnCtxt<'_, 'tcx> {
     /// Performs type inference fallback, returning true if any fallback
@@ -292,6 +300,7 @@ fn calculate_diverging_fallback(
         // `!`.
         let mut diverging_fallback = FxHashMap::default();
         diverging_fallback.reserve(diverging_vids.len());
+        let mut linted_roots = FxHashSet::default();
         for &diverging_vid in &diverging_vids {
             let diverging_ty = self.tcx.mk_ty_var(diverging_vid);
             let root_vid = self.root_var(diverging_vid);
@@ -340,13 +349,98 @@ fn calculate_diverging_fallback(
                 diverging_fallback.insert(diverging_ty, self.tcx.types.unit);
             } else {
                 debug!("fallback to ! - all diverging: {:?}", diverging_vid);
-                diverging_fallback.insert(diverging_ty, self.tcx.mk_diverging_default());
+                let fallback_ty = self.tcx.mk_diverging_default();
+                if fallback_ty.is_unit() && linted_roots.insert(root_vid) {
+                    self.lint_dependency_on_unit_fallback(root_vid, &coercion_graph);
+                }
+                diverging_fallback.insert(diverging_ty, fallback_ty);
             }
         }
 
         diverging_fallback
     }
 
+    /// Diverging variables which are not coerced into any live code currently
+    /// fall back to `()`, but are meant to fall back to `!` in the future. Warn
+    /// if this would change trait selection, i.e. if the variable (or a variable
+    /// it is coerced into) has to implement a trait which is implemented for `()`
+    /// but not for `!`.
+    fn lint_dependency_on_unit_fallback(
+        &self,
+        root_vid: ty::TyVid,
+        coercion_graph: &VecGraph<ty::TyVid>,
+    ) {
+        if self.is_tainted_by_errors() {
+            return;
+        }
+        let (level, _) =
+            self.tcx.lint_level_at_node(DEPENDENCY_ON_UNIT_NEVER_TYPE_FALLBACK, self.body_id);
+        if level == Level::Allow {
+            return;
+        }
+
+        let reachable_vids: FxHashSet<ty::TyVid> =
+            coercion_graph.depth_first_search(root_vid).collect();
+        let holds_for = |obligation: &traits::PredicateObligation<'tcx>,
+                         trait_pred: ty::PolyTraitPredicate<'tcx>,
+                         self_ty: Ty<'tcx>| {
+            let trait_pred = trait_pred.map_bound(|pred| ty::TraitPredicate {
+                trait_ref: ty::TraitRef::new(
+                    pred.def_id(),
+                    self.tcx.mk_substs_trait(self_ty, &pred.trait_ref.substs[1..]),
+                ),
+                ..pred
+            });
+            self.predicate_may_hold(&obligation.with(trait_pred.to_predicate(self.tcx)))
+        };
+        let pending_obligations = self.fulfillment_cx.borrow_mut().pending_obligations();
+        let Some((obligation, trait_def_id)) = pending_obligations.iter().find_map(|obligation| {
+            let trait_pred = obligation.predicate.to_opt_poly_trait_pred()?;
+            let self_vid = self.root_vid(trait_pred.self_ty().no_bound_vars()?)?;
+            (reachable_vids.contains(&self_vid)
+                && holds_for(obligation, trait_pred, self.tcx.types.unit)
+                && !holds_for(obligation, trait_pred, self.tcx.types.never))
+                .then(|| (obligation, trait_pred.def_id()))
+        }) else {
+            return;
+        };
+
+        let mut finder = UnitFallbackAnnotationFinder {
+            fcx: self,
+            reachable_vids: &reachable_vids,
+            suggestion: None,
+        };
+        finder.visit_expr(self.tcx.hir().expect_expr(self.body_id));
+        let suggestion = finder.suggestion;
+
+        let span = obligation.cause.span;
+        self.tcx.struct_span_lint_hir(
+            DEPENDENCY_ON_UNIT_NEVER_TYPE_FALLBACK,
+            self.body_id,
+            span,
+            |lint| {
+                let mut err = lint.build("this function depends on never type fallback being `()`");
+                err.span_label(
+                    span,
+                    format!("`!` does not implement `{}`", self.tcx.def_path_str(trait_def_id)),
+                );
+                err.note(
+                    "the type of a diverging expression currently falls back to `()`, \
+                     but this is expected to change to `!` in the future",
+                );
+                if let Some((span, sugg)) = suggestion {
+                    err.span_suggestion_verbose(
+                        span,
+                        "use `()` annotations to avoid fallback changes",
+                        sugg,
+                        Applicability::MachineApplicable,
+                    );
+                }
+                err.emit();
+            },
+        );
+    }
+
     /// Returns a graph whose nodes are (unresolved) inference variables and where
     /// an edge `?A -> ?B` indicates that the variable `?A` is coerced to `?B`.
     fn create_coercion_graph(&self) -> VecGraph<ty::TyVid> {
@@ -396,3 +490,67 @@ fn root_vid(&self, ty: Ty<'tcx>) -> Option<ty::TyVid> {
         Some(self.root_var(self.shallow_resolve(ty).ty_vid()?))
     }
 }
+
+/// Finds a place where `()` can be written down explicitly to constrain one of the
+/// given type variables, either as the type of a `let` binding or as a generic
+/// argument of a path to a function.
+struct UnitFallbackAnnotationFinder<'a, 'tcx> {
+    fcx: &'a FnCtxt<'a, 'tcx>,
+    reachable_vids: &'a FxHashSet<ty::TyVid>,
+    suggestion: Option<(Span, String)>,
+}
+
+impl<'a, 'tcx> UnitFallbackAnnotationFinder<'a, 'tcx> {
+    fn is_reachable(&self, ty: Ty<'tcx>) -> bool {
+        self.fcx.root_vid(ty).map_or(false, |vid| self.reachable_vids.contains(&vid))
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UnitFallbackAnnotationFinder<'a, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.fcx.tcx.hir()
+    }
+
+    fn visit_local(&mut self, local: &'tcx hir::Local<'tcx>) {
+        if self.suggestion.is_none()
+            && local.ty.is_none()
+            && let Some(ty) = self.fcx.typeck_results.borrow().node_type_opt(local.hir_id)
+            && self.is_reachable(ty)
+        {
+            self.suggestion = Some((local.pat.span.shrink_to_hi(), ": ()".to_string()));
+        }
+        intravisit::walk_local(self, local);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if self.suggestion.is_none()
+            && let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = expr.kind
+            && let [.., segment] = path.segments
+            && segment.args.is_none()
+            && let Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) = path.res
+            && let Some(substs) = self.fcx.typeck_results.borrow().node_substs_opt(expr.hir_id)
+        {
+            let generics = self.fcx.tcx.generics_of(def_id);
+            let own_substs = &substs[generics.parent_count..];
+            // Only suggest a turbofish if every own generic parameter can be written
+            // down in it, using `_` for all of those which are unrelated.
+            if !generics.params.is_empty()
+                && generics.params.iter().all(|param| {
+                    matches!(param.kind, GenericParamDefKind::Type { synthetic: false, .. })
+                })
+                && own_substs.iter().any(|arg| self.is_reachable(arg.expect_ty()))
+            {
+                let args = own_substs
+                    .iter()
+                    .map(|arg| if self.is_reachable(arg.expect_ty()) { "()" } else { "_" })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                self.suggestion =
+                    Some((segment.ident.span.shrink_to_hi(), format!("::<{}>", args)));
+            }
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
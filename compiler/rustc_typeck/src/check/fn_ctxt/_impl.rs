@@ -56,7 +56,7 @@ pub(in super::super) fn warn_if_unreachable(&self, id: hir::HirId, span: Span, k
                 && !span.is_desugaring(DesugaringKind::Async)
                 && !orig_span.is_desugaring(DesugaringKind::Await)
             {
-                self.diverges.set(Diverges::WarnedAlways);
+                self.diverges.set(Diverges::WarnedAlways { span: orig_span });
 
                 debug!("warn_if_unreachable: id={:?} span={:?} kind={}", id, span, kind);
 
@@ -1014,6 +1014,32 @@ pub(in super::super) fn note_internal_mutation_in_method(
         }
     }
 
+    /// When a type mismatch happens in code that is unreachable because of a diverging expression
+    /// the expected type was inferred from, like the `break 'a;` in `'a: { break 'a; 1 }`, point
+    /// at that expression, as it explains both the expected type and the unreachability.
+    ///
+    /// `inferred_from` are the spans of the expressions the expected type was inferred from.
+    pub(in super::super) fn note_unreachable_due_to_diverging_expr(
+        &self,
+        err: &mut Diagnostic,
+        expected: Ty<'tcx>,
+        inferred_from: &[Span],
+    ) {
+        let span = match self.diverges.get() {
+            Diverges::Always { span, .. } | Diverges::WarnedAlways { span } => span,
+            Diverges::Maybe => return,
+        };
+        if inferred_from.contains(&span) {
+            err.span_label(
+                span,
+                format!(
+                    "expected `{expected}` because of this expression, which also makes any code \
+                     following it unreachable"
+                ),
+            );
+        }
+    }
+
     pub(in super::super) fn note_need_for_fn_pointer(
         &self,
         err: &mut Diagnostic,
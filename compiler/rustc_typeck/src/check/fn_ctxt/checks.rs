@@ -55,6 +55,17 @@ pub(in super::super) fn check_transmutes(&self) {
         }
     }
 
+    pub(in super::super) fn check_simd_intrinsics(&self) {
+        let mut deferred_simd_intrinsic_checks = self.deferred_simd_intrinsic_checks.borrow_mut();
+        debug!(
+            "FnCtxt::check_simd_intrinsics: {} deferred checks",
+            deferred_simd_intrinsic_checks.len()
+        );
+        for (name, sig, span) in deferred_simd_intrinsic_checks.drain(..) {
+            self.check_simd_intrinsic(span, name, sig);
+        }
+    }
+
     pub(in super::super) fn check_asms(&self) {
         let mut deferred_asm_checks = self.deferred_asm_checks.borrow_mut();
         debug!("FnCtxt::check_asm: {} deferred checks", deferred_asm_checks.len());
@@ -430,7 +441,7 @@ fn report_arg_errors(
         err_code: &str,
         fn_def_id: Option<DefId>,
         call_span: Span,
-        call_expr: &hir::Expr<'tcx>,
+        call_expr: &'tcx hir::Expr<'tcx>,
     ) {
         // Next, let's construct the error
         let (error_span, full_call_span, ctor_of, is_method) = match &call_expr.kind {
@@ -758,6 +769,9 @@ fn has_error_or_infer<'tcx>(tys: impl IntoIterator<Item = Ty<'tcx>>) -> bool {
                 Some(expected_idx.as_usize()),
                 is_method,
             );
+            if is_method && let Some(fn_def_id) = fn_def_id {
+                self.note_ref_counted_method_shadowing(&mut err, call_expr, fn_def_id);
+            }
             err.emit();
             return;
         }
@@ -1042,6 +1056,9 @@ enum SuggestionText {
         self.label_fn_like(&mut err, fn_def_id, callee_ty, None, is_method);
 
         // And add a suggestion block for all of the parameters
+        let only_reordered =
+            matches!(suggestion_text, SuggestionText::Swap | SuggestionText::Reorder)
+                && matched_inputs.iter().all(Option::is_some);
         let suggestion_text = match suggestion_text {
             SuggestionText::None => None,
             SuggestionText::Provide(plural) => {
@@ -1054,8 +1071,34 @@ enum SuggestionText {
             SuggestionText::Reorder => Some("reorder these arguments".to_string()),
             SuggestionText::DidYouMean => Some("did you mean".to_string()),
         };
-        if let Some(suggestion_text) = suggestion_text {
-            let source_map = self.sess().source_map();
+        let source_map = self.sess().source_map();
+        // If the arguments only need to be reordered, point at each argument that has to be moved
+        // instead of rewriting the whole call.
+        let reorder_suggestion = only_reordered
+            .then(|| {
+                matched_inputs
+                    .iter_enumerated()
+                    .filter_map(|(expected_idx, provided_idx)| {
+                        let provided_idx = (*provided_idx)?;
+                        let target_idx = expected_idx.to_provided_idx();
+                        (provided_idx != target_idx).then_some((target_idx, provided_idx))
+                    })
+                    .map(|(target_idx, provided_idx)| {
+                        let (_, target_span) = provided_arg_tys[target_idx];
+                        let (_, provided_span) = provided_arg_tys[provided_idx];
+                        Some((target_span, source_map.span_to_snippet(provided_span).ok()?))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .flatten()
+            .filter(|parts| !parts.is_empty());
+        if let (Some(suggestion_text), Some(parts)) = (&suggestion_text, reorder_suggestion) {
+            err.multipart_suggestion_verbose(
+                suggestion_text,
+                parts,
+                Applicability::MaybeIncorrect,
+            );
+        } else if let Some(suggestion_text) = suggestion_text {
             let mut suggestion = format!(
                 "{}(",
                 source_map.span_to_snippet(full_call_span).unwrap_or_else(|_| fn_def_id
@@ -1095,6 +1138,12 @@ enum SuggestionText {
             );
         }
 
+        // A method of an `Rc` or `Arc` may have been picked over the one the user meant to call
+        // on the pointee.
+        if is_method && let Some(fn_def_id) = fn_def_id {
+            self.note_ref_counted_method_shadowing(&mut err, call_expr, fn_def_id);
+        }
+
         err.emit();
     }
 
@@ -1256,16 +1305,85 @@ pub(in super::super) fn check_decl(&self, decl: Declaration<'tcx>) {
         if let Some(blk) = decl.els {
             let previous_diverges = self.diverges.get();
             let else_ty = self.check_block_with_expected(blk, NoExpectation);
-            let cause = self.cause(blk.span, ObligationCauseCode::LetElse);
+            // Point at the part of the block which returns normally, not the whole block.
+            let normal_exit_span = match (blk.expr, blk.stmts.last()) {
+                (Some(tail), _) => tail.span,
+                (None, Some(stmt)) => stmt.span,
+                (None, None) => blk.span,
+            };
+            let cause = self.cause(normal_exit_span, ObligationCauseCode::LetElse);
             if let Some(mut err) =
                 self.demand_eqtype_with_origin(&cause, self.tcx.types.never, else_ty)
             {
+                self.suggest_diverging_let_else(&mut err, blk);
                 err.emit();
             }
             self.diverges.set(previous_diverges);
         }
     }
 
+    /// Suggests appending a diverging expression to the `else` block of a `let...else`
+    /// statement which doesn't diverge.
+    fn suggest_diverging_let_else(&self, err: &mut Diagnostic, blk: &'tcx hir::Block<'tcx>) {
+        let mut diverging_exprs = vec!["return", "panic!()"];
+        if self.is_inside_loop(blk.hir_id) {
+            diverging_exprs.push("continue");
+        }
+
+        let source_map = self.tcx.sess.source_map();
+        let (span, prefix, suffix) = match (blk.expr, blk.stmts.last()) {
+            // The tail expression has to become a statement, which might need a semicolon.
+            (Some(tail), _) => {
+                let needs_semi = !matches!(
+                    tail.kind,
+                    ExprKind::If(..)
+                        | ExprKind::Match(..)
+                        | ExprKind::Block(..)
+                        | ExprKind::Loop(..)
+                );
+                (tail.span, if needs_semi { ";" } else { "" }, "")
+            }
+            (None, Some(stmt)) => (stmt.span, "", ";"),
+            (None, None) => {
+                err.span_suggestions(
+                    blk.span,
+                    "try adding a diverging expression",
+                    diverging_exprs.into_iter().map(|expr| format!("{{ {} }}", expr)),
+                    Applicability::MaybeIncorrect,
+                );
+                return;
+            }
+        };
+        let separator = if source_map.is_multiline(blk.span.shrink_to_lo().to(span)) {
+            format!("\n{}", source_map.indentation_before(span).unwrap_or_default())
+        } else {
+            " ".to_string()
+        };
+        err.span_suggestions(
+            span.shrink_to_hi(),
+            "try adding a diverging expression",
+            diverging_exprs
+                .into_iter()
+                .map(|expr| format!("{}{}{}{}", prefix, separator, expr, suffix)),
+            Applicability::MaybeIncorrect,
+        );
+    }
+
+    /// Whether `hir_id` is inside of a loop of the current body, so that `continue` can be used.
+    fn is_inside_loop(&self, hir_id: hir::HirId) -> bool {
+        for (_, node) in self.tcx.hir().parent_iter(hir_id) {
+            match node {
+                Node::Expr(hir::Expr { kind: ExprKind::Loop(..), .. }) => return true,
+                Node::Expr(hir::Expr { kind: ExprKind::Closure(..), .. })
+                | Node::Item(_)
+                | Node::TraitItem(_)
+                | Node::ImplItem(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
     /// Type check a `let` statement.
     pub fn check_decl_local(&self, local: &'tcx hir::Local<'tcx>) {
         self.check_decl(local.into());
@@ -6,6 +6,7 @@
 use rustc_errors::{Applicability, Diagnostic, MultiSpan};
 use rustc_hir as hir;
 use rustc_hir::def::{CtorOf, DefKind};
+use rustc_hir::intravisit::Visitor;
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{
     Expr, ExprKind, GenericBound, Node, Path, QPath, Stmt, StmtKind, TyKind, WherePredicate,
@@ -14,8 +15,9 @@
 use rustc_infer::traits::{self, StatementAsExpression};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, Binder, IsSuggestable, Subst, ToPredicate, Ty};
-use rustc_span::symbol::sym;
+use rustc_span::symbol::{kw, sym};
 use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt as _;
 
 impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
@@ -57,10 +59,96 @@ pub fn suggest_mismatched_types_on_tail(
             self.suggest_missing_break_or_return_expr(
                 err, expr, &fn_decl, expected, found, blk_id, fn_id,
             );
+        } else if let Some(closure_id) = self.tcx.hir().get_return_block(blk_id)
+            && let Node::Expr(&Expr { kind: ExprKind::Closure(closure), .. }) =
+                self.tcx.hir().get(closure_id)
+        {
+            pointing_at_return_type =
+                self.suggest_missing_closure_return_type(err, closure, expected, found);
         }
         pointing_at_return_type
     }
 
+    /// Like `suggest_missing_return_type`, but for closures and async blocks whose return type
+    /// was inferred to be `()`:
+    ///
+    /// ```compile_fail,E0308
+    /// # fn f(cond: bool) {
+    /// let c = || {
+    ///     if cond { return; }
+    ///     1
+    /// };
+    /// # }
+    /// ```
+    fn suggest_missing_closure_return_type(
+        &self,
+        err: &mut Diagnostic,
+        closure: &hir::Closure<'_>,
+        expected: Ty<'tcx>,
+        found: Ty<'tcx>,
+    ) -> bool {
+        // If the return type was known before checking the body (e.g. from an `Fn` bound),
+        // annotating the closure won't help.
+        if self.return_type_pre_known || !expected.is_unit() {
+            return false;
+        }
+        let hir::FnRetTy::DefaultReturn(span) = closure.fn_decl.output else {
+            return false;
+        };
+        let found =
+            self.resolve_numeric_literals_with_default(self.resolve_vars_if_possible(found));
+        if found.is_unit() || !found.is_suggestable(self.tcx, false) {
+            return false;
+        }
+        let body = self.tcx.hir().body(closure.body);
+        match body.generator_kind {
+            // A return type can only be added to a closure whose body is a block.
+            None if matches!(body.value.kind, ExprKind::Block(..)) => {
+                // A bare `return;` is usually what made the return type `()`, so it has to return
+                // a value as well once the closure has a return type.
+                struct BareReturnVisitor(Vec<Span>);
+                impl<'v> Visitor<'v> for BareReturnVisitor {
+                    fn visit_expr(&mut self, ex: &'v hir::Expr<'v>) {
+                        match ex.kind {
+                            ExprKind::Ret(None) => self.0.push(ex.span),
+                            // Returns in nested closures don't return from this closure.
+                            ExprKind::Closure(..) => return,
+                            _ => {}
+                        }
+                        hir::intravisit::walk_expr(self, ex);
+                    }
+                }
+                let mut visitor = BareReturnVisitor(vec![]);
+                visitor.visit_expr(&body.value);
+
+                let mut suggestion = vec![(span, format!("-> {found} "))];
+                let (msg, applicability) = if visitor.0.is_empty() {
+                    (
+                        rustc_errors::fluent::typeck::add_return_type_closure,
+                        Applicability::MaybeIncorrect,
+                    )
+                } else {
+                    suggestion.extend(
+                        visitor.0.into_iter().map(|span| (span, "return /* value */".to_string())),
+                    );
+                    (
+                        rustc_errors::fluent::typeck::add_return_type_closure_and_values,
+                        Applicability::HasPlaceholders,
+                    )
+                };
+                err.multipart_suggestion(msg, suggestion, applicability);
+                true
+            }
+            Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Block)) => {
+                // Point at `async` or `async move`, not at the whole block.
+                let span = self.tcx.sess.source_map().span_until_char(closure.fn_decl_span, '{');
+                err.subdiagnostic(AddReturnTypeSuggestion::AsyncBlock { span, found });
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// When encountering an fn-like ctor that needs to unify with a value, check whether calling
     /// the ctor would successfully solve the type mismatch and if so, suggest it:
     /// ```compile_fail,E0308
@@ -334,6 +422,100 @@ pub(in super::super) fn suggest_no_capture_closure(
         }
     }
 
+    /// When a function item can't be coerced to a function pointer only because it is `unsafe`
+    /// or uses a different ABI, name the difference and suggest calling it from a closure.
+    pub(in super::super) fn suggest_fn_item_to_fn_ptr_adapter(
+        &self,
+        err: &mut Diagnostic,
+        expr: &hir::Expr<'_>,
+        expected: Ty<'tcx>,
+        found: Ty<'tcx>,
+    ) {
+        let (ty::FnPtr(expected_sig), ty::FnDef(def_id, substs)) = (expected.kind(), found.kind())
+        else {
+            return;
+        };
+        if self.tcx.is_intrinsic(*def_id) {
+            return;
+        }
+        let found_sig = self.tcx.bound_fn_sig(*def_id).subst(self.tcx, substs);
+        let unsafety_mismatch = expected_sig.unsafety() == hir::Unsafety::Normal
+            && found_sig.unsafety() == hir::Unsafety::Unsafe;
+        let abi_mismatch = expected_sig.abi() != found_sig.abi();
+        if !unsafety_mismatch && !abi_mismatch {
+            return;
+        }
+        // Only explain the mismatch if it is the only difference between the signatures.
+        let adjusted_sig = found_sig.map_bound(|sig| ty::FnSig {
+            unsafety: expected_sig.unsafety(),
+            abi: expected_sig.abi(),
+            ..sig
+        });
+        if self.can_eq(self.param_env, self.tcx.mk_fn_ptr(adjusted_sig), expected).is_err() {
+            return;
+        }
+
+        let path = self.tcx.def_path_str(*def_id);
+        if unsafety_mismatch {
+            err.note(&format!(
+                "`{}` is an `unsafe` function, so it cannot be coerced to a safe function pointer",
+                path
+            ));
+        }
+        if abi_mismatch {
+            err.note(&format!(
+                "`{}` uses the `extern \"{}\"` ABI, but the function pointer uses the \
+                 `extern \"{}\"` ABI",
+                path,
+                found_sig.abi().name(),
+                expected_sig.abi().name(),
+            ));
+        }
+
+        if expected_sig.abi() != Abi::Rust {
+            err.help(&format!(
+                "closures can only be coerced to `extern \"Rust\"` function pointers, consider \
+                 defining an `extern \"{}\"` function that calls `{}` instead",
+                expected_sig.abi().name(),
+                path,
+            ));
+            return;
+        }
+        let Ok(callee) = self.tcx.sess.source_map().span_to_snippet(expr.span) else {
+            return;
+        };
+        let arg_names = self.tcx.fn_arg_names(*def_id);
+        let num_args = expected_sig.inputs().skip_binder().len();
+        let use_arg_names = arg_names.len() == num_args
+            && arg_names.iter().enumerate().all(|(i, ident)| {
+                !matches!(ident.name, kw::Empty | kw::Underscore)
+                    && !arg_names[..i].iter().any(|prev| prev.name == ident.name)
+            });
+        let args = (0..num_args)
+            .map(|i| if use_arg_names { arg_names[i].to_string() } else { format!("arg{}", i) })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let (msg, call, applicability) = if unsafety_mismatch {
+            (
+                "consider calling the function from a closure, in an `unsafe` block",
+                format!("unsafe {{ {}({}) }}", callee, args),
+                Applicability::MaybeIncorrect,
+            )
+        } else {
+            (
+                "consider calling the function from a closure",
+                format!("{}({})", callee, args),
+                Applicability::MachineApplicable,
+            )
+        };
+        err.span_suggestion_verbose(
+            expr.span,
+            msg,
+            format!("|{}| {}", args, call),
+            applicability,
+        );
+    }
+
     /// When encountering an `impl Future` where `BoxFuture` is expected, suggest `Box::pin`.
     #[instrument(skip(self, err))]
     pub(in super::super) fn suggest_calling_boxed_future_when_appropriate(
@@ -150,6 +150,7 @@ fn record(
                     scope_span,
                     yield_span: yield_data.span,
                     expr: expr.map(|e| e.hir_id),
+                    temporary_span: expr.and_then(|e| self.temporary_borrow_span(ty, e)),
                 });
             }
         } else {
@@ -170,6 +171,48 @@ fn record(
             }
         }
     }
+
+    /// If `expr` is a temporary borrowed for the duration of its parent method call (e.g. the
+    /// `&self` of `foo.lock().unwrap().bar()`), or a raw pointer passed to a function or
+    /// constructor, returns the span of that parent expression so diagnostics can suggest
+    /// moving it into a `let` binding.
+    fn temporary_borrow_span(&self, ty: Ty<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Span> {
+        let hir = self.fcx.tcx.hir();
+        let parent = hir.get_parent_node(expr.hir_id);
+        let Some(hir::Node::Expr(parent_expr)) = hir.find(parent) else {
+            return None;
+        };
+
+        // ```rust
+        // impl T {
+        //     fn foo(&self) -> i32 {}
+        // }
+        // T.foo();
+        // ^^^^^^^ a temporary `&T` created inside this method call due to `&self`
+        // ```
+        let typeck_results = self.fcx.typeck_results.borrow();
+        let is_region_borrow =
+            typeck_results.expr_adjustments(expr).iter().any(|adj| adj.is_region_borrow());
+
+        // ```rust
+        // struct Foo(*const u8);
+        // bar(Foo(std::ptr::null())).await;
+        //     ^^^^^^^^^^^^^^^^^^^^^ raw-ptr `*T` created inside this struct ctor.
+        // ```
+        let is_raw_borrow_inside_fn_like_call =
+            match self.fcx.tcx.def_kind(parent.owner.to_def_id()) {
+                DefKind::Fn | DefKind::Ctor(..) => ty.is_unsafe_ptr(),
+                _ => false,
+            };
+
+        if (typeck_results.is_method_call(parent_expr) && is_region_borrow)
+            || is_raw_borrow_inside_fn_like_call
+        {
+            Some(parent_expr.span)
+        } else {
+            None
+        }
+    }
 }
 
 pub fn resolve_interior<'a, 'tcx>(
@@ -2,7 +2,7 @@
 //! intrinsics that the compiler exposes.
 
 use crate::errors::{
-    UnrecognizedAtomicOperation, UnrecognizedIntrinsicFunction,
+    UnrecognizedAtomicOperation, UnrecognizedAtomicOrdering, UnrecognizedIntrinsicFunction,
     WrongNumberOfGenericArgumentsToIntrinsic,
 };
 use crate::require_same_types;
@@ -12,6 +12,7 @@
 use rustc_middle::traits::{ObligationCause, ObligationCauseCode};
 use rustc_middle::ty::subst::Subst;
 use rustc_middle::ty::{self, TyCtxt};
+use rustc_span::lev_distance::find_best_match_for_name;
 use rustc_span::symbol::{kw, sym, Symbol};
 use rustc_target::spec::abi::Abi;
 
@@ -110,6 +111,80 @@ pub fn intrinsic_operation_unsafety(intrinsic: Symbol) -> hir::Unsafety {
     }
 }
 
+/// Checks the memory ordering suffixes of the atomic intrinsic `name`, which are otherwise
+/// only parsed once the intrinsic is codegened. A missing suffix is left to codegen.
+fn check_atomic_orderings(
+    tcx: TyCtxt<'_>,
+    it: &hir::ForeignItem<'_>,
+    name: &str,
+    op: &str,
+    orderings: &str,
+) {
+    if orderings.is_empty() {
+        return;
+    }
+
+    const ALL: &[&str] = &["relaxed", "acquire", "release", "acqrel", "seqcst"];
+    const CXCHG_FAILURE: &[&str] = &["relaxed", "acquire", "seqcst"];
+    let mut parts: Vec<&str> = orderings.splitn(2, '_').collect();
+    let expected: &[(&'static str, &[&str])] = match op {
+        "load" => &[("memory", &["unordered", "relaxed", "acquire", "seqcst"])],
+        "store" => &[("memory", &["unordered", "relaxed", "release", "seqcst"])],
+        "fence" | "singlethreadfence" => &[("memory", &["acquire", "release", "acqrel", "seqcst"])],
+        "cxchg" | "cxchgweak" => &[("success", ALL), ("failure", CXCHG_FAILURE)],
+        _ => &[("memory", ALL)],
+    };
+    if expected.len() == 1 && parts.len() > 1 {
+        // Only the compare-exchange intrinsics take two orderings.
+        parts = vec![orderings];
+    }
+
+    for (i, (&ordering, &(kind, valid))) in parts.iter().zip(expected).enumerate() {
+        if valid.contains(&ordering) {
+            continue;
+        }
+        let suggestion = suggest_atomic_ordering(ordering, valid).map(|suggested| {
+            let mut parts = parts.clone();
+            parts[i] = suggested.as_str();
+            format!("atomic_{}_{}", op, parts.join("_"))
+        });
+        let valid_orderings = match valid {
+            [init @ .., last] => format!(
+                "{} and `{}`",
+                init.iter().map(|o| format!("`{o}`")).collect::<Vec<_>>().join(", "),
+                last
+            ),
+            [] => unreachable!(),
+        };
+        tcx.sess.emit_err(UnrecognizedAtomicOrdering {
+            span: it.span,
+            name,
+            op,
+            ordering,
+            kind,
+            valid_orderings,
+            name_span: suggestion.is_some().then_some(it.ident.span),
+            suggested_name: suggestion.unwrap_or_default(),
+        });
+    }
+}
+
+/// Picks the valid ordering closest to `ordering`. Orderings that don't apply to an operation
+/// are weakened the same way `core::sync::atomic` does for compare-exchange failures, so that
+/// e.g. `acqrel` becomes `acquire` for loads and `release` for stores.
+fn suggest_atomic_ordering(ordering: &str, valid: &[&str]) -> Option<Symbol> {
+    let weakened: &[&str] = match ordering {
+        "acqrel" => &["acquire", "release"],
+        "release" | "acquire" | "unordered" => &["relaxed"],
+        _ => &[],
+    };
+    if let Some(weakened) = weakened.iter().find(|o| valid.contains(o)) {
+        return Some(Symbol::intern(weakened));
+    }
+    let candidates: Vec<Symbol> = valid.iter().map(|o| Symbol::intern(o)).collect();
+    find_best_match_for_name(&candidates, Symbol::intern(ordering), None)
+}
+
 /// Remember to add all intrinsics here, in `compiler/rustc_codegen_llvm/src/intrinsic.rs`,
 /// and in `library/core/src/intrinsics.rs`.
 pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
@@ -137,12 +212,13 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
         })
     };
 
-    let (n_tps, n_lts, inputs, output, unsafety) = if name_str.starts_with("atomic_") {
-        let split: Vec<&str> = name_str.split('_').collect();
-        assert!(split.len() >= 2, "Atomic intrinsic in an incorrect format");
+    let (n_tps, n_lts, inputs, output, unsafety) = if let Some(atomic) =
+        name_str.strip_prefix("atomic_")
+    {
+        // Atomic intrinsics are named `atomic_<operation>_<orderings>`.
+        let (op, orderings) = atomic.split_once('_').unwrap_or((atomic, ""));
 
-        //We only care about the operation here
-        let (n_tps, inputs, output) = match split[1] {
+        let (n_tps, inputs, output) = match op {
             "cxchg" | "cxchgweak" => (
                 1,
                 vec![tcx.mk_mut_ptr(param(0)), param(0), param(0)],
@@ -159,6 +235,7 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 return;
             }
         };
+        check_atomic_orderings(tcx, it, name_str, op, orderings);
         (n_tps, 0, inputs, output, hir::Unsafety::Unsafe)
     } else {
         let unsafety = intrinsic_operation_unsafety(intrinsic_name);
@@ -1,9 +1,10 @@
 use rustc_ast::InlineAsmTemplatePiece;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_errors::struct_span_err;
+use rustc_errors::{struct_span_err, Applicability, Diagnostic};
 use rustc_hir as hir;
 use rustc_index::vec::Idx;
 use rustc_middle::ty::layout::{LayoutError, SizeSkeleton};
+use rustc_middle::ty::simd::check_simd_intrinsic_types;
 use rustc_middle::ty::{self, Article, FloatTy, IntTy, Ty, TyCtxt, TypeVisitable, UintTy};
 use rustc_session::lint;
 use rustc_span::{Span, Symbol, DUMMY_SP};
@@ -94,9 +95,109 @@ pub fn check_transmute(&self, span: Span, from: Ty<'tcx>, to: Ty<'tcx>) {
         } else {
             err.note(&format!("source type: `{}` ({})", from, skeleton_string(from, sk_from)))
                 .note(&format!("target type: `{}` ({})", to, skeleton_string(to, sk_to)));
+            if let (Ok(sk_from), Ok(sk_to)) = (sk_from, sk_to) {
+                self.suggest_transmute_alternatives(&mut err, from, to, sk_from, sk_to);
+            }
         }
         err.emit();
     }
+
+    /// Suggests ways around a `transmute` between types whose sizes are known to differ.
+    fn suggest_transmute_alternatives(
+        &self,
+        err: &mut Diagnostic,
+        from: Ty<'tcx>,
+        to: Ty<'tcx>,
+        sk_from: SizeSkeleton<'tcx>,
+        sk_to: SizeSkeleton<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        let is_byte_array =
+            |ty: Ty<'tcx>| matches!(ty.kind(), ty::Array(elem, _) if *elem == tcx.types.u8);
+        let is_number =
+            |ty: Ty<'tcx>| matches!(ty.kind(), ty::Int(_) | ty::Uint(_) | ty::Float(_));
+        match (sk_from, sk_to) {
+            (SizeSkeleton::Known(size_from), SizeSkeleton::Known(size_to)) => {
+                if is_number(from) && is_byte_array(to) {
+                    err.help(&format!(
+                        "to get the bytes of `{from}`, use `{from}::to_ne_bytes`, `to_le_bytes` or \
+                         `to_be_bytes`, which return `[u8; {}]`",
+                        size_from.bytes(),
+                    ));
+                } else if is_byte_array(from) && is_number(to) {
+                    err.help(&format!(
+                        "to build a `{to}` from its bytes, use `{to}::from_ne_bytes`, \
+                         `from_le_bytes` or `from_be_bytes`, which take `[u8; {}]`",
+                        size_to.bytes(),
+                    ));
+                } else if size_from > size_to
+                    && size_to.bytes() > 0
+                    && !from.is_any_ptr()
+                    && !to.is_any_ptr()
+                {
+                    // Reading the leading bytes of a pointer, or none at all, is never useful.
+                    err.help(
+                        "if you want to reinterpret the leading bytes of the source value, \
+                         consider using `std::mem::transmute_copy`",
+                    );
+                }
+            }
+            _ => {
+                // A pointer to a type parameter that may be unsized has an unknown size; a
+                // `Sized` bound makes it a thin pointer.
+                let ptr_size = Pointer.size(&tcx);
+                let unsized_param = |sk: SizeSkeleton<'tcx>| match sk {
+                    SizeSkeleton::Pointer { tail, .. } if matches!(tail.kind(), ty::Param(_)) => {
+                        Some(tail)
+                    }
+                    _ => None,
+                };
+                let thin_or_unsized = |sk: SizeSkeleton<'tcx>| match sk {
+                    SizeSkeleton::Known(size) => size == ptr_size,
+                    SizeSkeleton::Pointer { tail, .. } => matches!(tail.kind(), ty::Param(_)),
+                };
+                if !thin_or_unsized(sk_from) || !thin_or_unsized(sk_to) {
+                    return;
+                }
+                let params: Vec<_> =
+                    [sk_from, sk_to].into_iter().filter_map(unsized_param).collect();
+                let Some(generics) = tcx.hir().get_generics(self.body_id.owner) else { return };
+                err.span_suggestion_verbose(
+                    generics.tail_span_for_predicate_suggestion(),
+                    "consider adding a `Sized` bound so that the size of the pointer is known",
+                    format!(
+                        "{} {}",
+                        generics.add_where_or_trailing_comma(),
+                        params
+                            .iter()
+                            .map(|param| format!("{param}: Sized"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+    }
+
+    /// Checks a call to one of the `simd_*` platform intrinsics once the types of its
+    /// arguments and return value are known, reporting misuse with concrete vector types
+    /// before monomorphization. Calls whose types still depend on generic parameters are
+    /// left to codegen, which runs the same checks.
+    pub fn check_simd_intrinsic(&self, span: Span, name: Symbol, sig: ty::PolyFnSig<'tcx>) {
+        let tcx = self.tcx;
+        let sig = self.resolve_vars_if_possible(sig);
+        if sig.has_infer_types_or_consts() || sig.needs_subst() || sig.references_error() {
+            return;
+        }
+        let sig = tcx.normalize_erasing_late_bound_regions(self.param_env, sig);
+        if let Err(msg) =
+            check_simd_intrinsic_types(tcx, self.param_env, name, sig.inputs(), sig.output())
+        {
+            struct_span_err!(tcx.sess, span, E0511, "invalid use of `{}` intrinsic: {}", name, msg)
+                .emit();
+        }
+    }
 }
 
 pub struct InlineAsmCtxt<'a, 'tcx> {
@@ -502,10 +603,41 @@ pub fn check_asm(&self, asm: &hir::InlineAsm<'tcx>, enclosing_id: hir::HirId) {
                         );
                     }
                 }
-                // No special checking is needed for these:
-                // - Typeck has checked that Const operands are integers.
-                // - AST lowering guarantees that SymStatic points to a static.
-                hir::InlineAsmOperand::Const { .. } | hir::InlineAsmOperand::SymStatic { .. } => {}
+                // Check that const operands are integers. Later passes (codegen in
+                // particular) depend on this.
+                hir::InlineAsmOperand::Const { anon_const } => {
+                    let ty = self.tcx.typeck_body(anon_const.body).node_type(anon_const.hir_id);
+                    match ty.kind() {
+                        ty::Error(_) => {}
+                        ty::Int(_) | ty::Uint(_) => {}
+                        _ => {
+                            let mut err = self
+                                .tcx
+                                .sess
+                                .struct_span_err(*op_sp, "invalid type for `const` operand");
+                            err.span_label(
+                                self.tcx.hir().span(anon_const.body.hir_id),
+                                &format!("is {} `{}`", ty.kind().article(), ty),
+                            );
+                            err.help("`const` operands must be of an integer type");
+                            err.emit();
+                        }
+                    };
+                }
+                // AST lowering guarantees that SymStatic points to a static, but
+                // thread-local statics have no fixed address a symbol could refer to.
+                hir::InlineAsmOperand::SymStatic { ref path, def_id } => {
+                    if self.tcx.is_thread_local_static(def_id) {
+                        let mut err =
+                            self.tcx.sess.struct_span_err(*op_sp, "invalid `sym` operand");
+                        err.span_label(path.span(), "is a thread-local static");
+                        err.help(
+                            "`sym` operands must refer to either a function or a static \
+                             which is not thread-local",
+                        );
+                        err.emit();
+                    }
+                }
                 // Check that sym actually points to a function. Later passes
                 // depend on this.
                 hir::InlineAsmOperand::SymFn { anon_const } => {
@@ -388,15 +388,20 @@ fn probe_op<OP, R>(
         // the truncated autoderef list.
         if steps.reached_recursion_limit {
             self.probe(|_| {
-                let ty = &steps
+                let deref_chain: Vec<_> = steps
                     .steps
+                    .iter()
+                    .map(|step| {
+                        let ty = &step.self_ty;
+                        self.probe_instantiate_query_response(span, &orig_values, ty)
+                            .unwrap_or_else(|_| span_bug!(span, "instantiating {:?} failed?", ty))
+                            .value
+                    })
+                    .collect();
+                let ty = *deref_chain
                     .last()
-                    .unwrap_or_else(|| span_bug!(span, "reached the recursion limit in 0 steps?"))
-                    .self_ty;
-                let ty = self
-                    .probe_instantiate_query_response(span, &orig_values, ty)
-                    .unwrap_or_else(|_| span_bug!(span, "instantiating {:?} failed?", ty));
-                autoderef::report_autoderef_recursion_limit_error(self.tcx, span, ty.value);
+                    .unwrap_or_else(|| span_bug!(span, "reached the recursion limit in 0 steps?"));
+                autoderef::report_autoderef_recursion_limit_error(self.tcx, span, ty, &deref_chain);
             });
         }
 
@@ -9,7 +9,7 @@
 };
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{CrateNum, DefId, LOCAL_CRATE};
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{ExprKind, Node, QPath};
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
@@ -431,12 +431,24 @@ pub fn report_method_error(
                             self.ty_to_value_string(actual.peel_refs())
                         };
                     if let SelfSource::MethodCall(expr) = source {
-                        err.span_suggestion(
-                            expr.span.to(span),
-                            "use associated function syntax instead",
-                            format!("{}::{}", ty_str, item_name),
-                            Applicability::MachineApplicable,
-                        );
+                        let rewrote_call = if let Some(CandidateSource::Impl(impl_did)) =
+                            static_sources.get(0)
+                            && let Some(args) = args
+                        {
+                            self.suggest_ref_counted_assoc_fn_call(
+                                &mut err, *impl_did, item_name, actual, args, sugg_span,
+                            )
+                        } else {
+                            false
+                        };
+                        if !rewrote_call {
+                            err.span_suggestion(
+                                expr.span.to(span),
+                                "use associated function syntax instead",
+                                format!("{}::{}", ty_str, item_name),
+                                Applicability::MachineApplicable,
+                            );
+                        }
                     } else {
                         err.help(&format!("try with `{}::{}`", ty_str, item_name,));
                     }
@@ -1659,15 +1671,169 @@ fn suggest_await_before_method(
         let method_exists = self.method_exists(item_name, output_ty, call.hir_id, true);
         debug!("suggest_await_before_method: is_method_exist={}", method_exists);
         if method_exists {
+            err.span_note(
+                call.span,
+                &format!("this expression is a `Future` whose `Output` is `{output_ty}`"),
+            );
+            // `.await` can only be inserted as-is inside of an `async` body.
+            let applicability = if self.is_in_async_body(call.hir_id) {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
             err.span_suggestion_verbose(
                 span.shrink_to_lo(),
                 "consider `await`ing on the `Future` and calling the method on its `Output`",
                 "await.",
-                Applicability::MaybeIncorrect,
+                applicability,
             );
         }
     }
 
+    /// Returns whether `hir_id` is inside of an `async fn` or `async` block, without an
+    /// intervening closure or item.
+    fn is_in_async_body(&self, hir_id: hir::HirId) -> bool {
+        let hir = self.tcx.hir();
+        hir.parent_iter(hir_id)
+            .find_map(|(_, node)| match node {
+                hir::Node::Expr(hir::Expr { kind: hir::ExprKind::Closure(closure), .. }) => {
+                    Some(matches!(
+                        hir.body(closure.body).generator_kind,
+                        Some(hir::GeneratorKind::Async(_))
+                    ))
+                }
+                hir::Node::Item(_) | hir::Node::ImplItem(_) | hir::Node::TraitItem(_) => {
+                    Some(false)
+                }
+                _ => None,
+            })
+            .unwrap_or(false)
+    }
+
+    /// Returns the pointee type if `ty` is an `Rc` or an `Arc`. Most of their functionality is
+    /// provided through associated functions, so that it doesn't shadow methods of the pointee.
+    pub(crate) fn ref_counted_pointee(&self, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+        match ty.kind() {
+            ty::Adt(def, substs)
+                if self.tcx.is_diagnostic_item(sym::Rc, def.did())
+                    || self.tcx.is_diagnostic_item(sym::Arc, def.did()) =>
+            {
+                Some(substs.type_at(0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Given `x.strong_count()` with `x: Rc<T>`, suggests `Rc::strong_count(&x)`. Returns whether
+    /// the associated function takes the pointer as its first argument and a suggestion was made.
+    fn suggest_ref_counted_assoc_fn_call(
+        &self,
+        err: &mut Diagnostic,
+        impl_did: DefId,
+        item_name: Ident,
+        rcvr_ty: Ty<'tcx>,
+        args: &'tcx [hir::Expr<'tcx>],
+        call_span: Span,
+    ) -> bool {
+        let ptr_ty = rcvr_ty.peel_refs();
+        let (Some(pointee), ty::Adt(def, _)) = (self.ref_counted_pointee(ptr_ty), ptr_ty.kind())
+        else {
+            return false;
+        };
+        let Some(item) = self.associated_value(impl_did, item_name) else { return false };
+        let ty::AssocKind::Fn = item.kind else { return false };
+        let sig = self.tcx.fn_sig(item.def_id).skip_binder();
+        let Some(&first_input) = sig.inputs().first() else { return false };
+        if !matches!(first_input.peel_refs().kind(), ty::Adt(first_def, _) if first_def == def) {
+            return false;
+        }
+
+        let autoref = autoref_for_explicit_receiver(first_input, rcvr_ty);
+        let source_map = self.tcx.sess.source_map();
+        let mut applicability = Applicability::MachineApplicable;
+        let args = args
+            .iter()
+            .map(|arg| {
+                source_map.span_to_snippet(arg.span).unwrap_or_else(|_| {
+                    applicability = Applicability::HasPlaceholders;
+                    "_".to_owned()
+                })
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let path = format!("{}::{}", self.tcx.def_path_str(def.did()), item_name);
+        err.span_suggestion_verbose(
+            call_span,
+            &format!("use associated function syntax to call `{path}` with the pointer"),
+            format!("{path}({autoref}{args})"),
+            applicability,
+        );
+        err.note(&format!(
+            "`{path}` is an associated function rather than a method, so that it doesn't \
+             shadow methods of `{pointee}`"
+        ));
+        true
+    }
+
+    /// Given `x.clone(1)` with `x: Rc<T>` where `T` has a method `clone` taking an argument,
+    /// explains that `Rc::clone(&x)` is called instead and suggests dereferencing the receiver.
+    pub(crate) fn note_ref_counted_method_shadowing(
+        &self,
+        err: &mut Diagnostic,
+        call_expr: &'tcx hir::Expr<'tcx>,
+        method_def_id: DefId,
+    ) {
+        let hir::ExprKind::MethodCall(segment, args, _) = call_expr.kind else { return };
+        let rcvr = &args[0];
+        let Some(rcvr_ty) = self.typeck_results.borrow().expr_ty_opt(rcvr) else { return };
+        let rcvr_ty = self.resolve_vars_if_possible(rcvr_ty);
+        let ptr_ty = rcvr_ty.peel_refs();
+        let (Some(pointee), ty::Adt(def, _)) = (self.ref_counted_pointee(ptr_ty), ptr_ty.kind())
+        else {
+            return;
+        };
+        let Ok(pick) = self.lookup_probe(
+            segment.ident.span,
+            segment.ident,
+            pointee,
+            call_expr,
+            ProbeScope::TraitsInScope,
+        ) else {
+            return;
+        };
+        if pick.item.def_id == method_def_id {
+            return;
+        }
+
+        let source_map = self.tcx.sess.source_map();
+        let Ok(rcvr_snippet) = source_map.span_to_snippet(rcvr.span) else { return };
+        let sig = self.tcx.fn_sig(method_def_id).skip_binder();
+        let autoref = sig
+            .inputs()
+            .first()
+            .map_or("", |&first_input| autoref_for_explicit_receiver(first_input, rcvr_ty));
+        err.note(&format!(
+            "this calls `{}::{}({autoref}{rcvr_snippet})`, because methods of `{ptr_ty}` are \
+             found before methods of `{pointee}`",
+            self.tcx.def_path_str(def.did()),
+            segment.ident,
+        ));
+        let mut derefs = String::from("*");
+        let mut ty = rcvr_ty;
+        while let ty::Ref(_, inner_ty, _) = ty.kind() {
+            derefs.push('*');
+            ty = *inner_ty;
+        }
+        err.multipart_suggestion_verbose(
+            &format!("dereference the receiver to call the method of `{pointee}` instead"),
+            vec![
+                (rcvr.span.shrink_to_lo(), format!("({derefs}")),
+                (rcvr.span.shrink_to_hi(), ")".to_string()),
+            ],
+            Applicability::MaybeIncorrect,
+        );
+    }
+
     fn suggest_use_candidates(&self, err: &mut Diagnostic, msg: String, candidates: Vec<DefId>) {
         let parent_map = self.tcx.visible_parent_map(());
 
@@ -1696,22 +1862,29 @@ fn suggest_use_candidates(&self, err: &mut Diagnostic, msg: String, candidates:
         let span = module.spans.inject_use_span;
 
         let path_strings = candidates.iter().map(|trait_did| {
-            format!("use {};\n", with_crate_prefix!(self.tcx.def_path_str(*trait_did)),)
+            let path = format!("use {};\n", with_crate_prefix!(self.tcx.def_path_str(*trait_did)));
+            (crate_depth(self.tcx, visible_crate(self.tcx, *trait_did)), path)
         });
 
         let glob_path_strings = globs.iter().map(|trait_did| {
             let parent_did = parent_map.get(trait_did).unwrap();
-            format!(
+            let path = format!(
                 "use {}::*; // trait {}\n",
                 with_crate_prefix!(self.tcx.def_path_str(*parent_did)),
                 self.tcx.item_name(*trait_did),
-            )
+            );
+            (crate_depth(self.tcx, visible_crate(self.tcx, *trait_did)), path)
         });
 
-        err.span_suggestions(
-            span,
+        // Suggest traits of the local crate and of direct dependencies before those of
+        // dependencies further away, which are less likely to be the ones the user wants. The
+        // crate that counts is the one the suggested path goes through, e.g. `std` rather than
+        // `core` for `std::fmt::Write`.
+        let mut suggestions: Vec<_> = path_strings.chain(glob_path_strings).collect();
+        suggestions.sort();
+        err.multipart_suggestions(
             &msg,
-            path_strings.chain(glob_path_strings),
+            suggestions.into_iter().map(|(_, path)| vec![(span, path)]),
             Applicability::MaybeIncorrect,
         );
     }
@@ -2229,10 +2402,55 @@ fn cmp(&self, other: &TraitInfo) -> Ordering {
     }
 }
 
-/// Retrieves all traits in this crate and any dependent crates,
-/// and wraps them into `TraitInfo` for custom sorting.
+/// Retrieves all traits in this crate and in every crate of its dependency graph, including
+/// the dependencies of dependencies, and wraps them into `TraitInfo` for custom sorting.
+///
+/// Crates are searched closest first, so that the traits of the local crate and of its direct
+/// dependencies are found before those of crates further away.
 pub fn all_traits(tcx: TyCtxt<'_>) -> Vec<TraitInfo> {
-    tcx.all_traits().map(|def_id| TraitInfo { def_id }).collect()
+    let mut crates: Vec<_> =
+        iter::once(LOCAL_CRATE).chain(tcx.crates(()).iter().copied()).collect();
+    crates.sort_by_key(|&cnum| crate_depth(tcx, cnum));
+    crates
+        .into_iter()
+        .flat_map(|cnum| tcx.traits_in_crate(cnum))
+        .map(|&def_id| TraitInfo { def_id })
+        .collect()
+}
+
+/// Returns how many `extern crate` edges separate `cnum` from the local crate in the crate
+/// graph, counting implicitly loaded transitive dependencies.
+fn crate_depth(tcx: TyCtxt<'_>, mut cnum: CrateNum) -> usize {
+    let mut depth = 0;
+    while cnum != LOCAL_CRATE {
+        let Some(extern_crate) = tcx.extern_crate(cnum.as_def_id()) else {
+            // Crates that weren't loaded through an `extern crate` chain go last.
+            return usize::MAX;
+        };
+        depth += 1;
+        cnum = extern_crate.dependency_of;
+    }
+    depth
+}
+
+/// Returns the crate that the visible path of `def_id`, which is the one we print, starts with.
+fn visible_crate(tcx: TyCtxt<'_>, mut def_id: DefId) -> CrateNum {
+    let parent_map = tcx.visible_parent_map(());
+    while let Some(&parent) = parent_map.get(&def_id) {
+        def_id = parent;
+    }
+    def_id.krate
+}
+
+/// Returns the borrow needed to pass a receiver of type `rcvr_ty` as the explicit first argument
+/// of type `first_input`, e.g. `&` when calling `Rc::strong_count` on an `Rc<T>`.
+fn autoref_for_explicit_receiver<'tcx>(first_input: Ty<'tcx>, rcvr_ty: Ty<'tcx>) -> &'static str {
+    match first_input.kind() {
+        _ if rcvr_ty.is_ref() => "",
+        ty::Ref(_, _, hir::Mutability::Mut) => "&mut ",
+        ty::Ref(_, _, hir::Mutability::Not) => "&",
+        _ => "",
+    }
 }
 
 fn print_disambiguation_help<'tcx>(
@@ -93,6 +93,7 @@
 pub mod writeback;
 
 use check::{check_abi, check_fn, check_mod_item_types};
+use compare_method::collect_trait_impl_trait_tys;
 pub use diverges::Diverges;
 pub use expectation::Expectation;
 pub use fn_ctxt::*;
@@ -101,6 +102,7 @@
 
 use crate::astconv::AstConv;
 use crate::check::gather_locals::GatherLocalsVisitor;
+use crate::errors::TransparentNonZeroSizedFields;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::{
     pluralize, struct_span_err, Applicability, Diagnostic, DiagnosticBuilder, MultiSpan,
@@ -250,6 +252,7 @@ pub fn provide(providers: &mut Providers) {
         used_trait_imports,
         check_mod_item_types,
         region_scope_tree,
+        collect_trait_impl_trait_tys,
         ..*providers
     };
 }
@@ -414,14 +417,11 @@ fn typeck_with_fallback<'tcx>(
                                 .iter()
                                 .filter_map(|(op, _op_sp)| match op {
                                     hir::InlineAsmOperand::Const { anon_const }
+                                    | hir::InlineAsmOperand::SymFn { anon_const }
                                         if anon_const.hir_id == id =>
                                     {
-                                        // Inline assembly constants must be integers.
-                                        Some(fcx.next_int_var())
-                                    }
-                                    hir::InlineAsmOperand::SymFn { anon_const }
-                                        if anon_const.hir_id == id =>
-                                    {
+                                        // The type of `const` and `sym` operands is checked
+                                        // once the body has been type-checked, in intrinsicck.
                                         Some(fcx.next_ty_var(TypeVariableOrigin {
                                             kind: TypeVariableOriginKind::MiscVariable,
                                             span,
@@ -470,6 +470,7 @@ fn typeck_with_fallback<'tcx>(
 
         if !fcx.infcx.is_tainted_by_errors() {
             fcx.check_transmutes();
+            fcx.check_simd_intrinsics();
         }
 
         fcx.check_asms();
@@ -635,6 +636,7 @@ fn missing_items_err(
 fn missing_items_must_implement_one_of_err(
     tcx: TyCtxt<'_>,
     impl_span: Span,
+    trait_def_id: DefId,
     missing_items: &[Ident],
     annotation_span: Option<Span>,
 ) {
@@ -649,6 +651,33 @@ fn missing_items_must_implement_one_of_err(
     );
     err.span_label(impl_span, format!("missing one of `{missing_items_msg}` in implementation"));
 
+    // Point at each function of the group, since having a default body doesn't make any of
+    // them count as implemented.
+    let group: Vec<_> = missing_items
+        .iter()
+        .filter_map(|&ident| {
+            let item = tcx.associated_items(trait_def_id).find_by_name_and_kind(
+                tcx,
+                ident,
+                ty::AssocKind::Fn,
+                trait_def_id,
+            )?;
+            let label = if item.defaultness(tcx).has_value() {
+                format!("`{ident}` has a default body")
+            } else {
+                format!("`{ident}` has no default body")
+            };
+            Some((tcx.def_span(item.def_id), label))
+        })
+        .collect();
+    if !group.is_empty() {
+        let mut group_span = MultiSpan::from_spans(group.iter().map(|(span, _)| *span).collect());
+        for (span, label) in group {
+            group_span.push_span_label(span, label);
+        }
+        err.span_note(group_span, "at least one of these functions must be overridden");
+    }
+
     if let Some(annotation_span) = annotation_span {
         err.span_note(annotation_span, "required because of this annotation");
     }
@@ -862,29 +891,23 @@ fn bad_variant_count<'tcx>(tcx: TyCtxt<'tcx>, adt: ty::AdtDef<'tcx>, sp: Span, d
 }
 
 /// Emit an error when encountering two or more non-zero-sized fields in a transparent
-/// enum.
+/// struct, union or enum variant.
 fn bad_non_zero_sized_fields<'tcx>(
     tcx: TyCtxt<'tcx>,
     adt: ty::AdtDef<'tcx>,
     field_count: usize,
-    field_spans: impl Iterator<Item = Span>,
+    mut field_spans: impl Iterator<Item = Span>,
     sp: Span,
 ) {
-    let msg = format!("needs at most one non-zero-sized field, but has {field_count}");
-    let mut err = struct_span_err!(
-        tcx.sess,
-        sp,
-        E0690,
-        "{}transparent {} {}",
-        if adt.is_enum() { "the variant of a " } else { "" },
-        adt.descr(),
-        msg,
-    );
-    err.span_label(sp, &msg);
-    for sp in field_spans {
-        err.span_label(sp, "this field is non-zero-sized");
-    }
-    err.emit();
+    let first_field = field_spans.next().expect("expected at least two non-zero-sized fields");
+    tcx.sess.emit_err(TransparentNonZeroSizedFields {
+        span: sp,
+        first_field,
+        extra_fields: field_spans.collect(),
+        field_count,
+        descr: adt.descr(),
+        is_variant: adt.is_enum(),
+    });
 }
 
 fn report_unexpected_variant_res(tcx: TyCtxt<'_>, res: Res, qpath: &hir::QPath<'_>, span: Span) {
@@ -4,9 +4,11 @@
 use super::{has_expected_num_generic_args, FnCtxt};
 use crate::check::Expectation;
 use rustc_ast as ast;
+use rustc_ast::util::parser::PREC_PREFIX;
 use rustc_errors::{self, struct_span_err, Applicability, Diagnostic};
 use rustc_hir as hir;
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
+use rustc_infer::infer;
 use rustc_infer::traits::ObligationCauseCode;
 use rustc_middle::ty::adjustment::{
     Adjust, Adjustment, AllowTwoPhase, AutoBorrow, AutoBorrowMutability,
@@ -479,6 +481,12 @@ fn check_overloaded_binop(
                         suggest_deref_binop(*lhs_deref_ty);
                     }
                 }
+                // We should suggest `a + b` => `&a + &b` if `&A: Add<&B>` is implemented.
+                if is_assign == IsAssign::No && op.node.is_by_value() {
+                    self.suggest_borrowing_binop_operands(
+                        &mut err, lhs_expr, rhs_expr, lhs_ty, rhs_ty, op, expected,
+                    );
+                }
                 if let Some(missing_trait) = missing_trait {
                     let mut visitor = TypeParamVisitor(vec![]);
                     visitor.visit_ty(lhs_ty);
@@ -548,6 +556,81 @@ fn check_overloaded_binop(
         (lhs_ty, rhs_ty, return_ty)
     }
 
+    /// If the operator isn't implemented for `lhs_ty` and `rhs_ty`, but is implemented for
+    /// references to one or both of them, suggest borrowing the corresponding operands.
+    fn suggest_borrowing_binop_operands(
+        &self,
+        err: &mut Diagnostic,
+        lhs_expr: &'tcx hir::Expr<'tcx>,
+        rhs_expr: &'tcx hir::Expr<'tcx>,
+        lhs_ty: Ty<'tcx>,
+        rhs_ty: Ty<'tcx>,
+        op: hir::BinOp,
+        expected: Expectation<'tcx>,
+    ) {
+        if lhs_ty.needs_infer() || rhs_ty.needs_infer() {
+            return;
+        }
+        let mk_ref = |ty: Ty<'tcx>, span: Span| {
+            let region = self.next_region_var(infer::MiscVariable(span));
+            self.tcx.mk_imm_ref(region, ty)
+        };
+        // Prefer borrowing as few operands as possible.
+        let candidates = [(false, true), (true, false), (true, true)];
+        for (borrow_lhs, borrow_rhs) in candidates {
+            if (borrow_lhs && lhs_ty.is_ref()) || (borrow_rhs && rhs_ty.is_ref()) {
+                continue;
+            }
+            let lhs_ty = if borrow_lhs { mk_ref(lhs_ty, lhs_expr.span) } else { lhs_ty };
+            let rhs_ty = if borrow_rhs { mk_ref(rhs_ty, rhs_expr.span) } else { rhs_ty };
+            if self
+                .lookup_op_method(
+                    lhs_ty,
+                    Some(rhs_ty),
+                    Some(rhs_expr),
+                    Op::Binary(op, IsAssign::No),
+                    expected,
+                )
+                .is_err()
+            {
+                continue;
+            }
+            let mut sugg = vec![];
+            let mut borrow = |expr: &hir::Expr<'_>| {
+                // Operands like `a * b` or `b as T` need parentheses to be borrowed as a whole.
+                if expr.precedence().order() < PREC_PREFIX {
+                    sugg.push((expr.span.shrink_to_lo(), "&(".to_string()));
+                    sugg.push((expr.span.shrink_to_hi(), ")".to_string()));
+                } else {
+                    sugg.push((expr.span.shrink_to_lo(), "&".to_string()));
+                }
+            };
+            if borrow_lhs {
+                borrow(lhs_expr);
+            }
+            if borrow_rhs {
+                borrow(rhs_expr);
+            }
+            let operands = match (borrow_lhs, borrow_rhs) {
+                (true, true) => "both operands",
+                (true, false) => "the left-hand side",
+                _ => "the right-hand side",
+            };
+            err.multipart_suggestion_verbose(
+                &format!(
+                    "`{}` is implemented for `{}` and `{}`, consider borrowing {}",
+                    op.node.as_str(),
+                    lhs_ty,
+                    rhs_ty,
+                    operands,
+                ),
+                sugg,
+                Applicability::MachineApplicable,
+            );
+            return;
+        }
+    }
+
     /// If one of the types is an uncalled function and calling it would yield the other type,
     /// suggest calling the function. Returns `true` if suggestion would apply (even if not given).
     fn add_type_neq_err_label(
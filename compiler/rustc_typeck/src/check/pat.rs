@@ -1,4 +1,5 @@
 use crate::check::FnCtxt;
+use crate::errors::DerefPatternUnsupportedScrutinee;
 use rustc_ast as ast;
 
 use rustc_data_structures::fx::FxHashMap;
@@ -342,24 +343,42 @@ fn peel_off_references(
         //
         // See the examples in `ui/match-defbm*.rs`.
         let mut pat_adjustments = vec![];
-        while let ty::Ref(_, inner_ty, inner_mutability) = *expected.kind() {
+        loop {
             debug!("inspecting {:?}", expected);
-
-            debug!("current discriminant is Ref, inserting implicit deref");
-            // Preserve the reference type. We'll need it later during THIR lowering.
-            pat_adjustments.push(expected);
-
-            expected = inner_ty;
-            def_bm = ty::BindByReference(match def_bm {
-                // If default binding mode is by value, make it `ref` or `ref mut`
-                // (depending on whether we observe `&` or `&mut`).
-                ty::BindByValue(_) |
-                // When `ref mut`, stay a `ref mut` (on `&mut`) or downgrade to `ref` (on `&`).
-                ty::BindByReference(hir::Mutability::Mut) => inner_mutability,
-                // Once a `ref`, always a `ref`.
-                // This is because a `& &mut` cannot mutate the underlying value.
-                ty::BindByReference(m @ hir::Mutability::Not) => m,
-            });
+            match *expected.kind() {
+                ty::Ref(_, inner_ty, inner_mutability) => {
+                    debug!("current discriminant is Ref, inserting implicit deref");
+                    // Preserve the reference type. We'll need it later during THIR lowering.
+                    pat_adjustments.push(expected);
+
+                    expected = inner_ty;
+                    def_bm = ty::BindByReference(match def_bm {
+                        // If default binding mode is by value, make it `ref` or `ref mut`
+                        // (depending on whether we observe `&` or `&mut`).
+                        ty::BindByValue(_) |
+                        // When `ref mut`, stay a `ref mut` (on `&mut`) or downgrade to `ref`
+                        // (on `&`).
+                        ty::BindByReference(hir::Mutability::Mut) => inner_mutability,
+                        // Once a `ref`, always a `ref`.
+                        // This is because a `& &mut` cannot mutate the underlying value.
+                        ty::BindByReference(m @ hir::Mutability::Not) => m,
+                    });
+                }
+                // With `#![feature(deref_patterns)]`, a pattern for the contents of a `Box`
+                // can be used to match on the `Box` itself. As dereferencing a `Box` is
+                // built-in, this is lowered just like the implicit derefs of references,
+                // but the default binding mode stays the same.
+                ty::Adt(def, substs)
+                    if def.is_box()
+                        && self.tcx.features().deref_patterns
+                        && !matches!(pat.kind, PatKind::Box(_)) =>
+                {
+                    debug!("current discriminant is Box, inserting implicit deref");
+                    pat_adjustments.push(expected);
+                    expected = substs.type_at(0);
+                }
+                _ => break,
+            }
         }
 
         if !pat_adjustments.is_empty() {
@@ -403,6 +422,19 @@ fn check_pat_lit(
             }
         }
 
+        // With `#![feature(deref_patterns)]`, string literals can also be used to match
+        // on `String`s, which are compared against the literal through `Deref`.
+        if self.tcx.features().deref_patterns
+            && let hir::ExprKind::Lit(Spanned { node: ast::LitKind::Str(..), .. }) = lt.kind
+            && let ty::Adt(def, _) = *self.resolve_vars_with_obligations(expected).kind()
+        {
+            if self.tcx.is_diagnostic_item(sym::String, def.did()) {
+                return expected;
+            }
+            self.report_unsupported_string_deref_pattern(span, expected);
+            return self.tcx.ty_error();
+        }
+
         // Somewhat surprising: in this case, the subtyping relation goes the
         // opposite way as the other cases. Actually what we really want is not
         // a subtyping relation at all but rather that there exists a LUB
@@ -430,6 +462,20 @@ fn check_pat_lit(
         pat_ty
     }
 
+    /// Explains why a string literal can't be used to match on `ty` through `Deref`.
+    fn report_unsupported_string_deref_pattern(&self, span: Span, ty: Ty<'tcx>) {
+        let mut autoderef = self.autoderef(span, ty).silence_errors();
+        let target = autoderef.nth(1).map(|(target, _)| target);
+        self.tcx.sess.emit_err(DerefPatternUnsupportedScrutinee {
+            span,
+            ty,
+            target: target.map_or_else(String::new, |target| target.to_string()),
+            unsupported_target: target.filter(|target| target.is_str()).map(|_| ()),
+            wrong_target: target.filter(|target| !target.is_str()).map(|_| ()),
+            no_deref: target.is_none().then_some(()),
+        });
+    }
+
     fn check_pat_range(
         &self,
         span: Span,
@@ -1,15 +1,21 @@
 use crate::constrained_generic_params::{identify_constrained_generic_params, Parameter};
+use crate::errors::{AutoTraitImplDefault, MissingGatBounds, UnusedWhereClauseLifetime};
 use crate::outlives::outlives_bounds::InferCtxtExt as _;
 use rustc_ast as ast;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
-use rustc_errors::{pluralize, struct_span_err, Applicability, DiagnosticBuilder, ErrorGuaranteed};
+use rustc_errors::{
+    struct_span_err, Applicability, Diagnostic, DiagnosticBuilder, ErrorGuaranteed,
+};
 use rustc_hir as hir;
 use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{self, Visitor};
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::ItemKind;
 use rustc_infer::infer::outlives::env::{OutlivesEnvironment, RegionBoundPairs};
 use rustc_infer::infer::outlives::obligations::TypeOutlives;
 use rustc_infer::infer::{self, InferCtxt, TyCtxtInferExt};
+use rustc_infer::traits::error_reporting::implicit_sized::implicitly_sized_impl_param;
+use rustc_middle::hir::nested_filter;
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::subst::{GenericArgKind, InternalSubsts, Subst};
 use rustc_middle::ty::trait_def::TraitSpecializationKind;
@@ -17,10 +23,12 @@
     self, AdtKind, DefIdTree, GenericParamDefKind, ToPredicate, Ty, TyCtxt, TypeFoldable,
     TypeSuperVisitable, TypeVisitable, TypeVisitor,
 };
+use rustc_session::lint::{builtin::UNUSED_WHERE_CLAUSE_LIFETIMES, Level};
 use rustc_session::parse::feature_err;
 use rustc_span::symbol::{sym, Ident, Symbol};
-use rustc_span::{Span, DUMMY_SP};
+use rustc_span::{BytePos, Span, DUMMY_SP};
 use rustc_trait_selection::autoderef::Autoderef;
+use rustc_trait_selection::infer::InferCtxtExt as _;
 use rustc_trait_selection::traits::error_reporting::InferCtxtExt;
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt as _;
 use rustc_trait_selection::traits::{
@@ -129,6 +137,188 @@ fn check_well_formed(tcx: TyCtxt<'_>, def_id: LocalDefId) {
             check_param_wf(tcx, param)
         }
     }
+
+    lint_where_clause_only_lifetimes(tcx, node);
+}
+
+/// Lints lifetime parameters of functions and inherent impls that are only mentioned by
+/// outlives bounds in the `where` clause, like `'a` in `fn foo<'a>() where 'a: 'static`.
+///
+/// As long as all of these bounds put the lifetime on the same side, a lifetime satisfying
+/// them can always be picked, so neither the parameter nor the bounds constrain anything.
+fn lint_where_clause_only_lifetimes<'tcx>(tcx: TyCtxt<'tcx>, node: hir::OwnerNode<'tcx>) {
+    let generics = match node {
+        hir::OwnerNode::Item(hir::Item {
+            kind:
+                hir::ItemKind::Fn(_, generics, _)
+                | hir::ItemKind::Impl(hir::Impl { generics, of_trait: None, .. }),
+            ..
+        }) => generics,
+        // The generics of methods of trait impls have to match the ones of the trait.
+        hir::OwnerNode::ImplItem(item @ hir::ImplItem { kind: hir::ImplItemKind::Fn(..), .. })
+            if tcx.impl_trait_ref(tcx.local_parent(item.def_id)).is_none() =>
+        {
+            item.generics
+        }
+        _ => return,
+    };
+    if !generics.has_where_clause_predicates {
+        return;
+    }
+    let def_id = node.def_id();
+    let hir_id = tcx.hir().local_def_id_to_hir_id(def_id);
+    let (level, _) = tcx.lint_level_at_node(UNUSED_WHERE_CLAUSE_LIFETIMES, hir_id);
+    if level == Level::Allow {
+        return;
+    }
+    // Other crates may rely on the parameter, e.g. by naming it in a turbofish, so removing it
+    // would be a breaking change.
+    if tcx.privacy_access_levels(()).is_reachable(def_id) {
+        return;
+    }
+
+    let mut uses =
+        LifetimeUseCounter { tcx, skipped: generics.predicates, counts: FxHashMap::default() };
+    match node {
+        hir::OwnerNode::Item(item) => uses.visit_item(item),
+        hir::OwnerNode::ImplItem(item) => uses.visit_impl_item(item),
+        _ => unreachable!(),
+    }
+
+    let where_predicates: Vec<_> =
+        generics.predicates.iter().filter(|pred| pred.in_where_clause()).collect();
+    let written_params: Vec<_> = generics
+        .params
+        .iter()
+        .filter(|param| match param.kind {
+            hir::GenericParamKind::Lifetime { kind } => {
+                matches!(kind, hir::LifetimeParamKind::Explicit)
+            }
+            hir::GenericParamKind::Type { synthetic, .. } => !synthetic,
+            hir::GenericParamKind::Const { .. } => true,
+        })
+        .collect();
+
+    for (param_idx, param) in written_params.iter().enumerate() {
+        if !matches!(param.kind, hir::GenericParamKind::Lifetime { .. }) {
+            continue;
+        }
+        let def_id = tcx.hir().local_def_id(param.hir_id);
+        if uses.counts.contains_key(&def_id) {
+            continue;
+        }
+
+        let is_param = |lifetime: &hir::Lifetime| {
+            matches!(lifetime.name, hir::LifetimeName::Param(id, _) if id == def_id)
+        };
+        let is_only_bound = |bounds: &[hir::GenericBound<'_>]| {
+            matches!(bounds, [hir::GenericBound::Outlives(lifetime)] if is_param(lifetime))
+        };
+        // Whether each predicate mentioning the lifetime has it as the longer (`true`) or the
+        // shorter (`false`) lifetime of the bound. Anything else counts as a proper use.
+        let mut dead_predicates = vec![];
+        let mut sides = FxHashSet::default();
+        let mut used = false;
+        for (pred_idx, &pred) in where_predicates.iter().enumerate() {
+            let mut mentions =
+                LifetimeUseCounter { tcx, skipped: &[], counts: FxHashMap::default() };
+            mentions.visit_where_predicate(pred);
+            let Some(&count) = mentions.counts.get(&def_id) else { continue };
+            let side = match pred {
+                hir::WherePredicate::RegionPredicate(pred) if is_param(&pred.lifetime) => true,
+                hir::WherePredicate::RegionPredicate(pred) if is_only_bound(pred.bounds) => false,
+                hir::WherePredicate::BoundPredicate(pred)
+                    if pred.bound_generic_params.is_empty()
+                        && is_only_bound(pred.bounds)
+                        && count == 1 =>
+                {
+                    false
+                }
+                _ => {
+                    used = true;
+                    break;
+                }
+            };
+            sides.insert(side);
+            dead_predicates.push(pred_idx);
+        }
+        if used || sides.len() != 1 {
+            continue;
+        }
+
+        let param_spans: Vec<_> = written_params.iter().map(|param| param.span).collect();
+        let mut removals = list_removal_spans(&param_spans, |idx| idx == param_idx)
+            .unwrap_or_else(|| vec![generics.span]);
+        let predicate_spans: Vec<_> = where_predicates.iter().map(|pred| pred.span()).collect();
+        match list_removal_spans(&predicate_spans, |idx| dead_predicates.contains(&idx)) {
+            Some(spans) => removals.extend(spans),
+            None => {
+                // Remove the whole `where` clause, along with the whitespace in front of it.
+                let span = generics.where_clause_span;
+                let span = tcx.sess.source_map().span_to_prev_source(span).map_or(span, |prev| {
+                    let whitespace = prev.len() - prev.trim_end().len();
+                    span.with_lo(span.lo() - BytePos(whitespace as u32))
+                });
+                removals.push(span);
+            }
+        }
+
+        tcx.emit_spanned_lint(
+            UNUSED_WHERE_CLAUSE_LIFETIMES,
+            param.hir_id,
+            param.span,
+            UnusedWhereClauseLifetime {
+                name: param.name.ident(),
+                predicate_spans: dead_predicates.iter().map(|&idx| predicate_spans[idx]).collect(),
+                removals,
+            },
+        );
+    }
+}
+
+/// Returns the spans removing the elements of a comma separated list for which `remove` returns
+/// `true` along with the commas separating them from the remaining elements, or `None` if all of
+/// the elements are removed.
+fn list_removal_spans(spans: &[Span], remove: impl Fn(usize) -> bool) -> Option<Vec<Span>> {
+    let last_kept = (0..spans.len()).rev().find(|&idx| !remove(idx))?;
+    let mut removals: Vec<_> = (0..last_kept)
+        .filter(|&idx| remove(idx))
+        .map(|idx| spans[idx].until(spans[idx + 1]))
+        .collect();
+    if last_kept + 1 < spans.len() {
+        removals.push(spans[last_kept].shrink_to_hi().to(spans[spans.len() - 1]));
+    }
+    Some(removals)
+}
+
+/// Counts the mentions of each lifetime parameter, including within bodies and the items of
+/// an impl, but not within the `skipped` predicates of a `where` clause.
+struct LifetimeUseCounter<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    skipped: &'tcx [hir::WherePredicate<'tcx>],
+    counts: FxHashMap<LocalDefId, usize>,
+}
+
+impl<'tcx> Visitor<'tcx> for LifetimeUseCounter<'tcx> {
+    type NestedFilter = nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.tcx.hir()
+    }
+
+    fn visit_where_predicate(&mut self, predicate: &'tcx hir::WherePredicate<'tcx>) {
+        let skipped = predicate.in_where_clause()
+            && self.skipped.iter().any(|skipped| std::ptr::eq(skipped, predicate));
+        if !skipped {
+            intravisit::walk_where_predicate(self, predicate);
+        }
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'tcx hir::Lifetime) {
+        if let hir::LifetimeName::Param(def_id, _) = lifetime.name {
+            *self.counts.entry(def_id).or_default() += 1;
+        }
+    }
 }
 
 /// Checks that the field types (in a struct def'n) or argument types (in an enum def'n) are
@@ -177,11 +367,15 @@ fn check_item<'tcx>(tcx: TyCtxt<'tcx>, item: &'tcx hir::Item<'tcx>) {
                 .map_or(false, |trait_ref| tcx.trait_is_auto(trait_ref.def_id));
             if let (hir::Defaultness::Default { .. }, true) = (impl_.defaultness, is_auto) {
                 let sp = impl_.of_trait.as_ref().map_or(item.span, |t| t.path.span);
-                let mut err =
-                    tcx.sess.struct_span_err(sp, "impls of auto traits cannot be default");
-                err.span_labels(impl_.defaultness_span, "default because of this");
-                err.span_label(sp, "auto trait");
-                err.emit();
+                let removal_span = impl_.defaultness_span.map(|span| {
+                    let source_map = tcx.sess.source_map();
+                    source_map.span_extend_while(span, char::is_whitespace).unwrap_or(span)
+                });
+                tcx.sess.emit_err(AutoTraitImplDefault {
+                    span: sp,
+                    default_span: impl_.defaultness_span,
+                    removal_span,
+                });
             }
             // We match on both `ty::ImplPolarity` and `ast::ImplPolarity` just to get the `!` span.
             match (tcx.impl_polarity(def_id), impl_.polarity) {
@@ -477,37 +671,17 @@ fn check_gat_where_clauses(tcx: TyCtxt<'_>, associated_items: &[hir::TraitItemRe
         unsatisfied_bounds.sort();
 
         if !unsatisfied_bounds.is_empty() {
-            let plural = pluralize!(unsatisfied_bounds.len());
-            let mut err = tcx.sess.struct_span_err(
-                gat_item_hir.span,
-                &format!("missing required bound{} on `{}`", plural, gat_item_hir.ident),
-            );
-
-            let suggestion = format!(
-                "{} {}",
-                gat_item_hir.generics.add_where_or_trailing_comma(),
-                unsatisfied_bounds.join(", "),
-            );
-            err.span_suggestion(
-                gat_item_hir.generics.tail_span_for_predicate_suggestion(),
-                &format!("add the required where clause{plural}"),
-                suggestion,
-                Applicability::MachineApplicable,
-            );
-
-            let bound =
-                if unsatisfied_bounds.len() > 1 { "these bounds are" } else { "this bound is" };
-            err.note(&format!(
-                "{} currently required to ensure that impls have maximum flexibility",
-                bound
-            ));
-            err.note(
-                "we are soliciting feedback, see issue #87479 \
-                 <https://github.com/rust-lang/rust/issues/87479> \
-                 for more information",
-            );
-
-            err.emit();
+            tcx.sess.emit_err(MissingGatBounds {
+                span: gat_item_hir.span,
+                where_clause_span: gat_item_hir.generics.tail_span_for_predicate_suggestion(),
+                where_clause: format!(
+                    "{} {}",
+                    gat_item_hir.generics.add_where_or_trailing_comma(),
+                    unsatisfied_bounds.join(", "),
+                ),
+                gat: gat_item_hir.ident,
+                bounds_len: unsatisfied_bounds.len(),
+            });
         }
     }
 }
@@ -923,6 +1097,7 @@ fn check_param_wf(tcx: TyCtxt<'_>, param: &hir::GenericParam<'_>) {
                                     format!("`{ty}` doesn't derive both `PartialEq` and `Eq`"),
                                 );
                             }
+                            suggest_structural_derives(tcx, &mut diag, non_structural_match_ty);
 
                             diag.emit();
                         }
@@ -972,6 +1147,50 @@ fn check_param_wf(tcx: TyCtxt<'_>, param: &hir::GenericParam<'_>) {
     }
 }
 
+/// Suggests deriving the traits that make a local ADT structural-match, so that it can be used
+/// as the type of a const parameter.
+fn suggest_structural_derives<'tcx>(tcx: TyCtxt<'tcx>, diag: &mut Diagnostic, ty: Ty<'tcx>) {
+    let ty::Adt(def, _) = ty.kind() else { return };
+    let Some(def_id) = def.did().as_local() else { return };
+    let span = tcx.def_span(def_id);
+    if span.from_expansion() {
+        return;
+    }
+
+    let traits = [
+        (LangItem::StructuralPeq, tcx.lang_items().eq_trait(), "PartialEq"),
+        (LangItem::StructuralTeq, tcx.get_diagnostic_item(sym::Eq), "Eq"),
+    ];
+    let mut missing = vec![];
+    let manually_implemented = tcx.infer_ctxt().enter(|infcx| {
+        let implements = |trait_def_id| {
+            infcx
+                .type_implements_trait(trait_def_id, ty, ty::List::empty(), ty::ParamEnv::empty())
+                .must_apply_modulo_regions()
+        };
+        traits.into_iter().any(|(structural, trait_def_id, name)| {
+            if implements(tcx.require_lang_item(structural, Some(span))) {
+                return false;
+            }
+            missing.push(name);
+            // A manual impl would conflict with the derive, so it needs to be removed first.
+            trait_def_id.map_or(false, |def_id| implements(def_id))
+        })
+    });
+    if manually_implemented || missing.is_empty() {
+        return;
+    }
+
+    let derives = missing.join(", ");
+    let indentation = tcx.sess.source_map().indentation_before(span).unwrap_or_default();
+    diag.span_suggestion_verbose(
+        span.shrink_to_lo(),
+        &format!("consider annotating `{ty}` with `#[derive({derives})]`"),
+        format!("#[derive({derives})]\n{indentation}"),
+        Applicability::MaybeIncorrect,
+    );
+}
+
 #[tracing::instrument(level = "debug", skip(tcx, span, sig_if_method))]
 fn check_associated_item(
     tcx: TyCtxt<'_>,
@@ -1476,14 +1695,22 @@ fn check_fn_or_method<'tcx>(
     let sig = tcx.liberate_late_bound_regions(def_id.to_def_id(), sig);
 
     // Normalize the input and output types one at a time, using a different
-    // `WellFormedLoc` for each. We cannot call `normalize_associated_types`
+    // `WellFormedLoc` and span for each. We cannot call `normalize_associated_types`
     // on the entire `FnSig`, since this would use the same `WellFormedLoc`
     // for each type, preventing the HIR wf check from generating
-    // a nice error message.
+    // a nice error message. Using the span of each type also means that
+    // unsatisfied bounds on an associated type in e.g. the return type
+    // point at that type rather than at the entire function.
+    let ty_spans: Vec<Span> = hir_decl
+        .inputs
+        .iter()
+        .map(|ty| ty.span)
+        .chain(iter::once(hir_decl.output.span()))
+        .collect();
     let ty::FnSig { mut inputs_and_output, c_variadic, unsafety, abi } = sig;
     inputs_and_output = tcx.mk_type_list(inputs_and_output.iter().enumerate().map(|(i, ty)| {
         wfcx.normalize(
-            span,
+            ty_spans.get(i).copied().unwrap_or(span),
             Some(WellFormedLoc::Param {
                 function: def_id,
                 // Note that the `param_idx` of the output type is
@@ -1550,7 +1777,7 @@ fn check_method_receiver<'tcx>(
     if tcx.features().arbitrary_self_types {
         if !receiver_is_valid(wfcx, span, receiver_ty, self_ty, true) {
             // Report error; `arbitrary_self_types` was enabled.
-            e0307(tcx, span, receiver_ty);
+            e0307(tcx, wfcx.param_env, span, receiver_ty, self_ty);
         }
     } else {
         if !receiver_is_valid(wfcx, span, receiver_ty, self_ty, false) {
@@ -1569,22 +1796,53 @@ fn check_method_receiver<'tcx>(
                 .emit();
             } else {
                 // Report error; would not have worked with `arbitrary_self_types`.
-                e0307(tcx, span, receiver_ty);
+                e0307(tcx, wfcx.param_env, span, receiver_ty, self_ty);
             }
         }
     }
 }
 
-fn e0307<'tcx>(tcx: TyCtxt<'tcx>, span: Span, receiver_ty: Ty<'_>) {
-    struct_span_err!(
+fn e0307<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    span: Span,
+    receiver_ty: Ty<'tcx>,
+    self_ty: Ty<'tcx>,
+) {
+    let mut err = struct_span_err!(
         tcx.sess.diagnostic(),
         span,
         E0307,
         "invalid `self` parameter type: {receiver_ty}"
-    )
-    .note("type of `self` must be `Self` or a type that dereferences to it")
-    .help(HELP_FOR_SELF_TYPE)
-    .emit();
+    );
+    err.note("type of `self` must be `Self` or a type that dereferences to it");
+    err.help(HELP_FOR_SELF_TYPE);
+    // A common reason for `Ptr<Self>` not to deref to `Self` is a `Deref` impl for `Ptr<T>`
+    // that forgot to relax `T: Sized`.
+    if let Some(deref_trait) = tcx.lang_items().deref_trait()
+        && !receiver_ty.needs_infer()
+        && let Some(implicit_sized) =
+            implicitly_sized_impl_param(tcx, param_env, deref_trait, 0, receiver_ty)
+    {
+        err.subdiagnostic(implicit_sized);
+    }
+    // A custom pointer like `MyPtr<Self>` can only be used as a receiver if it derefs to `Self`.
+    if let Some(deref_trait) = tcx.lang_items().deref_trait()
+        && let ty::Adt(def, substs) = *receiver_ty.kind()
+        && substs.types().any(|ty| ty == self_ty)
+        && !tcx.all_impls(deref_trait).any(|impl_def_id| {
+            matches!(*tcx.type_of(impl_def_id).kind(), ty::Adt(impl_def, _) if impl_def == def)
+        })
+    {
+        err.span_note(
+            tcx.def_span(def.did()),
+            &format!(
+                "`{}` does not implement `Deref`, so it can't dereference to `Self`",
+                tcx.def_path_str(def.did()),
+            ),
+        );
+    }
+    err.emit();
 }
 
 /// Returns whether `receiver_ty` would be considered a valid receiver type for `self_ty`. If
@@ -2,51 +2,68 @@
 //! up data structures required by type-checking/codegen.
 
 use crate::errors::{
-    CoerceUnsizedInvalidDefinition, CoerceUnsizedNoCoercedField, CoerceUnsizedNotAStruct,
-    CoerceUnsizedTooManyCoercedFields, CopyImplOnNonAdt, CopyImplOnTypeWithDtor,
-    DropImplOnWrongItem, InvalidDispatchFromDynDeclaration, InvalidDispatchFromDynDeclarationType,
+    CoerceUnsizedInvalidDefinition, CoerceUnsizedMultiFieldMismatch, CoerceUnsizedNoCoercedField,
+    CoerceUnsizedNotAStruct, CoerceUnsizedTooManyCoercedFields, CoerceUnsizedUnchangedFields,
+    CopyImplOnNonAdt, CopyImplOnTypeWithDtor, DispatchFromDynFieldImplicitlySized,
+    DispatchFromDynThroughPin, DropImplOnWrongItem, InvalidDispatchFromDynDeclaration,
+    InvalidDispatchFromDynDeclarationType, UnreachableCoerceUnsizedImpl,
 };
+use rustc_data_structures::fx::FxIndexMap;
 use rustc_errors::{struct_span_err, MultiSpan};
 use rustc_hir as hir;
+use rustc_hir::def::CtorKind;
 use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::ItemKind;
 use rustc_infer::infer;
 use rustc_infer::infer::outlives::env::OutlivesEnvironment;
 use rustc_infer::infer::TyCtxtInferExt;
-use rustc_middle::ty::adjustment::CoerceUnsizedInfo;
+use rustc_infer::traits::error_reporting::implicit_sized::implicitly_sized_impl_param;
+use rustc_middle::ty::adjustment::{CoerceUnsizedInfo, CustomCoerceUnsized};
+use rustc_middle::ty::subst::{GenericArgKind, SubstsRef};
 use rustc_middle::ty::{self, suggest_constraining_type_params, Ty, TyCtxt, TypeVisitable};
+use rustc_session::lint::{builtin::UNREACHABLE_COERCE_UNSIZED_IMPLS, Level};
+use rustc_span::symbol::sym;
+use rustc_span::Span;
 use rustc_trait_selection::traits::error_reporting::InferCtxtExt;
 use rustc_trait_selection::traits::misc::{can_type_implement_copy, CopyImplementationError};
 use rustc_trait_selection::traits::predicate_for_trait_def;
 use rustc_trait_selection::traits::{self, ObligationCause};
-use std::collections::BTreeMap;
-
+use std::collections::BTreeSet;
+
+/// A check run on every local impl of a builtin trait.
+type BuiltinImplValidator = for<'tcx> fn(TyCtxt<'tcx>, LocalDefId);
+
+/// The lang item traits whose impls need extra validation, with the validator to run on each of
+/// their local impls. This is a fixed table: checks for a new lang item are added here, while
+/// custom drivers register theirs through the `extra_impl_validators` query instead.
+const BUILTIN_IMPL_VALIDATORS: &[(LangItem, BuiltinImplValidator)] = &[
+    (LangItem::Drop, visit_implementation_of_drop),
+    (LangItem::Copy, visit_implementation_of_copy),
+    (LangItem::CoerceUnsized, visit_implementation_of_coerce_unsized),
+    (LangItem::DispatchFromDyn, visit_implementation_of_dispatch_from_dyn),
+];
+
+/// Runs the validators for impls of `trait_def_id`.
+///
+/// There is no need to look through trait aliases here: implementing a trait alias is rejected
+/// during name resolution, so every impl of a builtin trait names that trait directly.
 pub fn check_trait(tcx: TyCtxt<'_>, trait_def_id: DefId) {
     let lang_items = tcx.lang_items();
-    Checker { tcx, trait_def_id }
-        .check(lang_items.drop_trait(), visit_implementation_of_drop)
-        .check(lang_items.copy_trait(), visit_implementation_of_copy)
-        .check(lang_items.coerce_unsized_trait(), visit_implementation_of_coerce_unsized)
-        .check(lang_items.dispatch_from_dyn_trait(), visit_implementation_of_dispatch_from_dyn);
-}
-
-struct Checker<'tcx> {
-    tcx: TyCtxt<'tcx>,
-    trait_def_id: DefId,
-}
+    for &(lang_item, validator) in BUILTIN_IMPL_VALIDATORS {
+        if lang_items.get(lang_item) == Some(trait_def_id) {
+            for &impl_def_id in tcx.hir().trait_impls(trait_def_id) {
+                validator(tcx, impl_def_id);
+            }
+        }
+    }
 
-impl<'tcx> Checker<'tcx> {
-    fn check<F>(&self, trait_def_id: Option<DefId>, mut f: F) -> &Self
-    where
-        F: FnMut(TyCtxt<'tcx>, LocalDefId),
-    {
-        if Some(self.trait_def_id) == trait_def_id {
-            for &impl_def_id in self.tcx.hir().trait_impls(self.trait_def_id) {
-                f(self.tcx, impl_def_id);
+    for validator in tcx.extra_impl_validators(()) {
+        if validator.trait_def_id == trait_def_id {
+            for &impl_def_id in tcx.hir().trait_impls(trait_def_id) {
+                (validator.validate)(tcx, impl_def_id);
             }
         }
-        self
     }
 }
 
@@ -66,6 +83,9 @@ fn visit_implementation_of_drop(tcx: TyCtxt<'_>, impl_did: LocalDefId) {
     tcx.sess.emit_err(DropImplOnWrongItem { span: sp });
 }
 
+/// For how many fields reporting E0204 works out the precise reasons why they are not `Copy`.
+const COPY_IMPL_NOTES_BUDGET: usize = 8;
+
 fn visit_implementation_of_copy(tcx: TyCtxt<'_>, impl_did: LocalDefId) {
     debug!("visit_implementation_of_copy: impl_did={:?}", impl_did);
 
@@ -99,17 +119,31 @@ fn visit_implementation_of_copy(tcx: TyCtxt<'_>, impl_did: LocalDefId) {
             );
 
             // We'll try to suggest constraining type parameters to fulfill the requirements of
-            // their `Copy` implementation.
-            let mut errors: BTreeMap<_, Vec<_>> = Default::default();
+            // their `Copy` implementation. Each group of requirements remembers the first field
+            // that needs it, so that the notes follow the order in which fields are declared.
+            let mut errors: FxIndexMap<_, (Span, Vec<_>)> = Default::default();
             let mut bounds = vec![];
+            let mut design_hints = vec![];
+            // Solving the `Copy` obligations of every field can be very slow for pathological
+            // types, so stop looking for the precise reasons once the budget is used up.
+            let mut over_budget = false;
 
-            for (field, ty) in fields {
+            for (i, (field, ty)) in fields.into_iter().enumerate() {
                 let field_span = tcx.def_span(field.did);
                 let field_ty_span = match tcx.hir().get_if_local(field.did) {
                     Some(hir::Node::Field(field_def)) => field_def.ty.span,
                     _ => field_span,
                 };
                 err.span_label(field_span, "this field does not implement `Copy`");
+                if tcx.sess.opts.unstable_opts.design_hints
+                    && let Some(hint) = copy_design_hint(tcx, param_env, field_ty_span, ty)
+                {
+                    design_hints.push((field_ty_span, hint));
+                }
+                if !tcx.sess.opts.unstable_opts.diag_no_budget && i >= COPY_IMPL_NOTES_BUDGET {
+                    over_budget = true;
+                    continue;
+                }
                 // Spin up a new FulfillmentContext, so we can get the _precise_ reason
                 // why this field does not implement Copy. This is useful because sometimes
                 // it is not immediately clear why Copy is not implemented for a field, since
@@ -131,7 +165,8 @@ fn visit_implementation_of_copy(tcx: TyCtxt<'_>, impl_did: LocalDefId) {
                         if error_predicate != error.root_obligation.predicate {
                             errors
                                 .entry((ty.to_string(), error_predicate.to_string()))
-                                .or_default()
+                                .or_insert_with(|| (field_span, vec![]))
+                                .1
                                 .push(error.obligation.cause.span);
                         }
                         if let ty::PredicateKind::Trait(ty::TraitPredicate {
@@ -152,7 +187,9 @@ fn visit_implementation_of_copy(tcx: TyCtxt<'_>, impl_did: LocalDefId) {
                     }
                 });
             }
-            for ((ty, error_predicate), spans) in errors {
+            let mut errors: Vec<_> = errors.into_iter().collect();
+            errors.sort_by_key(|&(_, (field_span, _))| field_span);
+            for ((ty, error_predicate), (_, spans)) in errors {
                 let span: MultiSpan = spans.into();
                 err.span_note(
                     span,
@@ -167,6 +204,15 @@ fn visit_implementation_of_copy(tcx: TyCtxt<'_>, impl_did: LocalDefId) {
                     (param.as_str(), constraint.as_str(), *def_id)
                 }),
             );
+            for (span, hint) in design_hints {
+                err.span_note(span, &hint);
+            }
+            if over_budget {
+                err.note(
+                    "additional context omitted (in Nightly builds, run with -Z diag-no-budget \
+                     for more info)",
+                );
+            }
             err.emit();
         }
         Err(CopyImplementationError::NotAnAdt) => {
@@ -182,6 +228,40 @@ fn visit_implementation_of_copy(tcx: TyCtxt<'_>, impl_did: LocalDefId) {
     }
 }
 
+/// For a field type that keeps a type from being `Copy` because it owns or mutably borrows its
+/// data, returns a note suggesting to share that data in a type implementing `Clone` instead.
+/// Owned data can be shared through a `Cow`, while data that is mutated needs shared ownership.
+fn copy_design_hint<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    span: Span,
+    ty: Ty<'tcx>,
+) -> Option<String> {
+    let borrowed = match *ty.kind() {
+        ty::Ref(_, inner, hir::Mutability::Mut) => {
+            let cell = if inner.is_copy_modulo_regions(tcx.at(span), param_env) {
+                "Cell"
+            } else {
+                "RefCell"
+            };
+            return Some(format!(
+                "if this data is mutated through every copy, consider storing an \
+                 `Rc<{cell}<{inner}>>` and implementing `Clone` instead of `Copy`"
+            ));
+        }
+        ty::Adt(def, substs) if def.is_box() => substs.type_at(0).to_string(),
+        ty::Adt(def, _) if tcx.is_diagnostic_item(sym::String, def.did()) => "str".to_string(),
+        ty::Adt(def, substs) if tcx.is_diagnostic_item(sym::Vec, def.did()) => {
+            format!("[{}]", substs.type_at(0))
+        }
+        _ => return None,
+    };
+    Some(format!(
+        "if this data is mostly read and shared, consider storing a `Cow<'_, {borrowed}>` and \
+         implementing `Clone` instead of `Copy`, or moving it out of this type"
+    ))
+}
+
 fn visit_implementation_of_coerce_unsized<'tcx>(tcx: TyCtxt<'tcx>, impl_did: LocalDefId) {
     debug!("visit_implementation_of_coerce_unsized: impl_did={:?}", impl_did);
 
@@ -189,7 +269,41 @@ fn visit_implementation_of_coerce_unsized<'tcx>(tcx: TyCtxt<'tcx>, impl_did: Loc
     // errors; other parts of the code may demand it for the info of
     // course.
     let span = tcx.def_span(impl_did);
-    tcx.at(span).coerce_unsized_info(impl_did);
+    let info = tcx.at(span).coerce_unsized_info(impl_did);
+    if let Some(CustomCoerceUnsized::Struct(field_index)) = info.custom_kind {
+        lint_unreachable_coerce_unsized_impl(tcx, impl_did, field_index);
+    }
+}
+
+/// Lints `CoerceUnsized` impls whose struct isn't reachable from other crates and whose coerced
+/// field is private, as only the current crate can ever perform the coercion.
+fn lint_unreachable_coerce_unsized_impl(tcx: TyCtxt<'_>, impl_did: LocalDefId, field_index: usize) {
+    let hir_id = tcx.hir().local_def_id_to_hir_id(impl_did);
+    let (level, _) = tcx.lint_level_at_node(UNREACHABLE_COERCE_UNSIZED_IMPLS, hir_id);
+    if level == Level::Allow {
+        return;
+    }
+
+    let self_ty = tcx.type_of(impl_did);
+    let ty::Adt(def, _) = *self_ty.kind() else { return };
+    let Some(struct_did) = def.did().as_local() else { return };
+    let field = &def.non_enum_variant().fields[field_index];
+    if tcx.visibility(field.did).is_public()
+        || tcx.privacy_access_levels(()).is_reachable(struct_did)
+    {
+        return;
+    }
+
+    tcx.emit_spanned_lint(
+        UNREACHABLE_COERCE_UNSIZED_IMPLS,
+        hir_id,
+        tcx.def_span(impl_did),
+        UnreachableCoerceUnsizedImpl {
+            field_span: tcx.def_span(field.did),
+            field_name: field_path(def.non_enum_variant(), field),
+            self_ty,
+        },
+    );
 }
 
 fn visit_implementation_of_dispatch_from_dyn<'tcx>(tcx: TyCtxt<'tcx>, impl_did: LocalDefId) {
@@ -218,6 +332,52 @@ fn visit_implementation_of_dispatch_from_dyn<'tcx>(tcx: TyCtxt<'tcx>, impl_did:
     tcx.infer_ctxt().enter(|infcx| {
         let cause = ObligationCause::misc(span, impl_hir_id);
 
+        // `Pin<P>: DispatchFromDyn<Pin<U>>` holds exactly when `P: DispatchFromDyn<U>`, so
+        // problems with impls involving `Pin` are reported in terms of the inner pointer.
+        let pin_pointer = |ty: Ty<'tcx>| match ty.kind() {
+            ty::Adt(def, substs) if tcx.lang_items().pin_type() == Some(def.did()) => {
+                Some(substs.type_at(0))
+            }
+            _ => None,
+        };
+        match (pin_pointer(source), pin_pointer(target)) {
+            (None, None) => {}
+            (Some(pointer), Some(pointer_target)) => {
+                let errors = traits::fully_solve_obligation(
+                    &infcx,
+                    predicate_for_trait_def(
+                        tcx,
+                        param_env,
+                        cause.clone(),
+                        dispatch_from_dyn_trait,
+                        0,
+                        pointer,
+                        &[pointer_target.into()],
+                    ),
+                );
+                if !errors.is_empty() {
+                    report_dispatch_from_dyn_through_pin(
+                        tcx,
+                        impl_did,
+                        span,
+                        pointer,
+                        pointer_target,
+                    );
+                    return;
+                }
+            }
+            (pointer, pointer_target) => {
+                report_dispatch_from_dyn_through_pin(
+                    tcx,
+                    impl_did,
+                    span,
+                    pointer.unwrap_or(source),
+                    pointer_target.unwrap_or(target),
+                );
+                return;
+            }
+        }
+
         use rustc_type_ir::sty::TyKind::*;
         match (source.kind(), target.kind()) {
             (&Ref(r_a, _, mutbl_a), Ref(r_b, _, mutbl_b))
@@ -248,7 +408,8 @@ fn visit_implementation_of_dispatch_from_dyn<'tcx>(tcx: TyCtxt<'tcx>, impl_did:
                     });
                 }
 
-                let fields = &def_a.non_enum_variant().fields;
+                let variant = def_a.non_enum_variant();
+                let fields = &variant.fields;
 
                 let coerced_fields = fields
                     .iter()
@@ -269,7 +430,8 @@ fn visit_implementation_of_dispatch_from_dyn<'tcx>(tcx: TyCtxt<'tcx>, impl_did:
                                     span,
                                     err_type:
                                         InvalidDispatchFromDynDeclarationType::InvalidFields {
-                                            field_name: field.name,
+                                            field_span: tcx.def_span(field.did),
+                                            field_name: field_path(variant, field),
                                             ty_a: ty_a.to_string(),
                                         },
                                 });
@@ -288,16 +450,22 @@ fn visit_implementation_of_dispatch_from_dyn<'tcx>(tcx: TyCtxt<'tcx>, impl_did:
                         err_type: InvalidDispatchFromDynDeclarationType::NoCoercedFields,
                     });
                 } else if coerced_fields.len() > 1 {
+                    let mut coerced_fields = coerced_fields;
+                    coerced_fields.sort_by_key(|field| tcx.def_span(field.did));
                     tcx.sess.emit_err(InvalidDispatchFromDynDeclaration {
                         span,
                         err_type: InvalidDispatchFromDynDeclarationType::TooManyCoercedFields {
+                            field_spans: coerced_fields
+                                .iter()
+                                .map(|field| tcx.def_span(field.did))
+                                .collect(),
                             coerced_fields_len: coerced_fields.len(),
                             coerced_fields: coerced_fields
                                 .iter()
                                 .map(|field| {
                                     format!(
                                         "`{}` (`{}` -> `{}`)",
-                                        field.name,
+                                        field_path(variant, field),
                                         field.ty(tcx, substs_a),
                                         field.ty(tcx, substs_b),
                                     )
@@ -307,22 +475,40 @@ fn visit_implementation_of_dispatch_from_dyn<'tcx>(tcx: TyCtxt<'tcx>, impl_did:
                         },
                     });
                 } else {
-                    let errors = traits::fully_solve_obligations(
+                    let field = coerced_fields[0];
+                    let (field_ty_a, field_ty_b) =
+                        (field.ty(tcx, substs_a), field.ty(tcx, substs_b));
+                    let errors = traits::fully_solve_obligation(
                         &infcx,
-                        coerced_fields.into_iter().map(|field| {
-                            predicate_for_trait_def(
-                                tcx,
-                                param_env,
-                                cause.clone(),
-                                dispatch_from_dyn_trait,
-                                0,
-                                field.ty(tcx, substs_a),
-                                &[field.ty(tcx, substs_b).into()],
-                            )
-                        }),
+                        predicate_for_trait_def(
+                            tcx,
+                            param_env,
+                            cause.clone(),
+                            dispatch_from_dyn_trait,
+                            0,
+                            field_ty_a,
+                            &[field_ty_b.into()],
+                        ),
                     );
                     if !errors.is_empty() {
-                        infcx.report_fulfillment_errors(&errors, None, false);
+                        // The field's own `DispatchFromDyn` impl may only be unusable because it
+                        // requires the (unsized) target to be `Sized`.
+                        if let Some(implicit_sized) = implicitly_sized_impl_param(
+                            tcx,
+                            param_env,
+                            dispatch_from_dyn_trait,
+                            1,
+                            field_ty_b,
+                        ) {
+                            tcx.sess.emit_err(DispatchFromDynFieldImplicitlySized {
+                                span,
+                                field_ty: field_ty_a,
+                                target_ty: field_ty_b,
+                                implicit_sized,
+                            });
+                        } else {
+                            infcx.report_fulfillment_errors(&errors, None, false);
+                        }
                     }
 
                     // Finally, resolve all regions.
@@ -340,6 +526,58 @@ fn visit_implementation_of_dispatch_from_dyn<'tcx>(tcx: TyCtxt<'tcx>, impl_did:
     })
 }
 
+/// Reports a `DispatchFromDyn` impl whose source or target is a `Pin`, pointing at the pointer
+/// inside the `Pin` and suggesting to implement the trait for that pointer instead.
+fn report_dispatch_from_dyn_through_pin<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    impl_did: LocalDefId,
+    span: Span,
+    pointer: Ty<'tcx>,
+    pointer_target: Ty<'tcx>,
+) {
+    let pin_pointer_arg = |ty: &'tcx hir::Ty<'tcx>| {
+        let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = ty.kind else { return None };
+        let def_id = path.res.opt_def_id()?;
+        if tcx.lang_items().pin_type() != Some(def_id) {
+            return None;
+        }
+        path.segments.last()?.args().args.iter().find_map(|arg| match arg {
+            hir::GenericArg::Type(inner) => Some((ty.span, inner.span)),
+            _ => None,
+        })
+    };
+
+    let ItemKind::Impl(impl_) = tcx.hir().expect_item(impl_did).kind else { bug!() };
+    let target_ty = impl_.of_trait.as_ref().and_then(|trait_ref| {
+        trait_ref.path.segments.last()?.args().args.iter().find_map(|arg| match arg {
+            hir::GenericArg::Type(ty) => Some(*ty),
+            _ => None,
+        })
+    });
+    let pins: Vec<_> = [Some(impl_.self_ty), target_ty]
+        .into_iter()
+        .flatten()
+        .filter_map(pin_pointer_arg)
+        .collect();
+
+    let source_map = tcx.sess.source_map();
+    let suggestion = pins
+        .iter()
+        .map(|&(pin_span, inner_span)| {
+            Some((pin_span, source_map.span_to_snippet(inner_span).ok()?))
+        })
+        .collect::<Option<Vec<_>>>()
+        .filter(|suggestion| !suggestion.is_empty());
+
+    tcx.sess.emit_err(DispatchFromDynThroughPin {
+        span,
+        pointer_span: pins.first().map(|&(_, inner_span)| inner_span),
+        pointer: pointer.to_string(),
+        pointer_target: pointer_target.to_string(),
+        suggestion,
+    });
+}
+
 pub fn coerce_unsized_info<'tcx>(tcx: TyCtxt<'tcx>, impl_did: DefId) -> CoerceUnsizedInfo {
     debug!("compute_coerce_unsized_info(impl_did={:?})", impl_did);
 
@@ -408,10 +646,26 @@ pub fn coerce_unsized_info<'tcx>(tcx: TyCtxt<'tcx>, impl_did: DefId) -> CoerceUn
                     let source_path = tcx.def_path_str(def_a.did());
                     let target_path = tcx.def_path_str(def_b.did());
 
+                    let ItemKind::Impl(impl_) = tcx.hir().expect_item(impl_did).kind else {
+                        bug!("expected CoerceUnsized impl item")
+                    };
+                    let target_span = impl_.of_trait.as_ref().and_then(|trait_ref| {
+                        trait_ref.path.segments.last()?.args().args.iter().find_map(|arg| {
+                            match arg {
+                                hir::GenericArg::Type(ty) => Some(ty.span),
+                                _ => None,
+                            }
+                        })
+                    });
+
                     tcx.sess.emit_err(CoerceUnsizedInvalidDefinition {
                         span,
+                        source_span: impl_.self_ty.span,
+                        target_span,
                         source_path,
                         target_path,
+                        source,
+                        target,
                     });
 
                     return err_info;
@@ -443,9 +697,10 @@ pub fn coerce_unsized_info<'tcx>(tcx: TyCtxt<'tcx>, impl_did: DefId) -> CoerceUn
                 // both substitutes. We are looking to find that
                 // exactly one (non-phantom) field has changed its
                 // type, which we will expect to be the pointer that
-                // is becoming fat (we could probably generalize this
-                // to multiple thin pointers of the same type becoming
-                // fat, but we don't). In this case:
+                // is becoming fat (impls marked with
+                // `#[rustc_coerce_unsized_multi_field]` may instead have
+                // several fields becoming fat, as long as they all undergo
+                // the exact same coercion). In this case:
                 //
                 // - `extra` has type `T` before and type `T` after
                 // - `ptr` has type `*mut U` before and type `*mut V` after
@@ -456,7 +711,8 @@ pub fn coerce_unsized_info<'tcx>(tcx: TyCtxt<'tcx>, impl_did: DefId) -> CoerceUn
                 // conversion). This will work out because `U:
                 // Unsize<V>`, and we have a builtin rule that `*mut
                 // U` can be coerced to `*mut V` if `U: Unsize<V>`.
-                let fields = &def_a.non_enum_variant().fields;
+                let variant = def_a.non_enum_variant();
+                let fields = &variant.fields;
                 let diff_fields = fields
                     .iter()
                     .enumerate()
@@ -490,7 +746,9 @@ pub fn coerce_unsized_info<'tcx>(tcx: TyCtxt<'tcx>, impl_did: DefId) -> CoerceUn
                     .collect::<Vec<_>>();
 
                 if diff_fields.is_empty() {
-                    tcx.sess.emit_err(CoerceUnsizedNoCoercedField { span });
+                    let unchanged_fields =
+                        coerce_unsized_unchanged_fields(tcx, def_a, substs_a, source, target);
+                    tcx.sess.emit_err(CoerceUnsizedNoCoercedField { span, unchanged_fields });
 
                     return err_info;
                 } else if diff_fields.len() > 1 {
@@ -503,19 +761,51 @@ pub fn coerce_unsized_info<'tcx>(tcx: TyCtxt<'tcx>, impl_did: DefId) -> CoerceUn
                         tcx.def_span(impl_did)
                     };
 
-                    tcx.sess.emit_err(CoerceUnsizedTooManyCoercedFields {
-                        span,
-                        _note: (),
-                        _fields_note: (),
-                        coerced_fields_len: diff_fields.len(),
-                        coerced_fields: diff_fields
+                    if tcx.has_attr(impl_did.to_def_id(), sym::rustc_coerce_unsized_multi_field) {
+                        // Only the first field is recorded in `CustomCoerceUnsized`, and the
+                        // collector relies on it to find the vtables needed by the coercion.
+                        // That is only correct if every other field is coerced the same way.
+                        let (first, first_source, first_target) = diff_fields[0];
+                        let mismatch = diff_fields
                             .iter()
-                            .map(|&(i, a, b)| format!("`{}` (`{}` -> `{}`)", fields[i].name, a, b))
-                            .collect::<Vec<_>>()
-                            .join(", "),
-                    });
+                            .find(|&&(_, a, b)| (a, b) != (first_source, first_target));
+                        if let Some(&(i, source, target)) = mismatch {
+                            tcx.sess.emit_err(CoerceUnsizedMultiFieldMismatch {
+                                span,
+                                first_field: field_path(variant, &fields[first]),
+                                first_source,
+                                first_target,
+                                field: field_path(variant, &fields[i]),
+                                source,
+                                target,
+                            });
+
+                            return err_info;
+                        }
+                    } else {
+                        let mut diff_fields = diff_fields;
+                        diff_fields.sort_by_key(|&(i, ..)| tcx.def_span(fields[i].did));
+                        tcx.sess.emit_err(CoerceUnsizedTooManyCoercedFields {
+                            span,
+                            _note: (),
+                            _fields_note: (),
+                            field_spans: diff_fields
+                                .iter()
+                                .map(|&(i, ..)| tcx.def_span(fields[i].did))
+                                .collect(),
+                            coerced_fields_len: diff_fields.len(),
+                            coerced_fields: diff_fields
+                                .iter()
+                                .map(|&(i, a, b)| {
+                                    let field = field_path(variant, &fields[i]);
+                                    format!("`{}` (`{}` -> `{}`)", field, a, b)
+                                })
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        });
 
-                    return err_info;
+                        return err_info;
+                    }
                 }
 
                 let (i, a, b) = diff_fields[0];
@@ -553,3 +843,75 @@ pub fn coerce_unsized_info<'tcx>(tcx: TyCtxt<'tcx>, impl_did: DefId) -> CoerceUn
         CoerceUnsizedInfo { custom_kind: kind }
     })
 }
+
+/// Renders `field` of `variant` for a list of fields in a diagnostic. The fields of tuple structs
+/// are shown as `self.0` rather than by their bare index, which reads like a count.
+fn field_path(variant: &ty::VariantDef, field: &ty::FieldDef) -> String {
+    match variant.ctor_kind {
+        CtorKind::Fn => format!("self.{}", field.name),
+        CtorKind::Fictive | CtorKind::Const => field.name.to_string(),
+    }
+}
+
+/// Explains why a `CoerceUnsized` impl coerces none of the fields of its struct `def`: the
+/// source and the target instantiate the generic parameters used by the field types the same
+/// way, as given by the source's `substs`. Returns `None` if no field uses any of the struct's
+/// generic parameters, as such fields can never be coerced.
+fn coerce_unsized_unchanged_fields<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def: ty::AdtDef<'tcx>,
+    substs: SubstsRef<'tcx>,
+    source: Ty<'tcx>,
+    target: Ty<'tcx>,
+) -> Option<CoerceUnsizedUnchangedFields<'tcx>> {
+    let variant = def.non_enum_variant();
+    let mut fields = vec![];
+    let mut params = BTreeSet::new();
+    for field in variant.fields.iter() {
+        let field_ty = tcx.type_of(field.did);
+        if field_ty.is_phantom_data() {
+            continue;
+        }
+        let used_params: Vec<_> = field_ty
+            .walk()
+            .filter_map(|arg| match arg.unpack() {
+                GenericArgKind::Type(ty) => match *ty.kind() {
+                    ty::Param(param) => Some(param.index),
+                    _ => None,
+                },
+                GenericArgKind::Const(ct) => match ct.kind() {
+                    ty::ConstKind::Param(param) => Some(param.index),
+                    _ => None,
+                },
+                GenericArgKind::Lifetime(_) => None,
+            })
+            .collect();
+        if !used_params.is_empty() {
+            fields.push(format!("`{}`", field_path(variant, field)));
+            params.extend(used_params);
+        }
+    }
+    if fields.is_empty() {
+        return None;
+    }
+
+    let generics = tcx.generics_of(def.did());
+    let mut params: Vec<_> = params
+        .into_iter()
+        .map(|index| {
+            let param = generics.param_at(index as usize, tcx);
+            format!("`{}` with `{}`", param.name, substs[index as usize])
+        })
+        .collect();
+    let last = params.pop().unwrap();
+    let params =
+        if params.is_empty() { last } else { format!("{} and {}", params.join(", "), last) };
+
+    Some(CoerceUnsizedUnchangedFields {
+        fields_len: fields.len(),
+        fields: fields.join(", "),
+        params,
+        source,
+        target,
+    })
+}
@@ -6,10 +6,12 @@
 // mappings. That mapping code resides here.
 
 use crate::errors::{
-    ExplicitImplOfInternalStructs, MarkerTraitImplContainsItems, TypeAutomaticallyImplementsTrait,
+    ExplicitImplOfCompilerImplementedTrait, ExplicitImplOfInternalStructs,
+    MarkerTraitImplContainsItems, TypeAutomaticallyImplementsTrait,
 };
 use rustc_errors::error_code;
 use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_middle::ty::fast_reject::SimplifiedTypeGen;
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::{self, TyCtxt, TypeVisitable};
 use rustc_trait_selection::traits;
@@ -46,21 +48,12 @@ fn enforce_trait_manually_implementable(
     let li = tcx.lang_items();
     let impl_header_span = tcx.def_span(impl_def_id);
 
-    // Disallow *all* explicit impls of `Pointee`, `DiscriminantKind`, `Sized` and `Unsize` for now.
-    if did == li.pointee_trait() {
-        tcx.sess.emit_err(ExplicitImplOfInternalStructs {
+    // Disallow *all* explicit impls of traits marked `#[rustc_deny_explicit_impl]`
+    // (e.g. `Pointee` and `DiscriminantKind`), as well as `Sized` and `Unsize` for now.
+    if tcx.trait_def(trait_def_id).deny_explicit_impl {
+        tcx.sess.emit_err(ExplicitImplOfCompilerImplementedTrait {
             span: impl_header_span,
-            error_code: error_code!(E0322),
-            trait_name: "Pointee",
-        });
-        return;
-    }
-
-    if did == li.discriminant_kind_trait() {
-        tcx.sess.emit_err(ExplicitImplOfInternalStructs {
-            span: impl_header_span,
-            error_code: error_code!(E0322),
-            trait_name: "DiscriminantKind",
+            trait_name: tcx.item_name(trait_def_id),
         });
         return;
     }
@@ -136,6 +129,7 @@ pub fn provide(providers: &mut Providers) {
         crate_inherent_impls_overlap_check,
         coerce_unsized_info,
         orphan_check_impl,
+        extra_impl_validators: |_, ()| &[],
         ..*providers
     };
 }
@@ -145,17 +139,33 @@ fn coherent_trait(tcx: TyCtxt<'_>, def_id: DefId) {
     // overlap errors.
     tcx.ensure().specialization_graph_of(def_id);
 
+    // The checks below are independent of each other and of the overlap check above, and all of
+    // them run for every impl even if one already failed, so that all problems with an impl are
+    // reported at once.
     let impls = tcx.hir().trait_impls(def_id);
     for &impl_def_id in impls {
         let trait_ref = tcx.impl_trait_ref(impl_def_id).unwrap();
 
         check_impl(tcx, impl_def_id, trait_ref);
-        check_object_overlap(tcx, impl_def_id, trait_ref);
 
         tcx.sess.time("unsafety_checking", || unsafety::check_item(tcx, impl_def_id));
         tcx.sess.time("orphan_checking", || tcx.ensure().orphan_check_impl(impl_def_id));
     }
 
+    // Only impls for trait object types can overlap with the automatic `impl Trait for dyn Trait`,
+    // so there is no need to look at impls bucketed under any other self type.
+    let local_impls = tcx.trait_impls_by_self_simplified(def_id);
+    for (simplified_self_ty, impls) in local_impls.non_blanket_impls() {
+        if let SimplifiedTypeGen::TraitSimplifiedType(_)
+        | SimplifiedTypeGen::MarkerTraitObjectSimplifiedType = simplified_self_ty
+        {
+            for &impl_def_id in impls {
+                let trait_ref = tcx.impl_trait_ref(impl_def_id).unwrap();
+                check_object_overlap(tcx, impl_def_id, trait_ref);
+            }
+        }
+    }
+
     builtin::check_trait(tcx, def_id);
 }
 
@@ -1,7 +1,7 @@
 //! Orphan checker: every impl either implements a trait defined in this
 //! crate or pertains to a type defined in this crate.
 
-use crate::errors::CrossCrateOptOutTraitImplOnInvalidTarget;
+use crate::errors::{CrossCrateOptOutTraitImplOnInvalidTarget, SuspiciousAutoTraitImpl};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::struct_span_err;
 use rustc_errors::{Diagnostic, ErrorGuaranteed};
@@ -164,7 +164,7 @@ fn visit_path(&mut self, path: &'v hir::Path<'v>, _id: hir::HirId) {
             _ => None,
         };
 
-        let msg = match opt_self_def_id {
+        let error_type = match opt_self_def_id {
             // We only want to permit nominal types, but not *all* nominal types.
             // They must be local to the current crate, so that people
             // can't do `unsafe impl Send for Rc<SomethingLocal>` or
@@ -173,18 +173,19 @@ fn visit_path(&mut self, path: &'v hir::Path<'v>, _id: hir::HirId) {
                 if self_def_id.is_local() {
                     None
                 } else {
-                    Some((tcx.def_path_str(trait_def_id), "".to_string(), "cross_crate"))
+                    Some("cross_crate")
                 }
             }
-            _ => Some((tcx.def_path_str(trait_def_id), self_ty.to_string(), "invalid_type")),
+            _ => Some("invalid_type"),
         };
 
-        if let Some((trait_path, self_type, error_type)) = msg {
+        if let Some(error_type) = error_type {
             let reported = tcx.sess.emit_err(CrossCrateOptOutTraitImplOnInvalidTarget {
                 span: sp,
-                trait_path,
+                self_ty_span: impl_.self_ty.span,
+                trait_path: tcx.def_path_str(trait_def_id),
                 error_type,
-                self_type,
+                self_type: self_ty.to_string(),
             });
 
             return Err(reported);
@@ -402,38 +403,60 @@ fn lint_auto_trait_impl<'tcx>(
         return;
     }
 
-    tcx.struct_span_lint_hir(
+    let (arg, kind) = match arg {
+        ty::util::NotUniqueParam::DuplicateParam(arg) => (arg, "duplicate"),
+        ty::util::NotUniqueParam::NotParam(arg) => (arg, "not_param"),
+    };
+    tcx.emit_spanned_lint(
         lint::builtin::SUSPICIOUS_AUTO_TRAIT_IMPLS,
         tcx.hir().local_def_id_to_hir_id(impl_def_id),
         tcx.def_span(impl_def_id),
-        |err| {
-            let item_span = tcx.def_span(self_type_did);
-            let self_descr = tcx.def_kind(self_type_did).descr(self_type_did);
-            let mut err = err.build(&format!(
-                "cross-crate traits with a default impl, like `{}`, \
-                         should not be specialized",
-                tcx.def_path_str(trait_ref.def_id),
-            ));
-            match arg {
-                ty::util::NotUniqueParam::DuplicateParam(arg) => {
-                    err.note(&format!("`{}` is mentioned multiple times", arg));
-                }
-                ty::util::NotUniqueParam::NotParam(arg) => {
-                    err.note(&format!("`{}` is not a generic parameter", arg));
-                }
-            }
-            err.span_note(
-                item_span,
-                &format!(
-                    "try using the same sequence of generic parameters as the {} definition",
-                    self_descr,
-                ),
-            );
-            err.emit();
+        SuspiciousAutoTraitImpl {
+            arg_span: offending_arg_span(tcx, impl_def_id, substs, arg),
+            adt_span: tcx.def_span(self_type_did),
+            trait_path: tcx.def_path_str(trait_ref.def_id),
+            arg: arg.to_string(),
+            kind,
+            adt_descr: tcx.def_kind(self_type_did).descr(self_type_did),
         },
     );
 }
 
+/// Finds the generic argument `arg` of the self type of an auto trait impl, whose generic
+/// arguments are `substs`. If `arg` is mentioned multiple times, this is its last mention.
+/// Falls back to the whole self type if the argument isn't written out, e.g. elided lifetimes.
+fn offending_arg_span<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    impl_def_id: LocalDefId,
+    substs: ty::SubstsRef<'tcx>,
+    arg: ty::GenericArg<'tcx>,
+) -> Span {
+    let item = tcx.hir().expect_item(impl_def_id);
+    let hir::ItemKind::Impl(impl_) = &item.kind else {
+        bug!("{:?} is not an impl: {:?}", impl_def_id, item);
+    };
+    let same_kind = |a: ty::GenericArg<'tcx>, b: ty::GenericArg<'tcx>| {
+        std::mem::discriminant(&a.unpack()) == std::mem::discriminant(&b.unpack())
+    };
+    let hir_arg_matches = |hir_arg: &hir::GenericArg<'_>| match (hir_arg, arg.unpack()) {
+        (hir::GenericArg::Lifetime(_), GenericArgKind::Lifetime(_))
+        | (hir::GenericArg::Type(_), GenericArgKind::Type(_))
+        | (hir::GenericArg::Const(_), GenericArgKind::Const(_)) => true,
+        _ => false,
+    };
+
+    let arg_span = || {
+        let index = substs.iter().rposition(|a| a == arg)?;
+        let nth = substs[..index].iter().filter(|&&a| same_kind(a, arg)).count();
+        let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = impl_.self_ty.kind else {
+            return None;
+        };
+        let hir_args = path.segments.last()?.args().args;
+        hir_args.iter().filter(|a| hir_arg_matches(a)).nth(nth).map(|a| a.span())
+    };
+    arg_span().unwrap_or(impl_.self_ty.span)
+}
+
 fn fast_reject_auto_impl<'tcx>(tcx: TyCtxt<'tcx>, trait_def_id: DefId, self_ty: Ty<'tcx>) -> bool {
     struct DisableAutoTraitVisitor<'tcx> {
         tcx: TyCtxt<'tcx>,
@@ -21,7 +21,7 @@
 use crate::errors;
 use crate::errors::{
     AttributeOnNonForeignFunction, CMSENonSecureEntryRequiresCAbi,
-    CMSENonSecureEntryRequiresTrustZoneMExt, EnumDiscriminantOverflow,
+    CMSENonSecureEntryRequiresTrustZoneMExt, DiscriminantOverflowKind, EnumDiscriminantOverflow,
     ExportNameContainsNullCharacters, FFIConstAndFFIPureOnSameFunction,
     InstructionSetUnsupportedOnTarget, RustcParenSugarNotEnabled, TrackCallerRequiresCAbi,
 };
@@ -43,6 +43,7 @@
 use rustc_middle::hir::nested_filter;
 use rustc_middle::middle::codegen_fn_attrs::{CodegenFnAttrFlags, CodegenFnAttrs};
 use rustc_middle::mir::mono::Linkage;
+use rustc_middle::ty::layout::IntegerExt;
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::subst::InternalSubsts;
 use rustc_middle::ty::util::Discr;
@@ -53,6 +54,7 @@
 use rustc_session::parse::feature_err;
 use rustc_span::symbol::{kw, sym, Ident, Symbol};
 use rustc_span::{Span, DUMMY_SP};
+use rustc_target::abi::Integer;
 use rustc_target::spec::{abi, SanitizerSet};
 use rustc_trait_selection::traits::error_reporting::suggestions::NextTypeParamName;
 use std::iter;
@@ -86,6 +88,7 @@ pub fn provide(providers: &mut Providers) {
         type_param_predicates,
         trait_def,
         adt_def,
+        variant_discriminant,
         fn_sig,
         impl_trait_ref,
         impl_polarity,
@@ -854,6 +857,8 @@ fn convert_trait_item(tcx: TyCtxt<'_>, trait_item_id: hir::TraitItemId) {
         hir::TraitItemKind::Fn(..) => {
             tcx.ensure().type_of(trait_item_id.def_id);
             tcx.ensure().fn_sig(trait_item_id.def_id);
+            // Declarations without a body are never codegened, so validate their attributes here.
+            tcx.ensure().codegen_fn_attrs(trait_item_id.def_id);
         }
 
         hir::TraitItemKind::Const(.., Some(_)) => {
@@ -923,24 +928,52 @@ fn convert_enum_variant_types(tcx: TyCtxt<'_>, def_id: DefId, variants: &[hir::V
     let def = tcx.adt_def(def_id);
     let repr_type = def.repr().discr_type();
     let initial = repr_type.initial_discriminant(tcx);
+    let (min, max) = Discr::bounds(tcx, initial.ty);
     let mut prev_discr = None::<Discr<'_>>;
 
     // fill the discriminant values and field types
     for variant in variants {
         let wrapped_discr = prev_discr.map_or(initial, |d| d.wrap_incr(tcx));
         prev_discr = Some(
-            if let Some(ref e) = variant.disr_expr {
-                let expr_did = tcx.hir().local_def_id(e.hir_id);
-                def.eval_explicit_discr(tcx, expr_did.to_def_id())
+            if let Some(ref disr_expr) = variant.disr_expr {
+                let discr =
+                    tcx.variant_discriminant(tcx.hir().local_def_id(variant.id).to_def_id());
+                if let Some(discr) = discr
+                    && let Some((span, magnitude)) =
+                        discriminant_literal_underflow(tcx, initial.ty, disr_expr)
+                {
+                    tcx.sess.emit_err(EnumDiscriminantOverflow {
+                        span,
+                        kind: DiscriminantOverflowKind::Underflow {
+                            span,
+                            value: format!("-{}", magnitude),
+                        },
+                        _note: (),
+                        overflown_discriminant: variant.ident,
+                        wrapped_value: discr.to_string(),
+                        repr_ty: initial.ty.to_string(),
+                        min: min.to_string(),
+                        max: max.to_string(),
+                        _range_note: (),
+                    });
+                }
+                discr
             } else if let Some(discr) = repr_type.disr_incr(tcx, prev_discr) {
                 Some(discr)
             } else {
                 tcx.sess.emit_err(EnumDiscriminantOverflow {
                     span: variant.span,
-                    last_good_discriminant: prev_discr.unwrap().to_string(),
+                    kind: DiscriminantOverflowKind::Overflow {
+                        span: variant.span,
+                        last_good_discriminant: prev_discr.unwrap().to_string(),
+                    },
                     _note: (),
                     overflown_discriminant: variant.ident,
                     wrapped_value: wrapped_discr.to_string(),
+                    repr_ty: initial.ty.to_string(),
+                    min: min.to_string(),
+                    max: max.to_string(),
+                    _range_note: (),
                 });
 
                 None
@@ -1099,6 +1132,38 @@ fn adt_def<'tcx>(tcx: TyCtxt<'tcx>, def_id: DefId) -> ty::AdtDef<'tcx> {
     tcx.alloc_adt_def(def_id.to_def_id(), kind, variants, repr)
 }
 
+/// Returns the span and the magnitude of an explicit discriminant written as a negated integer
+/// literal below the minimum of the signed integer type `ty`, which would otherwise silently
+/// wrap around.
+fn discriminant_literal_underflow<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
+    disr_expr: &hir::AnonConst,
+) -> Option<(Span, u128)> {
+    let ty::Int(ity) = *ty.kind() else { return None };
+    let expr = &tcx.hir().body(disr_expr.body).value;
+    let hir::ExprKind::Unary(hir::UnOp::Neg, operand) = expr.kind else { return None };
+    let hir::ExprKind::Lit(ref lit) = operand.kind else { return None };
+    let ast::LitKind::Int(magnitude, _) = lit.node else { return None };
+    let min = Integer::from_int_ty(&tcx, ity).size().signed_int_min();
+    (magnitude > min.unsigned_abs()).then_some((expr.span, magnitude))
+}
+
+/// Computes the discriminant of a single enum variant. Only the closest explicit discriminant
+/// expression at or before the variant is evaluated, so changing another one doesn't
+/// invalidate this result.
+fn variant_discriminant<'tcx>(tcx: TyCtxt<'tcx>, variant_did: DefId) -> Option<Discr<'tcx>> {
+    let def = tcx.adt_def(tcx.parent(variant_did));
+    let variant_index = def.variant_index_with_id(variant_did);
+    let (expr_did, offset) = def.discriminant_def_for_variant(variant_index);
+    let base = match expr_did {
+        Some(expr_did) => def.eval_explicit_discr(tcx, expr_did)?,
+        None => def.repr().discr_type().initial_discriminant(tcx),
+    };
+    let (discr, overflowed) = base.checked_add(tcx, offset as u128);
+    if overflowed { None } else { Some(discr) }
+}
+
 /// Ensures that the super-predicates of the trait with a `DefId`
 /// of `trait_def_id` are converted and stored. This also ensures that
 /// the transitive super-predicates are converted.
@@ -1311,6 +1376,8 @@ fn trait_def(tcx: TyCtxt<'_>, def_id: DefId) -> ty::TraitDef {
             no_dups.then_some(list)
         });
 
+    let deny_explicit_impl = tcx.has_attr(def_id, sym::rustc_deny_explicit_impl);
+
     ty::TraitDef::new(
         def_id,
         unsafety,
@@ -1320,6 +1387,7 @@ fn trait_def(tcx: TyCtxt<'_>, def_id: DefId) -> ty::TraitDef {
         skip_array_during_method_dispatch,
         spec_kind,
         must_implement_one_of,
+        deny_explicit_impl,
     )
 }
 
@@ -1838,8 +1906,37 @@ fn fn_sig(tcx: TyCtxt<'_>, def_id: DefId) -> ty::PolyFnSig<'_> {
             kind: TraitItemKind::Fn(sig, TraitFn::Provided(_)),
             generics,
             ..
-        })
-        | Item(hir::Item { kind: ItemKind::Fn(sig, generics, _), .. }) => {
+        }) => {
+            let fn_sig = infer_return_ty_for_fn_sig(tcx, sig, generics, def_id, &icx);
+            // A default body can't define the placeholder types that stand in for
+            // return-position `impl Trait`s yet, so reject it here rather than failing to
+            // typeck the body against them.
+            let has_impl_trait_placeholder = fn_sig.skip_binder().output().walk().any(|arg| {
+                if let ty::subst::GenericArgKind::Type(ty) = arg.unpack()
+                    && let ty::Projection(proj) = *ty.kind()
+                {
+                    tcx.def_kind(proj.item_def_id) == DefKind::ImplTraitPlaceholder
+                } else {
+                    false
+                }
+            });
+            if has_impl_trait_placeholder && let hir::FnRetTy::Return(ty) = sig.decl.output {
+                tcx.sess.emit_err(errors::ImplTraitInTraitWithDefaultBody { span: ty.span });
+                fn_sig.map_bound(|fn_sig| {
+                    tcx.mk_fn_sig(
+                        fn_sig.inputs().iter(),
+                        tcx.ty_error(),
+                        fn_sig.c_variadic,
+                        fn_sig.unsafety,
+                        fn_sig.abi,
+                    )
+                })
+            } else {
+                fn_sig
+            }
+        }
+
+        Item(hir::Item { kind: ItemKind::Fn(sig, generics, _), .. }) => {
             infer_return_ty_for_fn_sig(tcx, sig, generics, def_id, &icx)
         }
 
@@ -2868,8 +2965,25 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, did: DefId) -> CodegenFnAttrs {
         } else if attr.has_name(sym::thread_local) {
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::THREAD_LOCAL;
         } else if attr.has_name(sym::track_caller) {
-            if !tcx.is_closure(did.to_def_id()) && tcx.fn_sig(did).abi() != abi::Abi::Rust {
-                tcx.sess.emit_err(TrackCallerRequiresCAbi { span: attr.span });
+            let fn_abi = (!tcx.is_closure(did.to_def_id())).then(|| tcx.fn_sig(did).abi());
+            if let Some(fn_abi) = fn_abi && fn_abi != abi::Abi::Rust {
+                let hir_id = tcx.hir().local_def_id_to_hir_id(did);
+                // Naked functions rely on their ABI, so don't suggest changing it.
+                let extern_span = if tcx.has_attr(did.to_def_id(), sym::naked) {
+                    None
+                } else {
+                    tcx.hir()
+                        .fn_sig_by_hir_id(hir_id)
+                        .and_then(|fn_sig| crate::extern_qualifier_span(tcx, fn_sig.span))
+                };
+                tcx.sess.emit_err(TrackCallerRequiresCAbi {
+                    span: attr.span,
+                    abi: fn_abi.name(),
+                    extern_span,
+                    // Foreign and naked functions can't change their ABI, so suggest removing the
+                    // attribute instead.
+                    attr_span: extern_span.is_none().then_some(attr.span),
+                });
             }
             if tcx.is_closure(did.to_def_id()) && !tcx.features().closure_track_caller {
                 feature_err(
@@ -3325,23 +3439,25 @@ fn check_link_name_xor_ordinal(
     }
 }
 
-/// Checks the function annotated with `#[target_feature]` is not a safe
-/// trait method implementation, reporting an error if it is.
+/// Checks the function annotated with `#[target_feature]` is neither a safe trait
+/// method with a default body nor a safe trait method implementation, reporting
+/// an error if it is.
 fn check_target_feature_trait_unsafe(tcx: TyCtxt<'_>, id: LocalDefId, attr_span: Span) {
-    let hir_id = tcx.hir().local_def_id_to_hir_id(id);
-    let node = tcx.hir().get(hir_id);
-    if let Node::ImplItem(hir::ImplItem { kind: hir::ImplItemKind::Fn(..), .. }) = node {
-        let parent_id = tcx.hir().get_parent_item(hir_id);
-        let parent_item = tcx.hir().expect_item(parent_id);
-        if let hir::ItemKind::Impl(hir::Impl { of_trait: Some(_), .. }) = parent_item.kind {
-            tcx.sess
-                .struct_span_err(
-                    attr_span,
-                    "`#[target_feature(..)]` cannot be applied to safe trait method",
-                )
-                .span_label(attr_span, "cannot be applied to safe trait method")
-                .span_label(tcx.def_span(id), "not an `unsafe` function")
-                .emit();
+    // Callers going through the trait can't know about the target features of a particular
+    // implementation, and neither can callers of a default body that an impl doesn't override.
+    let trait_method_span = match tcx.hir().get_by_def_id(id) {
+        Node::TraitItem(hir::TraitItem { kind: hir::TraitItemKind::Fn(..), .. }) => None,
+        Node::ImplItem(hir::ImplItem { kind: hir::ImplItemKind::Fn(..), .. }) => {
+            let Some(trait_item_def_id) = tcx.associated_item(id).trait_item_def_id else {
+                return;
+            };
+            Some(tcx.def_span(trait_item_def_id))
         }
-    }
+        _ => return,
+    };
+    tcx.sess.emit_err(errors::TargetFeatureOnSafeTraitMethod {
+        attr_span,
+        def_span: tcx.def_span(id),
+        trait_method_span,
+    });
 }
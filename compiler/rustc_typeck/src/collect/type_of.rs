@@ -338,8 +338,12 @@ pub(super) fn type_of(tcx: TyCtxt<'_>, def_id: DefId) -> Ty<'_> {
                     find_opaque_ty_constraints_for_tait(tcx, def_id)
                 }
                 // Opaque types desugared from `impl Trait`.
-                ItemKind::OpaqueTy(OpaqueTy { origin: hir::OpaqueTyOrigin::FnReturn(owner) | hir::OpaqueTyOrigin::AsyncFn(owner), .. }) => {
-                    find_opaque_ty_constraints_for_rpit(tcx, def_id, owner)
+                ItemKind::OpaqueTy(OpaqueTy { origin: hir::OpaqueTyOrigin::FnReturn(owner) | hir::OpaqueTyOrigin::AsyncFn(owner), in_trait, .. }) => {
+                    if in_trait {
+                        tcx.ty_error_with_message(item.span, "impl-trait in trait has no default")
+                    } else {
+                        find_opaque_ty_constraints_for_rpit(tcx, def_id, owner)
+                    }
                 }
                 ItemKind::Trait(..)
                 | ItemKind::TraitAlias(..)
@@ -508,6 +512,10 @@ struct ConstraintLocator<'tcx> {
         /// checked against it (we also carry the span of that first
         /// type).
         found: Option<ty::OpaqueHiddenType<'tcx>>,
+
+        /// Defining uses whose hidden type differs from `found`. These are
+        /// reported together once all defining uses have been visited.
+        conflicts: Vec<ty::OpaqueHiddenType<'tcx>>,
     }
 
     impl ConstraintLocator<'_> {
@@ -545,7 +553,7 @@ fn check(&mut self, item_def_id: LocalDefId) {
                 debug!(?concrete_type, "found constraint");
                 if let Some(prev) = self.found {
                     if concrete_type.ty != prev.ty && !(concrete_type, prev).references_error() {
-                        prev.report_mismatch(&concrete_type, self.tcx);
+                        self.conflicts.push(concrete_type);
                     }
                 } else {
                     self.found = Some(concrete_type);
@@ -592,7 +600,7 @@ fn visit_trait_item(&mut self, it: &'tcx TraitItem<'tcx>) {
 
     let hir_id = tcx.hir().local_def_id_to_hir_id(def_id);
     let scope = tcx.hir().get_defining_scope(hir_id);
-    let mut locator = ConstraintLocator { def_id: def_id, tcx, found: None };
+    let mut locator = ConstraintLocator { def_id: def_id, tcx, found: None, conflicts: vec![] };
 
     debug!(?scope);
 
@@ -623,7 +631,12 @@ fn visit_trait_item(&mut self, it: &'tcx TraitItem<'tcx>) {
     }
 
     match locator.found {
-        Some(hidden) => hidden.ty,
+        Some(hidden) => {
+            if !hidden.ty.references_error() {
+                hidden.report_mismatches(&locator.conflicts, tcx);
+            }
+            hidden.ty
+        }
         None => {
             tcx.sess.emit_err(UnconstrainedOpaqueType {
                 span: tcx.def_span(def_id),
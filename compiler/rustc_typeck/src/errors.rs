@@ -1,5 +1,10 @@
 //! Errors emitted by typeck.
-use rustc_errors::{error_code, Applicability, DiagnosticBuilder, DiagnosticId, ErrorGuaranteed};
+use rustc_errors::{
+    error_code, Applicability, DecorateLint, DiagnosticBuilder, DiagnosticId, ErrorGuaranteed,
+    LintDiagnosticBuilder,
+};
+use rustc_hir as hir;
+use rustc_infer::traits::error_reporting::implicit_sized::ImplicitSizedBound;
 use rustc_macros::{LintDiagnostic, SessionDiagnostic, SessionSubdiagnostic};
 use rustc_middle::ty::Ty;
 use rustc_session::{parse::ParseSess, SessionDiagnostic};
@@ -25,6 +30,23 @@ pub struct UnrecognizedAtomicOperation<'a> {
     pub op: &'a str,
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(typeck::unrecognized_atomic_ordering, code = "E0092")]
+#[note]
+pub struct UnrecognizedAtomicOrdering<'a> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub name: &'a str,
+    pub op: &'a str,
+    pub ordering: &'a str,
+    pub kind: &'static str,
+    pub valid_orderings: String,
+    #[suggestion_verbose(code = "{suggested_name}", applicability = "maybe-incorrect")]
+    pub name_span: Option<Span>,
+    pub suggested_name: String,
+}
+
 #[derive(SessionDiagnostic)]
 #[diag(typeck::wrong_number_of_generic_arguments_to_intrinsic, code = "E0094")]
 pub struct WrongNumberOfGenericArgumentsToIntrinsic<'a> {
@@ -55,6 +77,22 @@ pub struct LifetimesOrBoundsMismatchOnTrait {
     pub generics_span: Option<Span>,
     pub item_kind: &'static str,
     pub ident: Ident,
+    #[subdiagnostic]
+    pub bound_mismatches: Vec<LifetimeBoundMismatch>,
+}
+
+#[derive(SessionSubdiagnostic)]
+pub enum LifetimeBoundMismatch {
+    #[label(typeck::bound_only_on_trait)]
+    OnlyOnTrait {
+        #[primary_span]
+        span: Span,
+    },
+    #[label(typeck::bound_only_on_impl)]
+    OnlyOnImpl {
+        #[primary_span]
+        span: Span,
+    },
 }
 
 #[derive(SessionDiagnostic)]
@@ -108,19 +146,106 @@ pub struct TraitObjectDeclaredWithNoTraits {
     pub trait_alias_span: Option<Span>,
 }
 
-#[derive(SessionDiagnostic)]
-#[diag(typeck::ambiguous_lifetime_bound, code = "E0227")]
-pub struct AmbiguousLifetimeBound {
+#[derive(SessionSubdiagnostic)]
+#[note(typeck::object_safe_principal_candidates)]
+pub struct ObjectSafePrincipalCandidates {
+    pub traits: String,
+    pub count: usize,
+}
+
+#[derive(SessionSubdiagnostic)]
+#[note(typeck::principal_trait_candidates)]
+pub struct PrincipalTraitCandidates {
+    pub traits: String,
+    pub count: usize,
+}
+
+#[derive(SessionSubdiagnostic)]
+#[suggestion_verbose(typeck::remove_non_auto_trait, code = "", applicability = "maybe-incorrect")]
+pub struct RemoveNonAutoTrait {
     #[primary_span]
     pub span: Span,
+    pub trait_name: Symbol,
+}
+
+pub struct AmbiguousLifetimeBound {
+    pub span: Span,
+    pub lifetimes: Vec<String>,
+}
+
+// Manual implementation of `SessionDiagnostic` to be able to offer one suggestion per lifetime.
+impl<'a> SessionDiagnostic<'a> for AmbiguousLifetimeBound {
+    fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuaranteed> {
+        let mut err = sess.span_diagnostic.struct_span_err_with_code(
+            self.span,
+            rustc_errors::fluent::typeck::ambiguous_lifetime_bound,
+            error_code!(E0227),
+        );
+        err.set_arg(
+            "lifetimes",
+            self.lifetimes.iter().map(|lt| format!("`{lt}`")).collect::<Vec<_>>().join(", "),
+        );
+        err.note(rustc_errors::fluent::typeck::note);
+        err.span_suggestions(
+            self.span.shrink_to_hi(),
+            rustc_errors::fluent::typeck::suggestion,
+            self.lifetimes.iter().map(|lt| format!(" + {lt}")),
+            Applicability::MaybeIncorrect,
+        );
+        err
+    }
 }
 
-#[derive(SessionDiagnostic)]
-#[diag(typeck::assoc_type_binding_not_allowed, code = "E0229")]
 pub struct AssocTypeBindingNotAllowed {
-    #[primary_span]
-    #[label]
     pub span: Span,
+    /// Removes the binding and adds an equivalent predicate to the `where` clause of the
+    /// enclosing item, if the binding can be written there.
+    pub where_clause_suggestion: Option<Vec<(Span, String)>>,
+}
+
+// Manual implementation of `SessionDiagnostic` to be able to offer a multipart suggestion.
+impl<'a> SessionDiagnostic<'a> for AssocTypeBindingNotAllowed {
+    fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuaranteed> {
+        let mut err = sess.span_diagnostic.struct_span_err_with_code(
+            self.span,
+            rustc_errors::fluent::typeck::assoc_type_binding_not_allowed,
+            error_code!(E0229),
+        );
+        err.span_label(self.span, rustc_errors::fluent::typeck::label);
+        if let Some(suggestion) = self.where_clause_suggestion {
+            err.multipart_suggestion(
+                rustc_errors::fluent::typeck::suggestion,
+                suggestion,
+                Applicability::MaybeIncorrect,
+            );
+        }
+        err
+    }
+}
+
+pub struct UnusedWhereClauseLifetime {
+    pub name: Ident,
+    pub predicate_spans: Vec<Span>,
+    /// Removes the lifetime parameter and the predicates mentioning it.
+    pub removals: Vec<Span>,
+}
+
+// Manual implementation of `DecorateLint` to be able to offer a multipart suggestion.
+impl<'a> DecorateLint<'a, ()> for UnusedWhereClauseLifetime {
+    fn decorate_lint(self, diag: LintDiagnosticBuilder<'a, ()>) {
+        let mut diag = diag.build(rustc_errors::fluent::typeck::unused_where_clause_lifetime);
+        diag.set_arg("name", self.name);
+        for span in self.predicate_spans {
+            diag.span_label(span, rustc_errors::fluent::typeck::label);
+        }
+        diag.note(rustc_errors::fluent::typeck::note);
+        diag.multipart_suggestion(
+            rustc_errors::fluent::typeck::suggestion,
+            self.removals.into_iter().map(|span| (span, String::new())).collect(),
+            Applicability::MaybeIncorrect,
+        );
+        diag.emit();
+    }
 }
 
 #[derive(SessionDiagnostic)]
@@ -215,6 +340,12 @@ pub enum AddReturnTypeSuggestion<'tcx> {
         #[primary_span]
         span: Span,
     },
+    #[help(typeck::add_return_type_async_block)]
+    AsyncBlock {
+        #[primary_span]
+        span: Span,
+        found: Ty<'tcx>,
+    },
 }
 
 #[derive(SessionSubdiagnostic)]
@@ -245,6 +376,9 @@ pub struct MissingTypeParams {
     pub span: Span,
     pub def_span: Span,
     pub missing_type_params: Vec<Symbol>,
+    /// The generic arguments to suggest: the missing parameters, along with the defaults of
+    /// the parameters preceding them. Empty if they couldn't be computed.
+    pub suggested_args: Vec<String>,
     pub empty_generic_args: bool,
 }
 
@@ -281,19 +415,17 @@ fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuar
                 // aware of the syntax, we do nothing.
             } else {
                 // The user wrote `Iterator`, so we don't have a type we can suggest, but at
-                // least we can clue them to the correct syntax `Iterator<Type>`.
+                // least we can clue them to the correct syntax `Iterator<Type>`, filling in the
+                // defaults of any preceding parameters so that the arguments line up.
+                let args = if self.suggested_args.is_empty() {
+                    self.missing_type_params.iter().map(|n| n.to_string()).collect()
+                } else {
+                    self.suggested_args
+                };
                 err.span_suggestion(
                     self.span,
                     rustc_errors::fluent::typeck::suggestion,
-                    format!(
-                        "{}<{}>",
-                        snippet,
-                        self.missing_type_params
-                            .iter()
-                            .map(|n| n.to_string())
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    ),
+                    format!("{}<{}>", snippet, args.join(", ")),
                     Applicability::HasPlaceholders,
                 );
                 suggested = true;
@@ -332,6 +464,17 @@ pub struct UnusedExternCrate {
     pub span: Span,
 }
 
+#[derive(LintDiagnostic)]
+#[diag(typeck::unreachable_drop_impl)]
+#[note]
+pub struct UnreachableDropImpl<'tcx> {
+    #[label]
+    pub bound_span: Span,
+    pub bound: String,
+    pub trait_name: String,
+    pub self_ty: Ty<'tcx>,
+}
+
 #[derive(LintDiagnostic)]
 #[diag(typeck::extern_crate_not_idiomatic)]
 pub struct ExternCrateNotIdiomatic {
@@ -365,17 +508,28 @@ pub struct AttributeRequiresUnsafeKeyword<'a> {
     pub attr_name: &'a str,
 }
 
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 pub enum UnconstrainedParameterType {
     Type,
     Lifetime,
     Const,
 }
 
+pub enum UnconstrainedParamFix {
+    /// The parameter is not mentioned anywhere in the impl, so it can be removed.
+    Remove(Span),
+    /// The parameter could be passed as a generic argument to the self type or to the trait.
+    AddToHeader(Vec<Vec<(Span, String)>>),
+    /// The parameter could be added to the definition of the self type, which then uses it in
+    /// a `PhantomData` field.
+    AddPhantomData { self_ty: Symbol, parts: Vec<(Span, String)> },
+}
+
 pub struct TypeParameterNotConstrainedForImpl {
     pub span: Span,
     pub kind: UnconstrainedParameterType,
     pub name: Symbol,
+    pub fixes: Vec<UnconstrainedParamFix>,
 }
 
 impl<'a> SessionDiagnostic<'a> for TypeParameterNotConstrainedForImpl {
@@ -402,6 +556,34 @@ fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuar
             err.note(rustc_errors::fluent::typeck::second_note);
         }
 
+        for fix in self.fixes {
+            match fix {
+                UnconstrainedParamFix::Remove(span) => {
+                    err.span_suggestion_verbose(
+                        span,
+                        rustc_errors::fluent::typeck::remove_param_suggestion,
+                        "",
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                UnconstrainedParamFix::AddToHeader(suggestions) => {
+                    err.multipart_suggestions(
+                        rustc_errors::fluent::typeck::add_param_suggestion,
+                        suggestions.into_iter(),
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                UnconstrainedParamFix::AddPhantomData { self_ty, parts } => {
+                    err.set_arg("self_ty", self_ty);
+                    err.multipart_suggestion(
+                        rustc_errors::fluent::typeck::phantom_data_suggestion,
+                        parts,
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+            }
+        }
+
         err
     }
 }
@@ -459,13 +641,36 @@ pub struct AssociatedTypeNotDefinedInTrait<'a> {
 #[diag(typeck::enum_discriminant_overflow, code = "E0370")]
 pub struct EnumDiscriminantOverflow {
     #[primary_span]
-    #[label]
     pub span: Span,
-    pub last_good_discriminant: String,
+    #[subdiagnostic]
+    pub kind: DiscriminantOverflowKind,
     pub overflown_discriminant: Ident,
     pub wrapped_value: String,
     #[note]
     pub _note: (),
+    pub repr_ty: String,
+    pub min: String,
+    pub max: String,
+    #[note(typeck::range_note)]
+    pub _range_note: (),
+}
+
+#[derive(SessionSubdiagnostic)]
+pub enum DiscriminantOverflowKind {
+    /// An implicit discriminant went past the maximum of the repr.
+    #[label(typeck::discriminant_overflowed_after)]
+    Overflow {
+        #[primary_span]
+        span: Span,
+        last_good_discriminant: String,
+    },
+    /// An explicit negative discriminant is below the minimum of a signed repr.
+    #[label(typeck::discriminant_underflowed)]
+    Underflow {
+        #[primary_span]
+        span: Span,
+        value: String,
+    },
 }
 
 #[derive(SessionDiagnostic)]
@@ -518,11 +723,41 @@ pub struct CMSENonSecureEntryRequiresTrustZoneMExt {
     pub span: Span,
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(typeck::cmse_nonsecure_call_inputs_too_large)]
+#[note]
+pub struct CmseNonSecureCallInputsTooLarge<'tcx> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    pub size: u64,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::cmse_nonsecure_call_output_too_large)]
+#[note]
+pub struct CmseNonSecureCallOutputTooLarge<'tcx> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    pub size: u64,
+}
+
 #[derive(SessionDiagnostic)]
 #[diag(typeck::track_caller_requires_cabi, code = "E0737")]
+#[note]
 pub struct TrackCallerRequiresCAbi {
     #[primary_span]
     pub span: Span,
+    pub abi: &'static str,
+    /// Removes the `extern "ABI"` qualifier of the function.
+    #[suggestion_verbose(code = "", applicability = "maybe-incorrect")]
+    pub extern_span: Option<Span>,
+    /// Removes the attribute when the ABI can't be changed.
+    #[suggestion(typeck::remove_attr_suggestion, code = "", applicability = "maybe-incorrect")]
+    pub attr_span: Option<Span>,
 }
 
 #[derive(SessionDiagnostic)]
@@ -545,6 +780,9 @@ pub struct VarargsOnNonCabiFunction {
     #[primary_span]
     #[label]
     pub span: Span,
+    #[suggestion_verbose(code = "{suggested_abi}")]
+    pub abi_suggestion: Option<(Span, Applicability)>,
+    pub suggested_abi: String,
 }
 
 #[derive(SessionDiagnostic)]
@@ -574,6 +812,18 @@ pub struct AsyncMainFunction {
     pub asyncness_span: Option<Span>,
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(typeck::non_rust_abi_on_main_function, code = "E0580")]
+pub struct NonRustAbiOnMainFunction {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub abi: &'static str,
+    /// Removes the `extern "ABI"` qualifier of `main`.
+    #[suggestion_verbose(code = "", applicability = "machine-applicable")]
+    pub extern_span: Option<Span>,
+}
+
 #[derive(SessionDiagnostic)]
 #[diag(typeck::generic_return_type_on_main, code = "E0131")]
 pub struct GenericReturnTypeOnMain {
@@ -581,12 +831,29 @@ pub struct GenericReturnTypeOnMain {
     pub span: Span,
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(typeck::main_return_type_not_termination, code = "E0277")]
+#[help]
+pub struct MainReturnTypeNotTermination<'tcx> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub return_ty: Ty<'tcx>,
+    /// Set if the return type only fails to implement `Termination` because of one of the
+    /// bounds of its impl, which is `unsatisfied_bound`.
+    #[note(typeck::unsatisfied_bound_note)]
+    pub unsatisfied_bound_note: Option<()>,
+    pub unsatisfied_bound: String,
+}
+
 #[derive(SessionDiagnostic)]
 #[diag(typeck::type_parameter_on_start_function, code = "E0132")]
 pub struct TypeParameterOnStartFunction {
     #[primary_span]
     #[label]
     pub span: Span,
+    #[subdiagnostic]
+    pub signature_sugg: StartFunctionSignature,
 }
 
 #[derive(SessionDiagnostic)]
@@ -596,6 +863,20 @@ pub struct WhereClauseOnStartFunction {
     pub span: Span,
     #[label]
     pub where_clause_span: Span,
+    #[subdiagnostic]
+    pub signature_sugg: StartFunctionSignature,
+}
+
+/// Replaces everything after the name of a start function with the signature it must have.
+#[derive(Clone, Copy, SessionSubdiagnostic)]
+#[suggestion_verbose(
+    typeck::start_function_signature,
+    code = "(argc: isize, argv: *const *const u8) -> isize",
+    applicability = "has-placeholders"
+)]
+pub struct StartFunctionSignature {
+    #[primary_span]
+    pub span: Span,
 }
 
 #[derive(SessionDiagnostic)]
@@ -606,38 +887,74 @@ pub struct AsyncStartFunction {
     pub span: Span,
 }
 
-#[derive(SessionSubdiagnostic)]
 pub enum AmbiguousAssociatedTypeFixSuggestion<'a> {
-    #[suggestion(
-        typeck::fix_std_module_text,
-        code = "std::",
-        applicability = "machine-applicable"
-    )]
-    StdModule {
-        #[primary_span]
-        span: Span,
-    },
-    #[suggestion(
-        typeck::fix_use_fully_qualified_syntax,
-        code = "<{type_str} as {trait_str}>::{name}",
-        applicability = "has-placeholders"
-    )]
+    StdModule { span: Span },
     UseFullyQualifiedSyntax {
-        #[primary_span]
         span: Span,
-        type_str: &'a str,
-        trait_str: &'a str,
+        /// `None` if there is no self type to suggest, in which case a placeholder is used.
+        type_str: Option<&'a str>,
+        /// The traits defining the associated type, or a placeholder if this is empty.
+        trait_strs: Vec<String>,
+        /// Whether some of `trait_strs` contain placeholders for the generics of the trait.
+        has_placeholders: bool,
         name: Symbol,
     },
 }
 
-#[derive(SessionDiagnostic)]
-#[diag(typeck::ambiguous_associated_type, code = "E0223")]
 pub struct AmbiguousAssociatedType<'a> {
-    #[primary_span]
     pub span: Span,
-    #[subdiagnostic]
     pub possible_fix: AmbiguousAssociatedTypeFixSuggestion<'a>,
+    pub implicit_sized: Option<ImplicitSizedBound>,
+}
+
+// Manual implementation of `SessionDiagnostic` to be able to offer one suggestion per trait.
+impl<'a, 'b> SessionDiagnostic<'a> for AmbiguousAssociatedType<'b> {
+    fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuaranteed> {
+        let mut err = sess.span_diagnostic.struct_span_err_with_code(
+            self.span,
+            rustc_errors::fluent::typeck::ambiguous_associated_type,
+            error_code!(E0223),
+        );
+        match self.possible_fix {
+            AmbiguousAssociatedTypeFixSuggestion::StdModule { span } => {
+                err.span_suggestion(
+                    span,
+                    rustc_errors::fluent::typeck::fix_std_module_text,
+                    "std::",
+                    Applicability::MachineApplicable,
+                );
+            }
+            AmbiguousAssociatedTypeFixSuggestion::UseFullyQualifiedSyntax {
+                span,
+                type_str,
+                trait_strs,
+                has_placeholders,
+                name,
+            } => {
+                let applicability =
+                    if type_str.is_none() || trait_strs.is_empty() || has_placeholders {
+                        Applicability::HasPlaceholders
+                    } else {
+                        Applicability::MaybeIncorrect
+                    };
+                let type_str = type_str.unwrap_or("Type");
+                let trait_strs =
+                    if trait_strs.is_empty() { vec!["Trait".to_string()] } else { trait_strs };
+                err.span_suggestions(
+                    span,
+                    rustc_errors::fluent::typeck::fix_use_fully_qualified_syntax,
+                    trait_strs
+                        .iter()
+                        .map(|trait_str| format!("<{type_str} as {trait_str}>::{name}")),
+                    applicability,
+                );
+            }
+        }
+        if let Some(implicit_sized) = self.implicit_sized {
+            err.subdiagnostic(implicit_sized);
+        }
+        err
+    }
 }
 
 #[derive(SessionSubdiagnostic)]
@@ -683,9 +1000,13 @@ pub struct ExpectedUsedSymbol {
 pub enum InvalidDispatchFromDynDeclarationType {
     TypesDifferTooMuch { source_path: String, target_path: String },
     InvalidRepr,
-    InvalidFields { field_name: Symbol, ty_a: String },
+    InvalidFields { field_span: Span, field_name: String, ty_a: String },
     NoCoercedFields,
-    TooManyCoercedFields { coerced_fields_len: usize, coerced_fields: String },
+    TooManyCoercedFields {
+        field_spans: Vec<Span>,
+        coerced_fields_len: usize,
+        coerced_fields: String,
+    },
     NotAStruct,
 }
 
@@ -729,19 +1050,28 @@ fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuar
                 err.set_arg("source_path", source_path);
                 err.set_arg("target_path", target_path);
             }
-            InvalidDispatchFromDynDeclarationType::InvalidFields { field_name, ty_a } => {
+            InvalidDispatchFromDynDeclarationType::InvalidFields {
+                field_span,
+                field_name,
+                ty_a,
+            } => {
                 err.set_arg("field_name", field_name);
                 err.set_arg("ty_a", ty_a);
 
-                err.note(rustc_errors::fluent::typeck::note);
+                err.span_note(field_span, rustc_errors::fluent::typeck::note);
             }
             InvalidDispatchFromDynDeclarationType::TooManyCoercedFields {
+                field_spans,
                 coerced_fields_len,
                 coerced_fields,
             } => {
                 err.set_arg("coerced_fields_len", coerced_fields_len);
                 err.set_arg("coerced_fields", coerced_fields);
 
+                for field_span in field_spans {
+                    err.span_label(field_span, rustc_errors::fluent::typeck::coerced_field_label);
+                }
+
                 err.note(rustc_errors::fluent::typeck::note);
                 err.note(rustc_errors::fluent::typeck::fields_that_need_coercions_fields);
             }
@@ -752,24 +1082,91 @@ fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuar
     }
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(typeck::dispatch_from_dyn_field_implicitly_sized, code = "E0378")]
+pub struct DispatchFromDynFieldImplicitlySized<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub field_ty: Ty<'tcx>,
+    pub target_ty: Ty<'tcx>,
+    #[subdiagnostic]
+    pub implicit_sized: ImplicitSizedBound,
+}
+
+pub struct DispatchFromDynThroughPin {
+    pub span: Span,
+    pub pointer_span: Option<Span>,
+    pub pointer: String,
+    pub pointer_target: String,
+    pub suggestion: Option<Vec<(Span, String)>>,
+}
+
+impl<'a> SessionDiagnostic<'a> for DispatchFromDynThroughPin {
+    fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuaranteed> {
+        let mut err = sess.span_diagnostic.struct_span_err_with_code(
+            self.span,
+            rustc_errors::fluent::typeck::dispatch_from_dyn_through_pin,
+            error_code!(E0378),
+        );
+        err.set_arg("pointer", self.pointer);
+        err.set_arg("pointer_target", self.pointer_target);
+
+        if let Some(pointer_span) = self.pointer_span {
+            err.span_label(pointer_span, rustc_errors::fluent::typeck::label);
+        }
+        err.note(rustc_errors::fluent::typeck::note);
+        if let Some(suggestion) = self.suggestion {
+            err.multipart_suggestion(
+                rustc_errors::fluent::typeck::suggestion,
+                suggestion,
+                Applicability::MaybeIncorrect,
+            );
+        }
+
+        err
+    }
+}
+
 #[derive(SessionDiagnostic)]
 #[diag(typeck::coerce_unsized_invalid_definition, code = "E0377")]
-pub struct CoerceUnsizedInvalidDefinition {
+#[note]
+pub struct CoerceUnsizedInvalidDefinition<'tcx> {
     #[primary_span]
     pub span: Span,
+    #[label(typeck::source_label)]
+    pub source_span: Span,
+    #[label(typeck::target_label)]
+    pub target_span: Option<Span>,
     pub source_path: String,
     pub target_path: String,
+    pub source: Ty<'tcx>,
+    pub target: Ty<'tcx>,
 }
 
 #[derive(SessionDiagnostic)]
 #[diag(typeck::coerce_unsized_no_coerced_field, code = "E0374")]
-pub struct CoerceUnsizedNoCoercedField {
+pub struct CoerceUnsizedNoCoercedField<'tcx> {
     #[primary_span]
     pub span: Span,
+    #[subdiagnostic]
+    pub unchanged_fields: Option<CoerceUnsizedUnchangedFields<'tcx>>,
+}
+
+/// Explains that the fields of a `CoerceUnsized` struct that could have been coerced keep their
+/// type because the impl instantiates the generic parameters they use the same way in the
+/// source and the target.
+#[derive(SessionSubdiagnostic)]
+#[note(typeck::coerce_unsized_unchanged_fields)]
+pub struct CoerceUnsizedUnchangedFields<'tcx> {
+    pub fields: String,
+    pub fields_len: usize,
+    pub params: String,
+    pub source: Ty<'tcx>,
+    pub target: Ty<'tcx>,
 }
 
 #[derive(SessionDiagnostic)]
-#[diag(typeck::coerce_unsized_no_coerced_field, code = "E0375")]
+#[diag(typeck::coerce_unsized_too_many_coerced_fields, code = "E0375")]
 pub struct CoerceUnsizedTooManyCoercedFields {
     #[primary_span]
     #[label]
@@ -778,10 +1175,36 @@ pub struct CoerceUnsizedTooManyCoercedFields {
     pub _note: (),
     #[note(typeck::fields_that_need_coercions_fields)]
     pub _fields_note: (),
+    #[label(typeck::coerced_field_label)]
+    pub field_spans: Vec<Span>,
     pub coerced_fields_len: usize,
     pub coerced_fields: String,
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(typeck::coerce_unsized_multi_field_mismatch, code = "E0375")]
+#[note]
+pub struct CoerceUnsizedMultiFieldMismatch<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub first_field: String,
+    pub first_source: Ty<'tcx>,
+    pub first_target: Ty<'tcx>,
+    pub field: String,
+    pub source: Ty<'tcx>,
+    pub target: Ty<'tcx>,
+}
+
+#[derive(LintDiagnostic)]
+#[diag(typeck::unreachable_coerce_unsized_impl)]
+#[note]
+pub struct UnreachableCoerceUnsizedImpl<'tcx> {
+    #[label]
+    pub field_span: Span,
+    pub field_name: String,
+    pub self_ty: Ty<'tcx>,
+}
+
 #[derive(SessionDiagnostic)]
 #[diag(typeck::coerce_unsized_not_a_struct, code = "E0376")]
 pub struct CoerceUnsizedNotAStruct {
@@ -811,6 +1234,16 @@ fn into_diagnostic(self, sess: &'a ParseSess) -> DiagnosticBuilder<'a, ErrorGuar
     }
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(typeck::explicit_impl_of_compiler_implemented_trait, code = "E0322")]
+#[note]
+pub struct ExplicitImplOfCompilerImplementedTrait {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub trait_name: Symbol,
+}
+
 #[derive(SessionDiagnostic)]
 #[diag(typeck::marker_trait_impl_contains_items, code = "E0715")]
 pub struct MarkerTraitImplContainsItems {
@@ -834,7 +1267,166 @@ pub struct CrossCrateOptOutTraitImplOnInvalidTarget {
     #[primary_span]
     #[label]
     pub span: Span,
+    #[label(typeck::self_ty_label)]
+    pub self_ty_span: Span,
     pub trait_path: String,
     pub error_type: &'static str,
     pub self_type: String,
 }
+
+#[derive(LintDiagnostic)]
+#[diag(typeck::suspicious_auto_trait_impl)]
+pub struct SuspiciousAutoTraitImpl {
+    #[label]
+    pub arg_span: Span,
+    #[note]
+    pub adt_span: Span,
+    pub trait_path: String,
+    pub arg: String,
+    pub kind: &'static str,
+    pub adt_descr: &'static str,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::auto_trait_impl_default)]
+pub struct AutoTraitImplDefault {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    #[label(typeck::default_label)]
+    pub default_span: Option<Span>,
+    #[suggestion_verbose(code = "", applicability = "machine-applicable")]
+    pub removal_span: Option<Span>,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::missing_gat_bounds)]
+#[note]
+#[note(typeck::feedback_note)]
+pub struct MissingGatBounds {
+    #[primary_span]
+    pub span: Span,
+    #[suggestion(code = "{where_clause}", applicability = "machine-applicable")]
+    pub where_clause_span: Span,
+    pub where_clause: String,
+    pub gat: Ident,
+    pub bounds_len: usize,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::impl_trait_in_trait_bound_not_satisfied, code = "E0277")]
+pub struct ImplTraitInTraitBoundNotSatisfied<'tcx> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    #[label(typeck::bound_label)]
+    pub bound_span: Span,
+    pub hidden_ty: Ty<'tcx>,
+    pub bound: String,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::transparent_non_zero_sized_fields, code = "E0690")]
+#[note]
+pub struct TransparentNonZeroSizedFields {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    #[label(typeck::first_field_label)]
+    pub first_field: Span,
+    #[label(typeck::extra_field_label)]
+    pub extra_fields: Vec<Span>,
+    pub field_count: usize,
+    pub descr: &'static str,
+    pub is_variant: bool,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::non_exhaustive_no_effect, code = "E0701")]
+#[note]
+pub struct NonExhaustiveNoEffect {
+    #[primary_span]
+    #[suggestion(applicability = "machine-applicable", code = "")]
+    pub attr_span: Span,
+    #[label]
+    pub item_span: Span,
+    pub kind: &'static str,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::target_feature_on_safe_trait_method)]
+pub struct TargetFeatureOnSafeTraitMethod {
+    #[primary_span]
+    #[label]
+    pub attr_span: Span,
+    #[label(typeck::def_label)]
+    pub def_span: Span,
+    #[label(typeck::trait_method_label)]
+    pub trait_method_span: Option<Span>,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::conflicting_packed_and_align_repr, code = "E0587")]
+pub struct ConflictingPackedAndAlignRepr {
+    #[primary_span]
+    pub span: Span,
+    #[label(typeck::packed_label)]
+    pub packed_span: Option<Span>,
+    #[label(typeck::align_label)]
+    pub align_span: Option<Span>,
+    /// Removes the `align` hint.
+    #[suggestion_verbose(code = "", applicability = "maybe-incorrect")]
+    pub removal_span: Option<Span>,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::conflicting_packed_repr, code = "E0634")]
+pub struct ConflictingPackedRepr {
+    #[primary_span]
+    pub span: Span,
+    #[label(typeck::first_label)]
+    pub first_span: Option<Span>,
+    #[label]
+    pub conflicting_span: Option<Span>,
+    /// Removes the conflicting `packed` hint.
+    #[suggestion_verbose(code = "", applicability = "maybe-incorrect")]
+    pub removal_span: Option<Span>,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::const_unstable_intrinsic)]
+pub struct ConstUnstableIntrinsic {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub name: Symbol,
+    pub kind: hir::ConstContext,
+    pub feature: Symbol,
+    #[help]
+    pub feature_help: Option<()>,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::deref_pattern_unsupported_scrutinee)]
+#[help]
+pub struct DerefPatternUnsupportedScrutinee<'tcx> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    pub target: String,
+    #[note(typeck::unsupported_target_note)]
+    pub unsupported_target: Option<()>,
+    #[note(typeck::wrong_target_note)]
+    pub wrong_target: Option<()>,
+    #[note(typeck::no_deref_note)]
+    pub no_deref: Option<()>,
+}
+
+#[derive(SessionDiagnostic)]
+#[diag(typeck::impl_trait_in_trait_with_default_body)]
+#[note]
+pub struct ImplTraitInTraitWithDefaultBody {
+    #[primary_span]
+    pub span: Span,
+}
@@ -12,14 +12,18 @@
 use min_specialization::check_min_specialization;
 
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
-use rustc_hir::def::DefKind;
-use rustc_hir::def_id::LocalDefId;
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_middle::hir::nested_filter;
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::{self, TyCtxt, TypeVisitable};
 use rustc_span::{Span, Symbol};
 
 use crate::errors::{
-    AssociatedItemsNotDistinct, TypeParameterNotConstrainedForImpl, UnconstrainedParameterType,
+    AssociatedItemsNotDistinct, TypeParameterNotConstrainedForImpl, UnconstrainedParamFix,
+    UnconstrainedParameterType,
 };
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 
@@ -127,7 +131,8 @@ fn enforce_impl_params_are_constrained(tcx: TyCtxt<'_>, impl_def_id: LocalDefId)
                 if !input_parameters.contains(&cgp::Parameter::from(param_ty)) {
                     report_unused_parameter(
                         tcx,
-                        tcx.def_span(param.def_id),
+                        impl_def_id,
+                        param,
                         UnconstrainedParameterType::Type,
                         param_ty.name,
                     );
@@ -140,7 +145,8 @@ fn enforce_impl_params_are_constrained(tcx: TyCtxt<'_>, impl_def_id: LocalDefId)
                 {
                     report_unused_parameter(
                         tcx,
-                        tcx.def_span(param.def_id),
+                        impl_def_id,
+                        param,
                         UnconstrainedParameterType::Lifetime,
                         param.name,
                     );
@@ -151,7 +157,8 @@ fn enforce_impl_params_are_constrained(tcx: TyCtxt<'_>, impl_def_id: LocalDefId)
                 if !input_parameters.contains(&cgp::Parameter::from(param_ct)) {
                     report_unused_parameter(
                         tcx,
-                        tcx.def_span(param.def_id),
+                        impl_def_id,
+                        param,
                         UnconstrainedParameterType::Const,
                         param_ct.name,
                     );
@@ -182,11 +189,352 @@ fn enforce_impl_params_are_constrained(tcx: TyCtxt<'_>, impl_def_id: LocalDefId)
 
 fn report_unused_parameter(
     tcx: TyCtxt<'_>,
-    span: Span,
+    impl_def_id: LocalDefId,
+    param: &ty::GenericParamDef,
     kind: UnconstrainedParameterType,
     name: Symbol,
 ) {
-    tcx.sess.emit_err(TypeParameterNotConstrainedForImpl { span, kind, name });
+    let span = tcx.def_span(param.def_id);
+    let fixes = unconstrained_param_fixes(tcx, impl_def_id, param, kind, name).unwrap_or_default();
+    tcx.sess.emit_err(TypeParameterNotConstrainedForImpl { span, kind, name, fixes });
+}
+
+/// Works out how the user could plausibly fix an unconstrained impl parameter: if it is not
+/// mentioned anywhere in the impl it can simply be removed, otherwise it may be missing from
+/// the generic arguments of the self type or of the implemented trait. If the self type has
+/// no room for it, the parameter may instead be meant to be part of the self type's
+/// definition, e.g. for variance or drop check purposes.
+fn unconstrained_param_fixes(
+    tcx: TyCtxt<'_>,
+    impl_def_id: LocalDefId,
+    param: &ty::GenericParamDef,
+    kind: UnconstrainedParameterType,
+    name: Symbol,
+) -> Option<Vec<UnconstrainedParamFix>> {
+    let hir::Node::Item(hir::Item { kind: hir::ItemKind::Impl(impl_), .. }) =
+        tcx.hir().get_by_def_id(impl_def_id) else { return None };
+    let param_def_id = param.def_id.as_local()?;
+    let generics = impl_.generics;
+    let hir_param = generics
+        .params
+        .iter()
+        .find(|p| tcx.hir().local_def_id(p.hir_id) == param_def_id)?;
+    if hir_param.span.from_expansion() || !generics.span.contains(hir_param.span) {
+        return None;
+    }
+
+    let mut finder = ParamUseFinder { tcx, param_def_id, found: false };
+    finder.visit_ty(impl_.self_ty);
+    if let Some(trait_ref) = &impl_.of_trait {
+        finder.visit_trait_ref(trait_ref);
+    }
+    for predicate in generics.predicates {
+        if !is_inline_bound_of(predicate, param_def_id) {
+            finder.visit_where_predicate(predicate);
+        }
+    }
+    let used_in_header = finder.found;
+    for item in impl_.items {
+        finder.visit_nested_impl_item(item.id);
+    }
+
+    if !finder.found {
+        let span = param_removal_span(tcx, generics, param_def_id)?;
+        return Some(vec![UnconstrainedParamFix::Remove(span)]);
+    }
+
+    let arg = name.to_string();
+    let mut candidates = vec![];
+    let mut phantom_data = None;
+    if let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = impl_.self_ty.kind
+        && let Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union, def_id) = path.res
+    {
+        match generic_arg_insertion(tcx, def_id, path, kind, &arg) {
+            Some(sugg) => candidates.push(sugg),
+            // Only offer this when nothing in the impl header relates the parameter to other
+            // types, as otherwise it is most likely meant to be constrained by those.
+            None if !used_in_header => {
+                phantom_data = phantom_data_insertion(tcx, def_id, path, kind, &arg);
+            }
+            None => {}
+        }
+    }
+    if let Some(trait_ref) = &impl_.of_trait
+        && let Some(def_id) = trait_ref.trait_def_id()
+        && let Some(sugg) = generic_arg_insertion(tcx, def_id, trait_ref.path, kind, &arg)
+    {
+        candidates.push(sugg);
+    }
+
+    let mut fixes = vec![];
+    if !candidates.is_empty() {
+        fixes.push(UnconstrainedParamFix::AddToHeader(candidates));
+    }
+    fixes.extend(phantom_data);
+    Some(fixes)
+}
+
+/// Whether `predicate` is one of the bounds written inline on the declaration of the
+/// parameter, like the `: Default` in `impl<T: Default>`.
+fn is_inline_bound_of(predicate: &hir::WherePredicate<'_>, param_def_id: LocalDefId) -> bool {
+    match predicate {
+        hir::WherePredicate::BoundPredicate(bp) => {
+            bp.origin == hir::PredicateOrigin::GenericParam
+                && bp.is_param_bound(param_def_id.to_def_id())
+        }
+        hir::WherePredicate::RegionPredicate(rp) => {
+            !rp.in_where_clause && rp.is_param_bound(param_def_id)
+        }
+        hir::WherePredicate::EqPredicate(_) => false,
+    }
+}
+
+/// The span covering an explicitly written generic parameter together with its inline
+/// bounds and the comma separating it from its neighbours, or the whole `<...>` list if it
+/// is the only parameter.
+fn param_removal_span(
+    tcx: TyCtxt<'_>,
+    generics: &hir::Generics<'_>,
+    param_def_id: LocalDefId,
+) -> Option<Span> {
+    let mut params: Vec<_> = generics
+        .params
+        .iter()
+        .filter(|p| generics.span.contains(p.span))
+        .map(|p| {
+            let def_id = tcx.hir().local_def_id(p.hir_id);
+            let span = generics
+                .predicates
+                .iter()
+                .filter(|pred| is_inline_bound_of(pred, def_id))
+                .fold(p.span, |span, pred| span.to(pred.span()));
+            (def_id, span)
+        })
+        .collect();
+    params.sort_by_key(|(_, span)| span.lo());
+
+    let pos = params.iter().position(|&(def_id, _)| def_id == param_def_id)?;
+    let span = params[pos].1;
+    if params.len() == 1 {
+        // impl<T> Foo for Bar {}
+        //     ^^^
+        Some(generics.span)
+    } else if let Some(&(_, next)) = params.get(pos + 1) {
+        // impl<T, U> Foo<U> for Bar {}
+        //      ^^^
+        Some(span.until(next))
+    } else {
+        // impl<T, U> Foo<T> for Bar {}
+        //       ^^^
+        Some(params[pos - 1].1.shrink_to_hi().to(span))
+    }
+}
+
+/// Suggests passing `arg` to the last segment of `path` if `def_id` (the item `path` resolves
+/// to) has more parameters of the given kind than the path provides arguments for.
+fn generic_arg_insertion(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+    path: &hir::Path<'_>,
+    kind: UnconstrainedParameterType,
+    arg: &str,
+) -> Option<Vec<(Span, String)>> {
+    let segment = path.segments.last()?;
+    if segment.ident.span.from_expansion() {
+        return None;
+    }
+    let def_generics = tcx.generics_of(def_id);
+    let counts = def_generics.own_counts();
+    let args = segment.args();
+    // Elided lifetimes in the impl header show up as implicit arguments, but those
+    // are exactly the places where a named lifetime could go.
+    let written_args: Vec<_> = match args.span_ext() {
+        Some(span_ext) => args.args.iter().filter(|arg| span_ext.contains(arg.span())).collect(),
+        None => vec![],
+    };
+    let (expected, provided) = match kind {
+        UnconstrainedParameterType::Lifetime => (
+            counts.lifetimes,
+            args.args
+                .iter()
+                .filter(|arg| {
+                    matches!(arg, hir::GenericArg::Lifetime(lt) if !lt.name.is_anonymous())
+                })
+                .count(),
+        ),
+        UnconstrainedParameterType::Type => (
+            counts.types - usize::from(def_generics.has_self),
+            args.args
+                .iter()
+                .filter(|arg| matches!(arg, hir::GenericArg::Type(_) | hir::GenericArg::Infer(_)))
+                .count(),
+        ),
+        UnconstrainedParameterType::Const => (
+            counts.consts,
+            args.args.iter().filter(|arg| matches!(arg, hir::GenericArg::Const(_))).count(),
+        ),
+    };
+    if provided >= expected {
+        return None;
+    }
+
+    let anon_lifetime = written_args.iter().find_map(|arg| match arg {
+        hir::GenericArg::Lifetime(lt) if lt.name.is_anonymous() => Some(lt.span),
+        _ => None,
+    });
+    let sugg = if args.span_ext().is_none() {
+        // Foo<T>
+        //    ^^^
+        (segment.ident.span.shrink_to_hi(), format!("<{arg}>"))
+    } else if kind == UnconstrainedParameterType::Lifetime && let Some(span) = anon_lifetime {
+        // Foo<'_, T>
+        //     ^^ replaced by `'a`
+        (span, arg.to_string())
+    } else if kind == UnconstrainedParameterType::Lifetime || written_args.is_empty() {
+        // Foo<'a, T>
+        //     ^^^^
+        let first = match written_args.first() {
+            Some(arg) => arg.span(),
+            None => args.bindings.first()?.span,
+        };
+        (first.shrink_to_lo(), format!("{arg}, "))
+    } else {
+        // Foo<U, T>
+        //      ^^^
+        (written_args.last()?.span().shrink_to_hi(), format!(", {arg}"))
+    };
+    Some(vec![sugg])
+}
+
+/// Suggests adding `arg` as a new parameter of the local struct `def_id`, used by a new
+/// `PhantomData` field, and passing it to the struct in `path`, the self type of the impl.
+fn phantom_data_insertion(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+    path: &hir::Path<'_>,
+    kind: UnconstrainedParameterType,
+    arg: &str,
+) -> Option<UnconstrainedParamFix> {
+    let local_def_id = def_id.as_local()?;
+    let hir::Node::Item(item) = tcx.hir().get_by_def_id(local_def_id) else { return None };
+    let hir::ItemKind::Struct(variant, generics) = &item.kind else { return None };
+    let segment = path.segments.last()?;
+    let sm = tcx.sess.source_map();
+    if item.span.from_expansion()
+        || segment.ident.span.from_expansion()
+        || sm.span_to_filename(item.span) != sm.span_to_filename(path.span)
+    {
+        return None;
+    }
+
+    let phantom_ty = match kind {
+        UnconstrainedParameterType::Type => format!("std::marker::PhantomData<{arg}>"),
+        UnconstrainedParameterType::Lifetime => format!("std::marker::PhantomData<&{arg} ()>"),
+        UnconstrainedParameterType::Const => return None,
+    };
+    let mut parts = vec![];
+    // Type parameters without a default have to come before the defaulted ones.
+    let first_defaulted = generics.params.iter().position(|param| {
+        matches!(
+            param.kind,
+            hir::GenericParamKind::Type { default: Some(_), .. }
+                | hir::GenericParamKind::Const { default: Some(_), .. }
+        )
+    });
+    // struct Foo<T>(PhantomData<T>);
+    //           ^^^
+    let param = match (generics.params.first(), first_defaulted, kind) {
+        (None, _, _) => (generics.span, format!("<{arg}>")),
+        (Some(first), _, UnconstrainedParameterType::Lifetime) => {
+            (first.span.shrink_to_lo(), format!("{arg}, "))
+        }
+        (Some(_), Some(idx), _) => (generics.params[idx].span.shrink_to_lo(), format!("{arg}, ")),
+        (Some(_), None, _) => (sm.end_point(generics.span).shrink_to_lo(), format!(", {arg}")),
+    };
+    match variant {
+        hir::VariantData::Struct(fields, _) => {
+            let last = fields.last()?;
+            if fields.iter().any(|field| field.ident.name.as_str() == "_marker") {
+                return None;
+            }
+            parts.push(param);
+            parts.push((last.span.shrink_to_hi(), format!(", _marker: {phantom_ty}")));
+        }
+        hir::VariantData::Tuple(fields, _) => {
+            let last = fields.last()?;
+            parts.push(param);
+            parts.push((last.span.shrink_to_hi(), format!(", {phantom_ty}")));
+        }
+        hir::VariantData::Unit(_) => {
+            // Both insertions would go right after the struct name, so merge them.
+            if generics.params.is_empty() {
+                parts.push((param.0, format!("{}({phantom_ty})", param.1)));
+            } else {
+                parts.push(param);
+                parts.push((generics.span.shrink_to_hi(), format!("({phantom_ty})")));
+            }
+        }
+    }
+
+    // impl<T> Trait for Foo<T> {}
+    //                      ^^^
+    let args = segment.args();
+    let written_args: Vec<_> = match args.span_ext() {
+        Some(span_ext) => args.args.iter().filter(|arg| span_ext.contains(arg.span())).collect(),
+        None => vec![],
+    };
+    // The argument goes in the same position among the non-lifetime arguments as the new
+    // parameter does among the non-lifetime parameters.
+    let defaulted_arg = first_defaulted.and_then(|idx| {
+        let position = generics.params[..idx]
+            .iter()
+            .filter(|param| !matches!(param.kind, hir::GenericParamKind::Lifetime { .. }))
+            .count();
+        written_args.iter().filter(|arg| !matches!(arg, hir::GenericArg::Lifetime(_))).nth(position)
+    });
+    parts.push(match (written_args.first(), written_args.last(), defaulted_arg, kind) {
+        (Some(first), _, _, UnconstrainedParameterType::Lifetime) => {
+            (first.span().shrink_to_lo(), format!("{arg}, "))
+        }
+        (_, _, Some(defaulted), _) => (defaulted.span().shrink_to_lo(), format!("{arg}, ")),
+        (_, Some(last), _, _) => (last.span().shrink_to_hi(), format!(", {arg}")),
+        _ => (segment.ident.span.shrink_to_hi(), format!("<{arg}>")),
+    });
+
+    Some(UnconstrainedParamFix::AddPhantomData { self_ty: item.ident.name, parts })
+}
+
+/// Looks for any mention of a generic parameter, including within the bodies of the
+/// items of an impl.
+struct ParamUseFinder<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    param_def_id: LocalDefId,
+    found: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for ParamUseFinder<'tcx> {
+    type NestedFilter = nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.tcx.hir()
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'tcx hir::Lifetime) {
+        if let hir::LifetimeName::Param(def_id, _) = lifetime.name
+            && def_id == self.param_def_id
+        {
+            self.found = true;
+        }
+    }
+
+    fn visit_path(&mut self, path: &'tcx hir::Path<'tcx>, _: hir::HirId) {
+        if let Res::Def(DefKind::TyParam | DefKind::ConstParam, def_id) = path.res
+            && def_id == self.param_def_id.to_def_id()
+        {
+            self.found = true;
+        }
+        intravisit::walk_path(self, path);
+    }
 }
 
 /// Enforce that we do not have two items in an impl with the same name.
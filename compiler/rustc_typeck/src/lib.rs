@@ -99,7 +99,7 @@
 mod structured_errors;
 mod variance;
 
-use rustc_errors::ErrorGuaranteed;
+use rustc_errors::{Applicability, Diagnostic, ErrorGuaranteed};
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_hir::{Node, CRATE_HIR_ID};
@@ -109,7 +109,7 @@
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_middle::util;
 use rustc_session::config::EntryFnType;
-use rustc_span::{symbol::sym, Span, DUMMY_SP};
+use rustc_span::{symbol::sym, BytePos, Span, DUMMY_SP};
 use rustc_target::spec::abi::Abi;
 use rustc_trait_selection::traits::error_reporting::InferCtxtExt as _;
 use rustc_trait_selection::traits::{self, ObligationCause, ObligationCauseCode};
@@ -118,35 +118,92 @@
 
 use crate::errors::{
     AsyncMainFunction, AsyncStartFunction, GenericParamsOnMainFunction, GenericReturnTypeOnMain,
+    MainReturnTypeNotTermination, NonRustAbiOnMainFunction, StartFunctionSignature,
     TypeParameterOnStartFunction, VarargsOnNonCabiFunction, WhenClauseOnMainFunction,
     WhereClauseOnStartFunction,
 };
 use astconv::AstConv;
 use bounds::Bounds;
 
-fn require_c_abi_if_c_variadic(tcx: TyCtxt<'_>, decl: &hir::FnDecl<'_>, abi: Abi, span: Span) {
+/// Checks that a C-variadic function uses a calling convention supporting C-variadics.
+/// `abi_suggestion` is the span of the written ABI string, which is used to suggest a supported
+/// ABI with the given applicability.
+fn require_c_abi_if_c_variadic(
+    tcx: TyCtxt<'_>,
+    decl: &hir::FnDecl<'_>,
+    abi: Abi,
+    span: Span,
+    abi_suggestion: Option<(Span, Applicability)>,
+) {
     match (decl.c_variadic, abi) {
         // The function has the correct calling convention, or isn't a "C-variadic" function.
+        // This includes the unwinding variants, `C-unwind` and `cdecl-unwind`.
         (false, _) | (true, Abi::C { .. }) | (true, Abi::Cdecl { .. }) => {}
         // The function is a "C-variadic" function with an incorrect calling convention.
         (true, _) => {
-            tcx.sess.emit_err(VarargsOnNonCabiFunction { span });
+            let unwind = match abi {
+                Abi::Stdcall { unwind }
+                | Abi::Fastcall { unwind }
+                | Abi::Vectorcall { unwind }
+                | Abi::Thiscall { unwind }
+                | Abi::Aapcs { unwind }
+                | Abi::Win64 { unwind }
+                | Abi::SysV64 { unwind }
+                | Abi::System { unwind } => unwind,
+                _ => false,
+            };
+            let suggested_abi = Abi::C { unwind };
+            tcx.sess.emit_err(VarargsOnNonCabiFunction {
+                span,
+                abi_suggestion,
+                suggested_abi: format!("\"{}\"", suggested_abi.name()),
+            });
         }
     }
 }
 
+/// Returns the span of the `extern "ABI"` qualifier in the function signature `span`, including
+/// the whitespace after it, so that removing it leaves a function with the Rust ABI.
+fn extern_qualifier_span(tcx: TyCtxt<'_>, span: Span) -> Option<Span> {
+    // The header doesn't keep the span of the ABI, so look for `extern "ABI"` in the source.
+    let snippet = tcx.sess.source_map().span_to_snippet(span).ok()?;
+    let start = snippet.find("extern")?;
+    let rest = snippet[start + "extern".len()..].trim_start();
+    let rest = match rest.strip_prefix('"') {
+        Some(abi) => abi.split_once('"')?.1,
+        None => rest,
+    };
+    let end = snippet.len() - rest.trim_start().len();
+    let lo = span.lo();
+    Some(span.with_lo(lo + BytePos(start as u32)).with_hi(lo + BytePos(end as u32)))
+}
+
 fn require_same_types<'tcx>(
     tcx: TyCtxt<'tcx>,
     cause: &ObligationCause<'tcx>,
     expected: Ty<'tcx>,
     actual: Ty<'tcx>,
+) -> bool {
+    require_same_types_and_decorate(tcx, cause, expected, actual, |_| {})
+}
+
+/// Like `require_same_types`, but lets the caller add to the error emitted when the types
+/// don't match.
+fn require_same_types_and_decorate<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    cause: &ObligationCause<'tcx>,
+    expected: Ty<'tcx>,
+    actual: Ty<'tcx>,
+    decorate: impl FnOnce(&mut Diagnostic),
 ) -> bool {
     tcx.infer_ctxt().enter(|ref infcx| {
         let param_env = ty::ParamEnv::empty();
         let errors = match infcx.at(cause, param_env).eq(expected, actual) {
             Ok(InferOk { obligations, .. }) => traits::fully_solve_obligations(infcx, obligations),
             Err(err) => {
-                infcx.report_mismatched_types(cause, expected, actual, err).emit();
+                let mut diag = infcx.report_mismatched_types(cause, expected, actual, err);
+                decorate(&mut diag);
+                diag.emit();
                 return false;
             }
         };
@@ -219,6 +276,19 @@ fn main_fn_asyncness_span(tcx: TyCtxt<'_>, def_id: DefId) -> Option<Span> {
         Some(tcx.def_span(def_id))
     }
 
+    fn main_fn_extern_span(tcx: TyCtxt<'_>, def_id: DefId) -> Option<Span> {
+        if !def_id.is_local() {
+            return None;
+        }
+        let hir_id = tcx.hir().local_def_id_to_hir_id(def_id.expect_local());
+        let Some(Node::Item(hir::Item { kind: hir::ItemKind::Fn(ref fn_sig, _, _), .. })) =
+            tcx.hir().find(hir_id)
+        else {
+            span_bug!(tcx.def_span(def_id), "main has a non-function type");
+        };
+        extern_qualifier_span(tcx, fn_sig.span)
+    }
+
     fn main_fn_return_type_span(tcx: TyCtxt<'_>, def_id: DefId) -> Option<Span> {
         if !def_id.is_local() {
             return None;
@@ -259,6 +329,17 @@ fn main_fn_return_type_span(tcx: TyCtxt<'_>, def_id: DefId) -> Option<Span> {
         error = true;
     }
 
+    let main_abi = main_fnsig.abi();
+    if main_abi != Abi::Rust {
+        tcx.sess.emit_err(NonRustAbiOnMainFunction {
+            span: main_span,
+            abi: main_abi.name(),
+            extern_span: main_fn_extern_span(tcx, main_def_id),
+        });
+
+        error = true;
+    }
+
     let main_asyncness = tcx.asyncness(main_def_id);
     if let hir::IsAsync::Async = main_asyncness {
         let asyncness_span = main_fn_asyncness_span(tcx, main_def_id);
@@ -302,8 +383,39 @@ fn main_fn_return_type_span(tcx: TyCtxt<'_>, def_id: DefId) -> Option<Span> {
             let norm_return_ty = ocx.normalize(cause.clone(), param_env, return_ty);
             ocx.register_bound(cause, param_env, norm_return_ty, term_did);
             let errors = ocx.select_all_or_error();
+            // Only report the return type as not implementing `Termination` when that's known
+            // to be the case, and leave ambiguities to the usual fulfillment error reporting.
+            let (ambiguities, errors): (Vec<_>, Vec<_>) =
+                errors.into_iter().partition(|error| {
+                    matches!(error.code, traits::FulfillmentErrorCode::CodeAmbiguity)
+                });
+            if !ambiguities.is_empty() {
+                infcx.report_fulfillment_errors(&ambiguities, None, false);
+                error = true;
+            }
             if !errors.is_empty() {
-                infcx.report_fulfillment_errors(&errors, None, false);
+                let return_ty = infcx.resolve_vars_if_possible(norm_return_ty);
+                // Point out the bound that doesn't hold if it isn't `Termination` itself but
+                // one of the bounds of the `Termination` impl of the return type, like
+                // `T: Termination` for `impl<T: Termination> Termination for Wrapper<T>`.
+                let unsatisfied_bound = errors.iter().find_map(|error| {
+                    let predicate = infcx.resolve_vars_if_possible(error.obligation.predicate);
+                    match predicate.to_opt_poly_trait_pred() {
+                        Some(pred)
+                            if pred.def_id() == term_did
+                                && pred.skip_binder().self_ty() == return_ty =>
+                        {
+                            None
+                        }
+                        _ => Some(predicate.to_string()),
+                    }
+                });
+                tcx.sess.emit_err(MainReturnTypeNotTermination {
+                    span: return_ty_span,
+                    return_ty,
+                    unsatisfied_bound_note: unsatisfied_bound.is_some().then_some(()),
+                    unsatisfied_bound: unsatisfied_bound.unwrap_or_default(),
+                });
                 error = true;
             }
         });
@@ -340,11 +452,24 @@ fn check_start_fn_ty(tcx: TyCtxt<'_>, start_def_id: DefId) {
     let start_t = tcx.type_of(start_def_id);
     match start_t.kind() {
         ty::FnDef(..) => {
+            let mut start_signature_sugg = None;
             if let Some(Node::Item(it)) = tcx.hir().find(start_id) {
                 if let hir::ItemKind::Fn(ref sig, ref generics, _) = it.kind {
                     let mut error = false;
+                    let signature_end = if generics.has_where_clause_predicates {
+                        generics.where_clause_span
+                    } else {
+                        sig.span
+                    };
+                    let signature_sugg = StartFunctionSignature {
+                        span: it.ident.span.shrink_to_hi().to(signature_end.shrink_to_hi()),
+                    };
+                    start_signature_sugg = Some(signature_sugg);
                     if !generics.params.is_empty() {
-                        tcx.sess.emit_err(TypeParameterOnStartFunction { span: generics.span });
+                        tcx.sess.emit_err(TypeParameterOnStartFunction {
+                            span: generics.span,
+                            signature_sugg,
+                        });
 
                         error = true;
                     }
@@ -352,6 +477,7 @@ fn check_start_fn_ty(tcx: TyCtxt<'_>, start_def_id: DefId) {
                         tcx.sess.emit_err(WhereClauseOnStartFunction {
                             span: generics.where_clause_span,
                             where_clause_span: generics.where_clause_span,
+                            signature_sugg,
                         });
 
                         error = true;
@@ -395,11 +521,16 @@ fn check_start_fn_ty(tcx: TyCtxt<'_>, start_def_id: DefId) {
                 Abi::Rust,
             )));
 
-            require_same_types(
+            require_same_types_and_decorate(
                 tcx,
                 &ObligationCause::new(start_span, start_id, ObligationCauseCode::StartFunctionType),
                 se_ty,
                 tcx.mk_fn_ptr(tcx.fn_sig(start_def_id)),
+                |err| {
+                    if let Some(signature_sugg) = start_signature_sugg {
+                        err.subdiagnostic(signature_sugg);
+                    }
+                },
             );
         }
         _ => {
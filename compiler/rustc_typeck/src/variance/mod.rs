@@ -26,7 +26,12 @@
 mod xform;
 
 pub fn provide(providers: &mut Providers) {
-    *providers = Providers { variances_of, crate_variances, ..*providers };
+    *providers = Providers {
+        variances_of,
+        crate_variances,
+        opaque_captured_lifetime_variances,
+        ..*providers
+    };
 }
 
 fn crate_variances(tcx: TyCtxt<'_>, (): ()) -> CrateVariancesMap<'_> {
@@ -61,3 +66,18 @@ fn variances_of(tcx: TyCtxt<'_>, item_def_id: DefId) -> &[ty::Variance] {
     let crate_map = tcx.crate_variances(());
     crate_map.variances.get(&item_def_id).copied().unwrap_or(&[])
 }
+
+fn opaque_captured_lifetime_variances(tcx: TyCtxt<'_>, def_id: DefId) -> &[ty::Variance] {
+    let mut variances = vec![ty::Bivariant; tcx.generics_of(def_id).count()];
+    for (region, variance) in tcx.region_variances_in(tcx.type_of(def_id)) {
+        let ty::ReEarlyBound(ebr) = *region else { continue };
+        let Some(current) = variances.get_mut(ebr.index as usize) else { continue };
+        // A lifetime used in several positions can only vary in the ways all of them allow.
+        *current = if *current == ty::Bivariant || *current == variance {
+            variance
+        } else {
+            ty::Invariant
+        };
+    }
+    tcx.arena.alloc_from_iter(variances)
+}
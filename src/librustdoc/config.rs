@@ -267,6 +267,8 @@ pub(crate) struct RenderOptions {
     pub(crate) generate_redirect_map: bool,
     /// Show the memory layout of types in the docs.
     pub(crate) show_type_layout: bool,
+    /// Explain why types that are not `Copy` cannot be `Copy` in the docs.
+    pub(crate) show_copy_obstacles: bool,
     pub(crate) unstable_features: rustc_feature::UnstableFeatures,
     pub(crate) emit: Vec<EmitType>,
     /// If `true`, HTML source pages will generate links for items to their definition.
@@ -681,6 +683,7 @@ fn println_condition(condition: Condition) {
         let run_check = matches.opt_present("check");
         let generate_redirect_map = matches.opt_present("generate-redirect-map");
         let show_type_layout = matches.opt_present("show-type-layout");
+        let show_copy_obstacles = matches.opt_present("show-copy-obstacles");
         let nocapture = matches.opt_present("nocapture");
         let generate_link_to_definition = matches.opt_present("generate-link-to-definition");
         let extern_html_root_takes_precedence =
@@ -755,6 +758,7 @@ fn println_condition(condition: Condition) {
                 document_hidden,
                 generate_redirect_map,
                 show_type_layout,
+                show_copy_obstacles,
                 unstable_features: rustc_feature::UnstableFeatures::from_environment(
                     crate_name.as_deref(),
                 ),
@@ -87,6 +87,8 @@ pub(crate) struct SharedContext<'tcx> {
     pub(crate) local_sources: FxHashMap<PathBuf, String>,
     /// Show the memory layout of types in the docs.
     pub(super) show_type_layout: bool,
+    /// Explain why types that are not `Copy` cannot be `Copy` in the docs.
+    pub(super) show_copy_obstacles: bool,
     /// The base-URL of the issue tracker for when an item has been tagged with
     /// an issue number.
     pub(super) issue_tracker_base_url: Option<String>,
@@ -403,6 +405,7 @@ fn init(
             unstable_features,
             generate_redirect_map,
             show_type_layout,
+            show_copy_obstacles,
             generate_link_to_definition,
             call_locations,
             no_emit_shared,
@@ -487,6 +490,7 @@ fn init(
             errors: receiver,
             redirections: if generate_redirect_map { Some(Default::default()) } else { None },
             show_type_layout,
+            show_copy_obstacles,
             span_correspondance_map: matches,
             cache,
             call_locations,
@@ -6,6 +6,7 @@
 use rustc_hir::def_id::DefId;
 use rustc_middle::middle::stability;
 use rustc_middle::span_bug;
+use rustc_middle::traits::CopyImplObstacle;
 use rustc_middle::ty::layout::LayoutError;
 use rustc_middle::ty::{Adt, TyCtxt};
 use rustc_span::hygiene::MacroKind;
@@ -1151,6 +1152,7 @@ fn item_union(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, s: &clean:
     }
     let def_id = it.item_id.expect_def_id();
     render_assoc_items(w, cx, it, def_id, AssocItemRender::All);
+    document_copy_obstacle(w, cx, def_id);
     document_type_layout(w, cx, def_id);
 }
 
@@ -1317,6 +1319,7 @@ fn item_enum(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, e: &clean::
     }
     let def_id = it.item_id.expect_def_id();
     render_assoc_items(w, cx, it, def_id, AssocItemRender::All);
+    document_copy_obstacle(w, cx, def_id);
     document_type_layout(w, cx, def_id);
 }
 
@@ -1463,6 +1466,7 @@ fn item_struct(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, s: &clean
     }
     let def_id = it.item_id.expect_def_id();
     render_assoc_items(w, cx, it, def_id, AssocItemRender::All);
+    document_copy_obstacle(w, cx, def_id);
     document_type_layout(w, cx, def_id);
 }
 
@@ -1959,6 +1963,60 @@ fn write_size_of_layout(w: &mut Buffer, layout: Layout<'_>, tag_size: u64) {
     writeln!(w, "</div>");
 }
 
+/// Explains why an ADT without a `Copy` impl could not have one, using the same check as
+/// the `impl Copy` coherence check.
+fn document_copy_obstacle(w: &mut Buffer, cx: &Context<'_>, ty_def_id: DefId) {
+    if !cx.shared.show_copy_obstacles {
+        return;
+    }
+
+    let tcx = cx.tcx();
+    let Some(copy_trait) = tcx.lang_items().copy_trait() else { return };
+    let implements_copy = cx
+        .cache()
+        .impls
+        .get(&ty_def_id)
+        .map_or(false, |impls| impls.iter().any(|i| i.trait_did() == Some(copy_trait)));
+    if implements_copy {
+        return;
+    }
+    let Some(obstacle) = tcx.copy_impl_obstacle(ty_def_id) else { return };
+
+    writeln!(
+        w,
+        "<h2 id=\"not-copy\" class=\"small-section-header\"> \
+        Not Copy<a href=\"#not-copy\" class=\"anchor\"></a></h2>"
+    );
+    writeln!(w, "<div class=\"docblock\">");
+    match obstacle {
+        CopyImplObstacle::HasDestructor => {
+            writeln!(
+                w,
+                "<p>This type cannot implement <code>Copy</code> because it implements \
+                 <code>Drop</code>.</p>"
+            );
+        }
+        CopyImplObstacle::InfringingFields(fields) => {
+            writeln!(
+                w,
+                "<p>This type cannot implement <code>Copy</code> because these fields do \
+                 not implement it:</p><ul>"
+            );
+            let (shown, hidden): (Vec<_>, Vec<_>) = fields.iter().partition(|&&field| {
+                cx.cache().document_private || tcx.visibility(field).is_public()
+            });
+            for field in shown {
+                writeln!(w, "<li><code>{}</code></li>", tcx.item_name(*field));
+            }
+            if !hidden.is_empty() {
+                writeln!(w, "<li>{} private field{}</li>", hidden.len(), pluralize(hidden.len()));
+            }
+            w.write_str("</ul>");
+        }
+    }
+    writeln!(w, "</div>");
+}
+
 fn pluralize(count: usize) -> &'static str {
     if count > 1 { "s" } else { "" }
 }
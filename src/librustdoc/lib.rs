@@ -574,6 +574,13 @@ fn opts() -> Vec<RustcOptGroup> {
         unstable("show-type-layout", |o| {
             o.optflagmulti("", "show-type-layout", "Include the memory layout of types in the docs")
         }),
+        unstable("show-copy-obstacles", |o| {
+            o.optflagmulti(
+                "",
+                "show-copy-obstacles",
+                "Explain why types that are not Copy cannot be Copy",
+            )
+        }),
         unstable("nocapture", |o| {
             o.optflag("", "nocapture", "Don't capture stdout and stderr of tests")
         }),
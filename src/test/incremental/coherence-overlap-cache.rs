@@ -0,0 +1,27 @@
+// Check that the cached overlap results of trait impls are recomputed when one of the
+// impls changes between revisions.
+
+// revisions: rpass1 rpass2 cfail3
+// compile-flags: -Z query-dep-graph
+
+#![feature(rustc_attrs)]
+
+trait Trait {}
+
+struct A;
+struct B;
+
+impl Trait for A {}
+
+#[cfg(rpass1)]
+impl Trait for B {}
+
+// `A` is local and not `Copy`, so this does not overlap with the impl for `A`.
+#[cfg(rpass2)]
+impl<T: Copy> Trait for T {}
+
+#[cfg(cfail3)]
+impl<T> Trait for T {}
+//[cfail3]~^ ERROR conflicting implementations of trait `Trait` for type `A`
+
+fn main() {}
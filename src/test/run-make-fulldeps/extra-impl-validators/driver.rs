@@ -0,0 +1,59 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that checks the impls of every local
+//! trait named `Marker`: they may only be implemented for structs. It does so by
+//! overriding the `extra_impl_validators` query, whose validators are run by
+//! coherence checking alongside the builtin checks for traits like `Copy`.
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use rustc_hir::def_id::LocalDefId;
+use rustc_interface::Config;
+use rustc_middle::ty::query::{ExternProviders, Providers};
+use rustc_middle::ty::{self, ImplValidator, TyCtxt};
+use rustc_session::Session;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        rustc_driver::RunCompiler::new(&rustc_args, &mut CompilerCalls).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers, _external: &mut ExternProviders) {
+    local.extra_impl_validators = extra_impl_validators;
+}
+
+fn extra_impl_validators<'tcx>(tcx: TyCtxt<'tcx>, (): ()) -> &'tcx [ImplValidator] {
+    tcx.arena.alloc_from_iter(
+        tcx.all_local_trait_impls(())
+            .keys()
+            .filter(|&&trait_def_id| tcx.item_name(trait_def_id).as_str() == "Marker")
+            .map(|&trait_def_id| ImplValidator { trait_def_id, validate: check_marker_impl }),
+    )
+}
+
+fn check_marker_impl(tcx: TyCtxt<'_>, impl_def_id: LocalDefId) {
+    let self_ty = tcx.type_of(impl_def_id);
+    match self_ty.kind() {
+        ty::Adt(def, _) if def.is_struct() => {}
+        _ => {
+            let msg = format!("`Marker` can only be implemented for structs, not `{}`", self_ty);
+            tcx.sess.span_err(tcx.def_span(impl_def_id), &msg);
+        }
+    }
+}
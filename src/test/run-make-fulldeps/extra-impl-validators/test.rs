@@ -0,0 +1,9 @@
+pub trait Marker {}
+
+pub struct Struct;
+impl Marker for Struct {}
+
+pub enum Enum {
+    A,
+}
+impl Marker for Enum {}
@@ -0,0 +1,5 @@
+// Rustdoc does not type-check function bodies, but still rejects `self` parameters of free
+// functions.
+
+pub fn free(&self) {}
+//~^ ERROR `self` parameter is only allowed in associated functions
@@ -0,0 +1,4 @@
+// Tests that `--show-copy-obstacles` is required in order to explain why types are not `Copy`.
+
+// @!has copy_obstacles_flag_required/struct.Foo.html '//*[@id="not-copy"]' ''
+pub struct Foo(pub String);
@@ -0,0 +1,33 @@
+// compile-flags: --show-copy-obstacles -Z unstable-options
+
+// @has copy_obstacles/struct.Fields.html '//*[@id="not-copy"]/a[@href="#not-copy"]' ''
+// @has - '//*[@class="docblock"]//li/code' 'name'
+// @hasraw - '1 private field'
+// @!hasraw - '<code>count</code>'
+pub struct Fields {
+    pub id: u32,
+    pub name: String,
+    tags: Vec<String>,
+    count: usize,
+}
+
+// @has copy_obstacles/enum.Variants.html '//*[@class="docblock"]//li/code' '0'
+pub enum Variants {
+    A(u8),
+    B(Box<u8>),
+}
+
+// @hasraw copy_obstacles/struct.Destructor.html 'because it implements <code>Drop</code>'
+pub struct Destructor;
+
+impl Drop for Destructor {
+    fn drop(&mut self) {}
+}
+
+// @!has copy_obstacles/struct.Copyable.html '//*[@id="not-copy"]' ''
+#[derive(Clone, Copy)]
+pub struct Copyable(pub u8);
+
+// Nothing keeps this type from being `Copy`, it just isn't.
+// @!has copy_obstacles/struct.Plain.html '//*[@id="not-copy"]' ''
+pub struct Plain(pub u8);
@@ -3,7 +3,7 @@
 // ignore-spirv
 // ignore-wasm32
 
-#![feature(asm_const, asm_sym)]
+#![feature(asm_const, asm_sym, thread_local)]
 
 use std::arch::{asm, global_asm};
 
@@ -56,11 +56,11 @@ const fn const_bar<T>(x: T) -> T {
         asm!("{}", const 0i32);
         asm!("{}", const 0i128);
         asm!("{}", const 0f32);
-        //~^ ERROR mismatched types
+        //~^ ERROR invalid type for `const` operand
         asm!("{}", const 0 as *mut u8);
-        //~^ ERROR mismatched types
+        //~^ ERROR invalid type for `const` operand
         asm!("{}", const &0);
-        //~^ ERROR mismatched types
+        //~^ ERROR invalid type for `const` operand
     }
 }
 
@@ -68,12 +68,22 @@ unsafe fn generic<T>() {
     asm!("{}", sym generic::<T>);
 }
 
+// Sym operands must not refer to thread-local statics.
+
+#[thread_local]
+static mut TLS: u32 = 0;
+
+unsafe fn thread_local() {
+    asm!("{}", sym TLS);
+    //~^ ERROR invalid `sym` operand
+}
+
 // Const operands must be integers and must be constants.
 
 global_asm!("{}", const 0);
 global_asm!("{}", const 0i32);
 global_asm!("{}", const 0i128);
 global_asm!("{}", const 0f32);
-//~^ ERROR mismatched types
+//~^ ERROR invalid type for `const` operand
 global_asm!("{}", const 0 as *mut u8);
-//~^ ERROR mismatched types
+//~^ ERROR invalid type for `const` operand
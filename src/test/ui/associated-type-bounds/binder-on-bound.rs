@@ -5,7 +5,7 @@ trait Trait {
 }
 
 fn foo() where Trait<for<'a> Bound<'a> = &'a ()> {
-    //~^ ERROR `for<...>` is not allowed on associated type bounds
+    //~^ ERROR `for<'a>` is not allowed on the associated type binding `Bound<'a>`
 }
 
 fn main() {}
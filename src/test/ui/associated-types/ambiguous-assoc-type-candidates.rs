@@ -0,0 +1,58 @@
+// Check that all the accessible traits that are implemented for the self type and define the
+// associated type are suggested.
+
+#![allow(dead_code)]
+
+struct S;
+
+trait Foo {
+    type Assoc;
+}
+
+trait Bar {
+    type Assoc;
+}
+
+trait Baz {
+    type Assoc;
+}
+
+mod private {
+    trait Hidden {
+        type Assoc;
+    }
+
+    impl Hidden for super::S {
+        type Assoc = ();
+    }
+}
+
+impl Foo for S {
+    type Assoc = u8;
+}
+
+impl Bar for S {
+    type Assoc = u16;
+}
+
+impl Baz for u32 {
+    type Assoc = u32;
+}
+
+struct G;
+
+// Lifetimes and defaulted parameters don't need to be specified, the rest is left as placeholders.
+trait Generic<'a, T, const N: usize, U = ()> {
+    type Out;
+}
+
+impl<'a> Generic<'a, u8, 0> for G {
+    type Out = ();
+}
+
+fn main() {
+    let _: S::Assoc;
+    //~^ ERROR ambiguous associated type
+    let _: G::Out;
+    //~^ ERROR ambiguous associated type
+}
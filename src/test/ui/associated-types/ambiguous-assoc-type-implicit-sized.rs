@@ -0,0 +1,19 @@
+// Check that we point at the implicit `Sized` bound that keeps an impl from providing the
+// associated type for an unsized type argument.
+
+#![allow(dead_code)]
+
+struct Wrapper<T: ?Sized>(Box<T>);
+
+trait Assoc {
+    type Out;
+}
+
+impl<T> Assoc for Wrapper<T> {
+    type Out = ();
+}
+
+fn main() {
+    let _: Wrapper<str>::Out;
+    //~^ ERROR ambiguous associated type
+}
@@ -0,0 +1,14 @@
+// run-rustfix
+#![allow(dead_code)]
+
+trait Foo<T> {
+    type A;
+}
+
+fn only_binding<I: Foo<u8>>(_: <I as Foo<u8, A = u8>>::A) {}
+//~^ ERROR associated type bindings are not allowed here
+
+fn existing_where_clause<I>(_: <I as Foo<u8, A = u8>>::A) where I: Copy {}
+//~^ ERROR associated type bindings are not allowed here
+
+fn main() {}
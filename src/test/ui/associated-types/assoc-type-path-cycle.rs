@@ -0,0 +1,14 @@
+// Check that a cycle between associated type paths in the bounds of type parameters points at
+// each of the paths involved.
+
+trait Trait {
+    type A;
+    type B;
+}
+
+fn foo<T: Trait<A = U::B>, U: Trait<A = T::B>>() {}
+//~^ ERROR cycle detected when resolving the associated type `T::B`
+
+fn bar<T: Trait<A = <U as Trait>::B>, U: Trait<A = T::B>>() {}
+
+fn main() {}
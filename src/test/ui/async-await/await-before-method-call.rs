@@ -0,0 +1,21 @@
+// edition:2018
+// run-rustfix
+#![allow(dead_code)]
+
+struct Struct;
+
+impl Struct {
+    fn method(&self) -> u32 {
+        0
+    }
+}
+
+async fn struct_() -> Struct {
+    Struct
+}
+
+async fn foo() -> u32 {
+    struct_().method() //~ ERROR no method named `method` found
+}
+
+fn main() {}
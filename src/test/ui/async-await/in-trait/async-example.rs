@@ -0,0 +1,28 @@
+// check-pass
+// edition: 2021
+
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features)]
+
+trait MyTrait {
+    async fn foo(&self) -> i32;
+    async fn bar(&self) -> i32;
+}
+
+impl MyTrait for i32 {
+    async fn foo(&self) -> i32 {
+        *self
+    }
+
+    async fn bar(&self) -> i32 {
+        self.foo().await
+    }
+}
+
+async fn call_foo<T: MyTrait>(x: &T) -> i32 {
+    x.foo().await + x.bar().await
+}
+
+fn main() {
+    let _ = call_foo(&1);
+}
@@ -0,0 +1,20 @@
+// edition: 2021
+
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features)]
+
+trait Foo {
+    async fn foo(&self) -> u32;
+}
+
+fn bar(_: &dyn Foo) {}
+//~^ ERROR the trait `Foo` cannot be made into an object
+
+trait Baz {
+    async fn baz(self: Box<Self>) -> u32;
+}
+
+fn qux(_: &dyn Baz) {}
+//~^ ERROR the trait `Baz` cannot be made into an object
+
+fn main() {}
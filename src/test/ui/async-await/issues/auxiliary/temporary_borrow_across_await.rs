@@ -0,0 +1,23 @@
+// edition:2018
+
+use std::any::Any;
+
+pub struct Client(Box<dyn Any + Send>);
+
+impl Client {
+    fn status(&self) -> u16 {
+        200
+    }
+}
+
+async fn get() {}
+
+pub async fn run() {
+    let client = Client(Box::new(true));
+    match client.status() {
+        200 => {
+            let _x = get().await;
+        }
+        _ => (),
+    }
+}
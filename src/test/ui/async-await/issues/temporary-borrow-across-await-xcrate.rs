@@ -0,0 +1,14 @@
+// aux-build: temporary_borrow_across_await.rs
+// edition:2018
+
+// Check that the suggestion to shorten the lifetime of a temporary borrow held across an
+// `.await` is also emitted when the future comes from another crate.
+
+extern crate temporary_borrow_across_await;
+
+fn g(_: impl Send) {}
+
+fn main() {
+    g(temporary_borrow_across_await::run())
+    //~^ ERROR future cannot be sent between threads safely
+}
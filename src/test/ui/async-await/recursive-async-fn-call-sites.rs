@@ -0,0 +1,26 @@
+// edition:2018
+// Check that the recursive calls of an `async fn` are pointed at.
+
+async fn fib(n: u32) -> u32 {
+    //~^ ERROR recursion in an `async fn` requires boxing
+    if n < 2 {
+        n
+    } else {
+        fib(n - 1).await + fib(n - 2).await
+    }
+}
+
+struct Counter;
+
+impl Counter {
+    async fn count_down(&self, n: u32) -> u32 {
+        //~^ ERROR recursion in an `async fn` requires boxing
+        if n == 0 {
+            0
+        } else {
+            self.count_down(n - 1).await + Self::count_down(self, n - 1).await
+        }
+    }
+}
+
+fn main() {}
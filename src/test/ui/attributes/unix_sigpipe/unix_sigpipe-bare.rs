@@ -0,0 +1,5 @@
+#![feature(unix_sigpipe)]
+#![allow(incomplete_features)]
+
+#[unix_sigpipe] //~ ERROR malformed `unix_sigpipe` attribute input
+fn main() {}
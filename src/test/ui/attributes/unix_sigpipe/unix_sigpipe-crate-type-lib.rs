@@ -0,0 +1,11 @@
+#![crate_type = "lib"]
+#![feature(unix_sigpipe)]
+#![allow(incomplete_features)]
+
+#[unix_sigpipe = "inherit"] //~ ERROR `unix_sigpipe` attribute can only be used on `fn main()`
+pub fn f() {}
+
+pub mod m {
+    #[unix_sigpipe = "sig_ign"] //~ ERROR `unix_sigpipe` attribute can only be used on root `fn main()`
+    pub fn main() {}
+}
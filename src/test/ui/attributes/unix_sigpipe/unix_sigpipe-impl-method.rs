@@ -0,0 +1,16 @@
+#![feature(unix_sigpipe)]
+#![allow(incomplete_features)]
+
+struct S;
+
+impl S {
+    #[unix_sigpipe = "inherit"] //~ ERROR `unix_sigpipe` attribute can only be used on `fn main()`
+    fn main() {}
+}
+
+trait T {
+    #[unix_sigpipe = "sig_dfl"] //~ ERROR `unix_sigpipe` attribute can only be used on `fn main()`
+    fn main();
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![feature(unix_sigpipe)]
+#![allow(incomplete_features)]
+
+#[unix_sigpipe = "inherit"] //~ ERROR `unix_sigpipe` attribute can only be used on `fn main()`
+fn f() {}
+
+#[unix_sigpipe = "sig_dfl"] //~ ERROR `unix_sigpipe` attribute can only be used on `fn main()`
+struct S;
+
+mod m {
+    #[unix_sigpipe = "sig_ign"] //~ ERROR `unix_sigpipe` attribute can only be used on root `fn main()`
+    pub fn main() {}
+}
+
+mod n {
+    #[unix_sigpipe = "inherit"] //~ ERROR `unix_sigpipe` attribute can only be used on `fn main()`
+    #[allow(non_camel_case_types)]
+    pub struct main;
+}
+
+fn main() {}
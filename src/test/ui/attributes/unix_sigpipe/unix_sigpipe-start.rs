@@ -0,0 +1,7 @@
+#![feature(start)]
+#![feature(unix_sigpipe)]
+#![allow(incomplete_features)]
+
+#[start]
+#[unix_sigpipe = "inherit"] //~ ERROR `unix_sigpipe` attribute can only be used on `fn main()`
+fn custom_start(argc: isize, argv: *const *const u8) -> isize { 0 }
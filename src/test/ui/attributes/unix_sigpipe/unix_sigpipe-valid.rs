@@ -0,0 +1,10 @@
+// check-pass
+// revisions: inherit sig_ign sig_dfl
+
+#![feature(unix_sigpipe)]
+#![allow(incomplete_features)]
+
+#[cfg_attr(inherit, unix_sigpipe = "inherit")]
+#[cfg_attr(sig_ign, unix_sigpipe = "sig_ign")]
+#[cfg_attr(sig_dfl, unix_sigpipe = "sig_dfl")]
+fn main() {}
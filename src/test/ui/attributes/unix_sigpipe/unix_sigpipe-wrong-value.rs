@@ -0,0 +1,5 @@
+#![feature(unix_sigpipe)]
+#![allow(incomplete_features)]
+
+#[unix_sigpipe = "sig_ing"] //~ ERROR valid values for `#[unix_sigpipe = "..."]` are `inherit`, `sig_ign`, or `sig_dfl`
+fn main() {}
@@ -0,0 +1,19 @@
+// run-rustfix
+
+use std::collections::{BTreeSet, HashSet};
+
+fn main() {
+    let a: HashSet<i32> = HashSet::new();
+    let b: HashSet<i32> = HashSet::new();
+    let _ = a - b;
+    //~^ ERROR cannot subtract `HashSet<i32>` from `HashSet<i32>`
+
+    let c: BTreeSet<i32> = BTreeSet::new();
+    let d: BTreeSet<i32> = BTreeSet::new();
+    let _ = c | d;
+    //~^ ERROR no implementation for `BTreeSet<i32> | BTreeSet<i32>`
+
+    let e: HashSet<i32> = HashSet::new();
+    let _ = &a - &b - e;
+    //~^ ERROR cannot subtract `HashSet<i32>` from `HashSet<i32>`
+}
@@ -0,0 +1,13 @@
+// run-rustfix
+
+#![feature(c_variadic)]
+
+extern "C" {
+    fn printf_like(..., format: *const u8) -> i32;
+    //~^ ERROR `...` must be the last argument of a C-variadic function
+}
+
+pub unsafe extern "C" fn named(mut ap: ..., format: *const u8) {}
+//~^ ERROR `...` must be the last argument of a C-variadic function
+
+fn main() {}
@@ -0,0 +1,18 @@
+// Check that the unwinding variants of the C calling convention support C-variadics, and that
+// the suggested calling convention for the other unwinding ABIs keeps unwinding. The calling
+// convention of a block is only suggested once.
+
+#![feature(c_unwind)]
+
+extern "C-unwind" {
+    fn c_unwind(x: i32, ...);
+}
+
+extern "system-unwind" {
+    fn system_unwind(x: i32, ...);
+    //~^ ERROR C-variadic function must have C or cdecl calling convention
+    fn system_unwind_2(x: i32, ...);
+    //~^ ERROR C-variadic function must have C or cdecl calling convention
+}
+
+fn main() {}
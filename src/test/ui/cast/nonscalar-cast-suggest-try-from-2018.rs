@@ -0,0 +1,17 @@
+// edition:2018
+
+struct Even(i64);
+
+impl std::convert::TryFrom<i64> for Even {
+    type Error = ();
+
+    fn try_from(value: i64) -> Result<Self, ()> {
+        if value % 2 == 0 { Ok(Even(value)) } else { Err(()) }
+    }
+}
+
+fn main() {
+    // `TryFrom` isn't in the prelude before the 2021 edition, so `Even::try_from` isn't suggested.
+    let _ = 4i64 as Even;
+    //~^ ERROR non-primitive cast: `i64` as `Even`
+}
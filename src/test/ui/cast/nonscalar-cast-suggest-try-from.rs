@@ -0,0 +1,35 @@
+// edition:2021
+
+use std::num::NonZeroU8;
+
+struct Even(i64);
+
+impl TryFrom<i64> for Even {
+    type Error = ();
+
+    fn try_from(value: i64) -> Result<Self, ()> {
+        if value % 2 == 0 { Ok(Even(value)) } else { Err(()) }
+    }
+}
+
+struct Wrapper(i64);
+
+impl TryInto<i64> for Wrapper {
+    type Error = ();
+
+    fn try_into(self) -> Result<i64, ()> {
+        Ok(self.0)
+    }
+}
+
+// The conversion traits' methods can't be called in constants, so nothing is suggested here.
+const ONE: NonZeroU8 = 1u8 as NonZeroU8;
+//~^ ERROR non-primitive cast: `u8` as `NonZeroU8`
+
+fn main() {
+    let x = 4i64;
+    let _ = x as Even;
+    //~^ ERROR non-primitive cast: `i64` as `Even`
+    let _ = Wrapper(x) as i64;
+    //~^ ERROR non-primitive cast: `Wrapper` as `i64`
+}
@@ -0,0 +1,19 @@
+// edition:2018
+
+fn main() {
+    let _ = |cond: bool| {
+        if cond {
+            return;
+        }
+        1
+        //~^ ERROR mismatched types
+    };
+
+    let _ = async move {
+        if true {
+            return;
+        }
+        1
+        //~^ ERROR mismatched types
+    };
+}
@@ -1,4 +1,4 @@
-// build-fail
+// check-fail
 // compile-flags: --target thumbv8m.main-none-eabi --crate-type lib
 // needs-llvm-components: arm
 #![feature(abi_c_cmse_nonsecure_call, no_core, lang_items, intrinsics)]
@@ -24,4 +24,5 @@ pub fn test(a: u32, b: u32, c: u32, d: u32, e: u32) -> u32 {
         )
     };
     non_secure_function(a, b, c, d, e)
+    //~^ ERROR arguments for `"C-cmse-nonsecure-call"` function too large
 }
@@ -0,0 +1,15 @@
+#![feature(coerce_unsized, rustc_attrs, unsize)]
+
+use std::marker::Unsize;
+use std::ops::CoerceUnsized;
+
+struct Mixed<'a, T: ?Sized> {
+    first: &'a T,
+    second: *const T,
+}
+
+#[rustc_coerce_unsized_multi_field]
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Mixed<'a, U>> for Mixed<'a, T> {}
+//~^ ERROR fields coerced by a `#[rustc_coerce_unsized_multi_field]` impl must all undergo the same coercion
+
+fn main() {}
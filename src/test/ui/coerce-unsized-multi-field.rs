@@ -0,0 +1,24 @@
+// run-pass
+#![feature(coerce_unsized, rustc_attrs, unsize)]
+
+// Check that `#[rustc_coerce_unsized_multi_field]` allows coercing several fields at once.
+
+use std::fmt::Debug;
+use std::marker::Unsize;
+use std::ops::CoerceUnsized;
+
+struct Pair<'a, T: ?Sized> {
+    first: &'a T,
+    second: &'a T,
+}
+
+#[rustc_coerce_unsized_multi_field]
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Pair<'a, U>> for Pair<'a, T> {}
+
+fn main() {
+    let pair: Pair<'_, dyn Debug> = Pair { first: &1u8, second: &2u8 };
+    assert_eq!(format!("{:?} {:?}", pair.first, pair.second), "1 2");
+
+    let pair: Pair<'_, [u8]> = Pair { first: &[1, 2], second: &[3, 4] };
+    assert_eq!(pair.first.len() + pair.second.len(), 4);
+}
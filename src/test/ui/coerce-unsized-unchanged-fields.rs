@@ -0,0 +1,14 @@
+#![feature(coerce_unsized, unsize)]
+
+use std::marker::{PhantomData, Unsize};
+use std::ops::CoerceUnsized;
+
+struct Wrapper<T: ?Sized, U: ?Sized> {
+    ptr: *const T,
+    _marker: PhantomData<U>,
+}
+
+impl<T: ?Sized, U: ?Sized + Unsize<V>, V: ?Sized> CoerceUnsized<Wrapper<T, V>> for Wrapper<T, U> {}
+//~^ ERROR E0374
+
+fn main() {}
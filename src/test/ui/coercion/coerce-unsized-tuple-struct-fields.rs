@@ -0,0 +1,18 @@
+// Fields of tuple structs are named like `self.0` in `CoerceUnsized` errors.
+
+#![feature(coerce_unsized, unsize)]
+
+use std::marker::{PhantomData, Unsize};
+use std::ops::CoerceUnsized;
+
+struct Pair<T: ?Sized>(*const T, *const T);
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Pair<U>> for Pair<T> {}
+//~^ ERROR implementing the trait `CoerceUnsized` requires multiple coercions
+
+struct Tagged<T: ?Sized, U: ?Sized>(*const T, PhantomData<U>);
+
+impl<T: ?Sized, U: ?Sized + Unsize<V>, V: ?Sized> CoerceUnsized<Tagged<T, V>> for Tagged<T, U> {}
+//~^ ERROR E0374
+
+fn main() {}
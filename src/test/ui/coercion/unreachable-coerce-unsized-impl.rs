@@ -0,0 +1,26 @@
+#![feature(coerce_unsized, unsize)]
+#![crate_type = "lib"]
+#![deny(unreachable_coerce_unsized_impls)]
+#![allow(dead_code)]
+
+use std::marker::Unsize;
+use std::ops::CoerceUnsized;
+
+struct Private<T: ?Sized> {
+    ptr: *const T,
+}
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Private<U>> for Private<T> {}
+//~^ ERROR this `CoerceUnsized` impl can never be used outside of the current crate
+
+pub struct Exported<T: ?Sized> {
+    ptr: *const T,
+}
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Exported<U>> for Exported<T> {}
+
+struct PublicField<T: ?Sized> {
+    pub ptr: *const T,
+}
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<PublicField<U>> for PublicField<T> {}
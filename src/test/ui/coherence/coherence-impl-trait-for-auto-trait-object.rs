@@ -0,0 +1,16 @@
+#![feature(auto_traits)]
+
+// Test that an auto trait cannot be implemented for a trait object type made up only of auto
+// traits that already contains it, just like for trait objects with a principal trait.
+
+auto trait Marker1 {}
+auto trait Marker2 {}
+
+trait Object {}
+
+impl Marker1 for dyn Object {} // OK, `Marker1` is not a supertrait of `Object`
+impl Marker1 for dyn Marker1 {} //~ ERROR E0371
+impl Marker2 for dyn Marker1 {} // OK, `dyn Marker1` does not contain `Marker2`
+impl Marker2 for dyn Marker2 {} //~ ERROR E0371
+
+fn main() {}
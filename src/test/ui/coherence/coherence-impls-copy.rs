@@ -4,6 +4,7 @@
 
 impl Copy for i32 {}
 //~^ ERROR E0117
+//~| ERROR E0119
 enum TestE {
   A
 }
@@ -32,5 +33,6 @@ impl Copy for [MyType] {}
 //~| ERROR E0117
 impl Copy for &'static [NotSync] {}
 //~^ ERROR E0117
+//~| ERROR E0119
 fn main() {
 }
@@ -0,0 +1,18 @@
+// Check that an overlap that is only found since the overlap check started equating higher-ranked
+// lifetimes with other lifetimes is reported through the `coherence_leak_check` future-compat
+// lint, explaining why the impls used to be disjoint, while other overlaps are still errors.
+
+trait Trait {}
+
+impl Trait for fn(&'static u8) {}
+
+impl Trait for for<'a> fn(&'a u8) {}
+//~^ WARNING conflicting implementations of trait `Trait` for type `fn(&'static u8)`
+//~| WARNING this was previously accepted by the compiler but is being phased out
+
+impl Trait for u8 {}
+
+impl Trait for u8 {}
+//~^ ERROR conflicting implementations of trait `Trait` for type `u8`
+
+fn main() {}
@@ -0,0 +1,26 @@
+// compile-flags: -Zdesign-hints
+
+struct Config {
+    name: String,
+    tags: Vec<String>,
+    id: u32,
+}
+
+impl Copy for Config {}
+//~^ ERROR the trait `Copy` may not be implemented for this type
+
+impl Clone for Config {
+    fn clone(&self) -> Self {
+        Config { name: self.name.clone(), tags: self.tags.clone(), id: self.id }
+    }
+}
+
+struct Counters<'a> {
+    hits: &'a mut u32,
+    log: &'a mut Vec<String>,
+}
+
+impl<'a> Copy for Counters<'a> {}
+//~^ ERROR the trait `Copy` may not be implemented for this type
+
+fn main() {}
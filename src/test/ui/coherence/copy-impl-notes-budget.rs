@@ -0,0 +1,28 @@
+// Check that E0204 only works out why the first few fields aren't `Copy`, and says so when it
+// skips the rest.
+
+#[derive(Clone, Copy)]
+struct Wrapper<T>(T);
+
+struct Many<T> {
+    f0: Wrapper<T>,
+    f1: Wrapper<T>,
+    f2: Wrapper<T>,
+    f3: Wrapper<T>,
+    f4: Wrapper<T>,
+    f5: Wrapper<T>,
+    f6: Wrapper<T>,
+    f7: Wrapper<T>,
+    f8: Wrapper<T>,
+}
+
+impl<T> Clone for Many<T> {
+    fn clone(&self) -> Self {
+        loop {}
+    }
+}
+
+impl<T> Copy for Many<T> {}
+//~^ ERROR the trait `Copy` may not be implemented for this type
+
+fn main() {}
@@ -0,0 +1,24 @@
+// Check that the notes explaining why fields don't implement `Copy` follow the order in which
+// the fields are declared, rather than the names of their types.
+
+#[derive(Clone, Copy)]
+struct Zeta<T>(T);
+
+#[derive(Clone, Copy)]
+struct Alpha<T>(T);
+
+struct Pair<T, U> {
+    zeta: Zeta<T>,
+    alpha: Alpha<U>,
+}
+
+impl<T, U> Clone for Pair<T, U> {
+    fn clone(&self) -> Self {
+        loop {}
+    }
+}
+
+impl<T, U> Copy for Pair<T, U> {}
+//~^ ERROR the trait `Copy` may not be implemented for this type
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![feature(adt_const_params)]
+#![allow(incomplete_features)]
+
+mod shapes {
+    pub struct Circle;
+
+    #[derive(PartialEq)]
+    pub struct Square;
+}
+
+struct Manual;
+
+impl PartialEq for Manual {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Manual {}
+
+fn circle<const C: shapes::Circle>() {}
+//~^ ERROR `Circle` must be annotated with `#[derive(PartialEq, Eq)]`
+
+fn square<const S: shapes::Square>() {}
+//~^ ERROR `Square` must be annotated with `#[derive(PartialEq, Eq)]`
+
+fn manual<const M: Manual>() {}
+//~^ ERROR `Manual` must be annotated with `#[derive(PartialEq, Eq)]`
+
+fn main() {}
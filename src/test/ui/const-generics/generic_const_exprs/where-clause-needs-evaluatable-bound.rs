@@ -0,0 +1,24 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+trait Trait {}
+
+struct Foo<const N: usize>
+where
+    [(); N + 1]:;
+
+fn bar<const M: usize>()
+where
+    Foo<M>: Trait,
+    //~^ ERROR unconstrained generic constant
+{
+}
+
+fn baz<const M: usize>()
+where
+    [(); M + 1]:,
+    Foo<M>: Trait,
+{
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// diagnostic-records
+// compile-flags: --json=diagnostic-rendered-ansi -Zunstable-options -Zjson-diagnostic-records=no
+// Check that `--json=diagnostic-rendered-ansi` keeps the Fluent identifier and arguments of
+// each diagnostic next to the colored rendering when unstable options are enabled.
+
+struct Foo;
+
+impl<T> Foo {}
+//~^ ERROR the type parameter `T` is not constrained
+
+fn main() {}
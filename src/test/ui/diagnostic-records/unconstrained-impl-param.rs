@@ -0,0 +1,10 @@
+// diagnostic-records
+// Check that `diagnostic-records` tests compare Fluent identifiers and arguments rather than
+// the rendered output.
+
+struct Foo;
+
+impl<T> Foo {}
+//~^ ERROR the type parameter `T` is not constrained
+
+fn main() {}
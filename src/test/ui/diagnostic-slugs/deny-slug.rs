@@ -0,0 +1,20 @@
+// Check that `-Z deny-slug` reports the warnings it names as errors, and only those.
+
+// revisions: typeck lint
+// edition:2015
+//[typeck] compile-flags: -Zdeny-slug=typeck::unused_extern_crate
+//[lint] compile-flags: -Zdeny-slug=lint_path_statement_no_effect
+
+#![warn(unused_extern_crates)]
+#![warn(path_statements)]
+
+extern crate core;
+//[typeck]~^ ERROR unused extern crate
+//[lint]~^^ WARN unused extern crate
+
+fn main() {
+    let x = 1;
+    x;
+    //[typeck]~^ WARN path statement with no effect
+    //[lint]~^^ ERROR path statement with no effect
+}
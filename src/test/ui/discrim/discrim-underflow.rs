@@ -0,0 +1,17 @@
+// Check that explicit discriminants below the minimum of a signed repr are reported
+// instead of silently wrapping around.
+
+#![allow(overflowing_literals)]
+
+#[repr(i8)]
+enum A {
+    Ok = -128,
+    OhNo = -129, //~ ERROR enum discriminant overflowed [E0370]
+}
+
+#[repr(i16)]
+enum B {
+    OhNo = -32769, //~ ERROR enum discriminant overflowed [E0370]
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#![feature(dispatch_from_dyn, unsize)]
+
+// Check that we point at the implicit `Sized` bound that keeps a field from being coerced.
+
+use std::marker::Unsize;
+use std::ops::DispatchFromDyn;
+
+struct Ptr<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized + Unsize<U>, U> DispatchFromDyn<Ptr<U>> for Ptr<T> {}
+
+struct Wrapper<T: ?Sized> {
+    ptr: Ptr<T>,
+}
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<Wrapper<U>> for Wrapper<T> {}
+//~^ ERROR the trait bound `Ptr<T>: DispatchFromDyn<Ptr<U>>` is not satisfied because of an implicit `Sized` bound
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![feature(unsize, dispatch_from_dyn)]
+
+use std::marker::Unsize;
+use std::ops::DispatchFromDyn;
+use std::pin::Pin;
+
+struct Ptr<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<Pin<Ptr<U>>> for Ptr<T> {}
+//~^ ERROR the trait `DispatchFromDyn` must be implemented for the pointer inside `Pin` [E0378]
+
+struct Wrapper<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<Wrapper<U>> for Pin<Wrapper<T>> {}
+//~^ ERROR the trait `DispatchFromDyn` must be implemented for the pointer inside `Pin` [E0378]
+
+fn main() {}
@@ -0,0 +1,41 @@
+// check-pass
+
+#![allow(dead_code)]
+
+trait Flush {}
+
+trait Sink {}
+
+impl Sink for u8 {}
+
+pub trait Exported {}
+
+struct Buffer<T: Flush>(T);
+
+impl<T: Flush> Drop for Buffer<T> {
+    //~^ WARN this `Drop` impl is never used
+    fn drop(&mut self) {}
+}
+
+struct Writer<T: Sink>(T);
+
+impl<T: Sink> Drop for Writer<T> {
+    fn drop(&mut self) {}
+}
+
+pub struct Public<T: Exported>(T);
+
+impl<T: Exported> Drop for Public<T> {
+    fn drop(&mut self) {}
+}
+
+struct Boxed<T: ?Sized + Flush>(Box<T>);
+
+// `Boxed<dyn Flush>` uses this impl.
+impl<T: ?Sized + Flush> Drop for Boxed<T> {
+    fn drop(&mut self) {}
+}
+
+fn main() {
+    let _ = Writer(0u8);
+}
@@ -1,6 +1,7 @@
 trait Trait {
     fn bar<'a,'b:'a>(x: &'a str, y: &'b str);
     //~^ NOTE lifetimes in impl do not match this method in trait
+    //~| NOTE this bound is declared on the trait but not in the impl
 }
 
 struct Foo;
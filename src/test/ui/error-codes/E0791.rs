@@ -0,0 +1,8 @@
+#![feature(repr_simd)]
+
+#[repr(simd)]
+struct Bad([u32; 2], [u32; 2]);
+//~^ ERROR E0791
+
+fn main() {
+}
@@ -0,0 +1,8 @@
+#![feature(repr_simd)]
+
+#[repr(simd)]
+struct Bad(f32, f32, f32);
+//~^ ERROR E0792
+
+fn main() {
+}
@@ -7,5 +7,6 @@
 struct Q;
 
 impl<R> External for (Q, R) {} //~ ERROR only traits defined
+//~^ ERROR conflicting implementations
 
 fn main() {}
@@ -3,5 +3,6 @@
 struct Foo;
 
 impl<Foo> Deref for Foo { } //~ ERROR must be used
+//~^ ERROR conflicting implementations
 
 fn main() {}
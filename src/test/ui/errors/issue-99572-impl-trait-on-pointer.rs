@@ -20,6 +20,7 @@ impl<T> marker::Copy for *mut T {
 //~| NOTE impl doesn't use only types from inside the current crate
 //~| NOTE `*mut T` is not defined in the current crate because raw pointers are always foreign
 //~| NOTE define and implement a trait or new type instead
+//~| ERROR conflicting implementations of trait `std::marker::Copy` for type `*mut _`
 }
 
 fn main() {}
@@ -1 +1,3 @@
-extern "C" fn main() {} //~ ERROR: `main` function has wrong type [E0580]
+// run-rustfix
+
+extern "C" fn main() {} //~ ERROR `main` function is not allowed to use the "C" ABI [E0580]
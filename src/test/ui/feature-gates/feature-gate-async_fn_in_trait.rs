@@ -0,0 +1,7 @@
+// edition: 2021
+
+trait T {
+    async fn foo(); //~ ERROR functions in traits cannot be declared `async`
+}
+
+fn main() {}
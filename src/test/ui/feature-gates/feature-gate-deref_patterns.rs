@@ -0,0 +1,15 @@
+// Check that matching through `Deref` is gated behind `#![feature(deref_patterns)]`.
+
+fn main() {
+    match String::new() {
+        "" => {}
+        //~^ ERROR mismatched types
+        _ => {}
+    }
+
+    let b: Box<(i32, i32)> = Box::new((1, 2));
+    match b {
+        (_, _) => {}
+        //~^ ERROR mismatched types
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt::Debug;
+
+const C: impl Debug = 5;
+//~^ ERROR `impl Trait` only allowed in function and inherent method return types, not in const type
+
+static S: impl Debug + Sync = "hello";
+//~^ ERROR `impl Trait` only allowed in function and inherent method return types, not in static type
+
+struct Foo;
+
+impl Foo {
+    const ASSOC: impl Debug = ();
+    //~^ ERROR `impl Trait` only allowed in function and inherent method return types, not in const type
+}
+
+trait Tr {
+    const ASSOC: impl Debug;
+    //~^ ERROR `impl Trait` only allowed in function and inherent method return types, not in trait associated const type
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+trait Foo {
+    fn bar() -> impl Sized; //~ ERROR `impl Trait` only allowed in function and inherent method return types, not in trait method return
+}
+
+impl Foo for () {
+    fn bar() -> impl Sized {} //~ ERROR `impl Trait` only allowed in function and inherent method return types, not in `impl` method return
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+fn main() {
+    let x: typeof(1u8) = 2;
+    //~^ ERROR use of the reserved `typeof` keyword is experimental
+    let _: u8 = x;
+}
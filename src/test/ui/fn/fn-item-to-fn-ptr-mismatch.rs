@@ -0,0 +1,23 @@
+// Check that we explain why a function item can't be coerced to a function pointer
+// if it only differs in its `unsafe` qualifier or its ABI.
+
+unsafe fn unsafe_add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+extern "C" fn c_add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+fn rust_add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+fn main() {
+    let _: fn(u32, u32) -> u32 = unsafe_add;
+    //~^ ERROR mismatched types
+    let _: fn(u32, u32) -> u32 = c_add;
+    //~^ ERROR mismatched types
+    let _: extern "C" fn(u32, u32) -> u32 = rust_add;
+    //~^ ERROR mismatched types
+}
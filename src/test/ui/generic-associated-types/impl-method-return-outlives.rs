@@ -0,0 +1,21 @@
+#![feature(generic_associated_types)]
+
+// Check that when the return type of an impl method doesn't satisfy an outlives bound
+// declared on the trait's associated type, we point at the return type and at that bound.
+
+trait Container {
+    type Item<'a> where Self: 'a;
+    fn make<'a>() -> Self::Item<'a> where Self: 'a;
+}
+
+struct Wrapper<T>(T);
+
+impl<T> Container for Wrapper<T> {
+    type Item<'a> = Option<T> where Self: 'a;
+    fn make<'a>() -> Self::Item<'a> {
+        //~^ ERROR the parameter type `T` may not live long enough
+        None
+    }
+}
+
+fn main() {}
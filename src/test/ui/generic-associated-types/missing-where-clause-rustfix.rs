@@ -0,0 +1,21 @@
+// run-rustfix
+// Check that the where clauses suggested for missing GAT bounds can be applied as is.
+
+#![feature(generic_associated_types)]
+#![allow(dead_code)]
+
+trait Lending {
+    type Item<'a>;
+    //~^ ERROR missing required bound on `Item`
+
+    fn next<'a>(&'a mut self) -> Self::Item<'a>;
+}
+
+trait Pairs<T> {
+    type Pair<'a> where T: Clone;
+    //~^ ERROR missing required bounds on `Pair`
+
+    fn pair<'a>(&'a self, value: &'a T) -> Self::Pair<'a>;
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// run-rustfix
+
+#![deny(no_mangle_generic_items)]
+
+#[export_name = "foo"]
+pub fn foo<T>() {} //~ ERROR functions generic over types or consts must be mangled
+
+#[export_name = "bar"]
+pub fn bar<'a>(x: &'a i32) -> &'a i32 { x }
+
+pub struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    #[export_name = "baz"]
+    pub fn baz(&self) {} //~ ERROR functions generic over types or consts must be mangled
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// Check that outlives bounds which only appear on one side of E0195 are pointed at when they
+// are written in a where clause.
+
+trait Trait<'t> {
+    fn foo<'a, 'b>(x: &'a str, y: &'b str) where 'b: 'a;
+    //~^ NOTE lifetimes in impl do not match this method in trait
+    //~| NOTE this bound is declared on the trait but not in the impl
+}
+
+impl<'t> Trait<'t> for () {
+    fn foo<'a, 'b>(x: &'a str, y: &'b str) where 'a: 't {}
+    //~^ ERROR lifetime parameters or bounds on method `foo` do not match the trait declaration
+    //~| NOTE lifetimes do not match method in trait
+    //~| NOTE this bound is declared in the impl but not on the trait
+}
+
+fn main() {}
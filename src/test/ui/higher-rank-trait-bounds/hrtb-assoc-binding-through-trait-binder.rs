@@ -0,0 +1,33 @@
+// check-pass
+// Check that associated type bindings using the lifetimes of the trait's `for<...>` binder are
+// accepted wherever a trait bound can be written.
+
+#![feature(unboxed_closures)]
+
+trait Trait<'a> {
+    type Assoc;
+}
+
+impl<'a> Trait<'a> for () {
+    type Assoc = &'a u8;
+}
+
+fn where_clause<T>()
+where
+    T: for<'a> Trait<'a, Assoc = &'a u8>,
+{
+}
+
+fn arg_position(_: impl for<'a> Trait<'a, Assoc = &'a u8>) {}
+
+fn object(_: &dyn for<'a> Fn<(&'a u8,), Output = &'a u8>) {}
+
+fn identity(x: &u8) -> &u8 {
+    x
+}
+
+fn main() {
+    where_clause::<()>();
+    arg_position(());
+    object(&identity);
+}
@@ -0,0 +1,13 @@
+// run-rustfix
+// Check that all the elided lifetimes in an impl header are reported with a single suggestion.
+
+#![allow(warnings)]
+
+trait MyTrait<'a> {}
+
+struct Foo<'a, T> { x: &'a T }
+
+impl MyTrait for Foo<u32> {}
+//~^ ERROR implicit elided lifetime not allowed here
+
+fn main() {}
@@ -0,0 +1,40 @@
+// Check that only the initializer of a constant or static can define the hidden type of
+// `impl Trait` in its type, and that `impl Trait` isn't allowed in the types of associated constants of traits.
+
+#![feature(impl_trait_in_consts)]
+
+use std::fmt::Debug;
+
+const C: impl Debug = 5u8;
+
+static S: impl Debug + Sync = 5u8;
+
+fn get() {
+    let _: u8 = C;
+    //~^ ERROR mismatched types
+    let _: u8 = S;
+    //~^ ERROR mismatched types
+}
+
+struct Foo;
+
+impl Foo {
+    const ASSOC: impl Debug = 5u8;
+
+    fn get() {
+        let _: u8 = Self::ASSOC;
+        //~^ ERROR mismatched types
+    }
+}
+
+trait Tr {
+    const ASSOC: impl Debug;
+    //~^ ERROR `impl Trait` only allowed in function and inherent method return types, not in trait associated const type
+}
+
+impl Tr for Foo {
+    const ASSOC: impl Debug = ();
+    //~^ ERROR `impl Trait` only allowed in function and inherent method return types, not in trait associated const type
+}
+
+fn main() {}
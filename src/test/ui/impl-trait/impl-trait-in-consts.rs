@@ -0,0 +1,27 @@
+// check-pass
+// Check that `impl Trait` is accepted in the types of statics and (associated) constants
+// with `#![feature(impl_trait_in_consts)]`, its hidden type being that of the initializer.
+
+#![feature(impl_trait_in_consts)]
+
+use std::fmt::Debug;
+
+const C: impl Debug = 5;
+
+static S: impl Debug + Sync = "hello";
+
+struct Foo;
+
+impl Foo {
+    const ASSOC: impl Debug = ();
+}
+
+struct Wrapper<T>(T);
+
+impl<T: Debug> Wrapper<T> {
+    const NONE: impl Debug = None::<T>;
+}
+
+fn main() {
+    println!("{:?} {:?} {:?} {:?}", C, S, Foo::ASSOC, Wrapper::<u8>::NONE);
+}
@@ -0,0 +1,14 @@
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Display;
+
+pub trait Foo {
+    fn bar(&self) -> impl Display;
+}
+
+impl Foo for u32 {
+    fn bar(&self) -> impl Display {
+        *self + 1
+    }
+}
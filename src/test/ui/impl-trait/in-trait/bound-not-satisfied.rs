@@ -0,0 +1,19 @@
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Display;
+
+struct NotDisplay;
+
+trait Foo {
+    fn bar(&self) -> impl Display;
+}
+
+impl Foo for () {
+    fn bar(&self) -> NotDisplay {
+        //~^ ERROR the return type `NotDisplay` does not satisfy the bound `Display` on the trait's `impl Trait`
+        NotDisplay
+    }
+}
+
+fn main() {}
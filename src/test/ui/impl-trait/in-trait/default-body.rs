@@ -0,0 +1,13 @@
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Display;
+
+trait Foo {
+    fn bar(&self) -> impl Display {
+    //~^ ERROR return-position `impl Trait` in traits is not yet supported in trait methods with a default body
+        0
+    }
+}
+
+fn main() {}
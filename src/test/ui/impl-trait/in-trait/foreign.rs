@@ -0,0 +1,23 @@
+// aux-build: rpitit.rs
+// run-pass
+
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+extern crate rpitit;
+
+use rpitit::Foo;
+use std::fmt::Display;
+
+struct Local;
+
+impl Foo for Local {
+    fn bar(&self) -> impl Display {
+        "local"
+    }
+}
+
+fn main() {
+    assert_eq!(1u32.bar().to_string(), "2");
+    assert_eq!(Local.bar().to_string(), "local");
+}
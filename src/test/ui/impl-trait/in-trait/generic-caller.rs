@@ -0,0 +1,31 @@
+// run-pass
+
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Display;
+
+trait Foo {
+    fn bar(&self) -> impl Display;
+}
+
+impl Foo for i32 {
+    fn bar(&self) -> i32 {
+        *self
+    }
+}
+
+impl Foo for String {
+    fn bar(&self) -> impl Display {
+        format!("{self}!")
+    }
+}
+
+fn show<T: Foo>(t: &T) -> String {
+    t.bar().to_string()
+}
+
+fn main() {
+    assert_eq!(show(&1i32), "1");
+    assert_eq!(show(&String::from("hello")), "hello!");
+}
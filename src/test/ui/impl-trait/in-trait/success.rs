@@ -0,0 +1,38 @@
+// run-pass
+
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Display;
+
+trait Foo {
+    fn bar(&self) -> impl Display;
+}
+
+impl Foo for i32 {
+    fn bar(&self) -> i32 {
+        *self
+    }
+}
+
+impl Foo for &'static str {
+    fn bar(&self) -> impl Display {
+        format!("{self}!")
+    }
+}
+
+trait Iterable {
+    fn iter(&self) -> impl Iterator<Item = u32> + '_;
+}
+
+impl Iterable for Vec<u32> {
+    fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.as_slice().iter().copied()
+    }
+}
+
+fn main() {
+    println!("{}", 1i32.bar());
+    println!("{}", "hello".bar());
+    assert_eq!(vec![1, 2, 3].iter().sum::<u32>(), 6);
+}
@@ -0,0 +1,36 @@
+// Check that an invariant use of a captured lifetime is explained through the hidden type of
+// another opaque type, whose variances come from the `opaque_captured_lifetime_variances` query.
+
+trait Captures<'a> {}
+
+impl<'a, T: ?Sized> Captures<'a> for T {}
+
+trait Swap: Sized {
+    fn swap(self, other: Self);
+}
+
+impl<T> Swap for &mut T {
+    fn swap(self, other: Self) {
+        std::mem::swap(self, other);
+    }
+}
+
+fn hide_ref<'a, 'b, T: 'static>(x: &'a mut &'b T) -> impl Swap + Captures<'b> + 'a {
+    x
+}
+
+fn rehide_ref<'a, 'b, T: 'static>(x: &'a mut &'b T) -> impl Swap + 'a {
+    hide_ref(x)
+    //~^ ERROR hidden type
+}
+
+fn hide_shared<'a, 'b, T: 'static>(x: &'a &'b T) -> impl Sized + Captures<'b> + 'a {
+    x
+}
+
+// `'b` is only used covariantly by the hidden type of `hide_shared`, so it can be shortened.
+fn rehide_shared<'a, 'b: 'a, T: 'static>(x: &'a &'b T) -> impl Sized + 'a {
+    hide_shared(x)
+}
+
+fn main() {}
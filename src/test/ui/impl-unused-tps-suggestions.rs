@@ -0,0 +1,78 @@
+// Check the fixes suggested for impl parameters that are not constrained.
+
+struct Wrapper<T = ()>(T);
+struct Pair<A, B = A>(A, B);
+struct Ref<'a>(&'a u8);
+struct Buf<const N: usize = 1>;
+
+trait Convert<T = Self> {
+    type Output;
+}
+
+trait Tr {
+    type Out;
+}
+
+trait Other {
+    type Out;
+}
+
+trait Third {
+    type Out;
+}
+
+impl<T> Tr for Wrapper {
+    //~^ ERROR the type parameter `T` is not constrained
+    type Out = T;
+}
+
+impl<T> Convert for u8 {
+    //~^ ERROR the type parameter `T` is not constrained
+    type Output = T;
+}
+
+impl<T> Convert for Wrapper {
+    //~^ ERROR the type parameter `T` is not constrained
+    type Output = T;
+}
+
+impl<T, U> Convert<T> for Pair<T> {
+    //~^ ERROR the type parameter `U` is not constrained
+    type Output = U;
+}
+
+impl<'a> Tr for Ref<'_> {
+    //~^ ERROR the lifetime parameter `'a` is not constrained
+    type Out = &'a u8;
+}
+
+impl<const N: usize> Tr for Buf {
+    //~^ ERROR the const parameter `N` is not constrained
+    type Out = [u8; N];
+}
+
+impl<T, U: Clone> Other for Wrapper<T> {
+    //~^ ERROR the type parameter `U` is not constrained
+    type Out = T;
+}
+
+impl<T> Third for u8 {
+    //~^ ERROR the type parameter `T` is not constrained
+    type Out = Vec<T>;
+}
+
+struct Tagged<A>(A);
+
+impl<A, B> Tr for Tagged<A> {
+    //~^ ERROR the type parameter `B` is not constrained
+    type Out = B;
+}
+
+struct Defaulted<A, B = ()>(A, B);
+
+impl<A, C> Tr for Defaulted<A, u8> {
+    //~^ ERROR the type parameter `C` is not constrained
+    type Out = C;
+}
+
+fn main() {}
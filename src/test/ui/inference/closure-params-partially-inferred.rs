@@ -0,0 +1,9 @@
+// Check that we suggest annotating all of the parameters of a closure at once,
+// using the types we were able to infer for the other parameters.
+
+fn main() {
+    let _f = |a, b| {
+        //~^ ERROR type annotations needed
+        let _: u32 = b;
+    };
+}
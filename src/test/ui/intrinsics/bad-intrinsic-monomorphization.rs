@@ -27,6 +27,12 @@ pub unsafe fn test_fadd_fast(a: Foo, b: Foo) -> Foo {
 }
 
 pub unsafe fn test_simd_add(a: Foo, b: Foo) -> Foo {
+    generic_simd_add(a, b)
+}
+
+// Calls with concrete types are already rejected during type checking, so go through a generic
+// function to get to codegen.
+unsafe fn generic_simd_add<T>(a: T, b: T) -> T {
     simd_add(a, b)
     //~^ ERROR `simd_add` intrinsic: expected SIMD input type, found non-SIMD `Foo`
 }
@@ -0,0 +1,16 @@
+#![feature(core_intrinsics)]
+
+use std::intrinsics;
+
+const fn name<T>() -> &'static str {
+    intrinsics::type_name::<T>()
+    //~^ ERROR `type_name` is not yet stable as a const intrinsic
+}
+
+const ALIGN: usize = unsafe { intrinsics::min_align_of_val(&0u8) };
+//~^ ERROR `min_align_of_val` is not yet stable as a const intrinsic
+
+fn main() {
+    // Outside of const contexts, no feature gate is required.
+    let _ = intrinsics::type_name::<u8>();
+}
@@ -0,0 +1,16 @@
+#![feature(intrinsics)]
+
+extern "rust-intrinsic" {
+    fn atomic_load_acqrel<T: Copy>(src: *const T) -> T;
+    //~^ ERROR unrecognized memory ordering `acqrel` in atomic intrinsic `atomic_load_acqrel`
+    fn atomic_store_acquire<T: Copy>(dst: *mut T, val: T);
+    //~^ ERROR unrecognized memory ordering `acquire` in atomic intrinsic `atomic_store_acquire`
+    fn atomic_xadd_aquire<T: Copy>(dst: *mut T, src: T) -> T;
+    //~^ ERROR unrecognized memory ordering `aquire` in atomic intrinsic `atomic_xadd_aquire`
+    fn atomic_cxchg_seqcst_release<T: Copy>(dst: *mut T, old: T, src: T) -> (T, bool);
+    //~^ ERROR unrecognized memory ordering `release` in atomic intrinsic
+    fn atomic_fence_relaxed();
+    //~^ ERROR unrecognized memory ordering `relaxed` in atomic intrinsic `atomic_fence_relaxed`
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// When the receiver type of a free function's `self` parameter is written out, the suggested
+// `impl` block is for that type.
+
+struct Counter {
+    count: u32,
+}
+
+fn bump(self: &mut Counter) {
+    //~^ ERROR `self` parameter is only allowed in associated functions
+    self.count += 1;
+}
+
+fn main() {}
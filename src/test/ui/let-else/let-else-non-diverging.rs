@@ -1,13 +1,22 @@
 #![feature(let_else)]
 
 fn main() {
-    let Some(x) = Some(1) else { //~ ERROR does not diverge
+    let Some(x) = Some(1) else {
         Some(2)
+        //~^ ERROR does not diverge
     };
-    let Some(x) = Some(1) else { //~ ERROR does not diverge
+    let Some(x) = Some(1) else {
         if 1 == 1 {
             panic!();
         }
+        //~^^^ ERROR does not diverge
     };
     let Some(x) = Some(1) else { Some(2) }; //~ ERROR does not diverge
+    for _ in 0..2 {
+        let Some(x) = Some(1) else {
+            drop(1);
+            //~^ ERROR does not diverge
+        };
+    }
+    let Some(x) = Some(1) else {}; //~ ERROR does not diverge
 }
@@ -0,0 +1,17 @@
+#![deny(improper_ctypes)]
+#![allow(dead_code)]
+
+// Check that each parameter with a type that is not FFI-safe is reported on its own.
+
+pub struct Rust(u32);
+
+extern "C" {
+    pub fn wide(a: u32, b: i128, c: u8) -> u128;
+    //~^ ERROR `extern` block uses type `i128`
+    //~| ERROR `extern` block uses type `u128`
+    pub fn adt(ok: *const u8, by_value: Rust, _: Rust);
+    //~^ ERROR `extern` block uses type `Rust`
+    //~| ERROR `extern` block uses type `Rust`
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+// check-pass
+
+#![feature(lint_reasons)]
+
+trait Bound {}
+
+trait OnMethod {
+    #[expect(where_clauses_object_safety)]
+    fn method(&self) where Self: Bound;
+}
+
+impl Bound for dyn OnMethod {}
+
+#[expect(where_clauses_object_safety)]
+trait OnTrait {
+    fn method(&self) where Self: Bound;
+}
+
+impl Bound for dyn OnTrait {}
+
+trait NoLongerFires {
+    #[expect(where_clauses_object_safety)]
+    //~^ WARNING this lint expectation is unfulfilled
+    fn method(&self) where Self: Sized;
+}
+
+impl Bound for dyn NoLongerFires {}
+
+trait TheTrait {}
+
+impl TheTrait for for<'a, 'b> fn(&'a u8, &'b u8) -> &'a u8 {}
+
+#[expect(coherence_leak_check)]
+impl TheTrait for for<'a> fn(&'a u8, &'a u8) -> &'a u8 {}
+
+fn main() {}
@@ -0,0 +1,43 @@
+// run-rustfix
+
+#![deny(unused_where_clause_lifetimes)]
+#![allow(dead_code)]
+
+fn outlives_static<'a>() where 'a: 'static {}
+//~^ ERROR lifetime parameter `'a` is only used in the `where` clause
+
+fn longer<'a, 'b>(x: &'b u8) -> &'b u8 where 'a: 'b { x }
+//~^ ERROR lifetime parameter `'a` is only used in the `where` clause
+
+fn shorter<'a, T>(x: T) -> T where T: 'a, T: Clone { x }
+//~^ ERROR lifetime parameter `'a` is only used in the `where` clause
+
+struct S;
+
+impl S {
+    fn method<'a, 'b>(&self, _: &'b u8) where 'b: 'a {}
+    //~^ ERROR lifetime parameter `'a` is only used in the `where` clause
+}
+
+// Together, these bounds require `T: 'b`.
+fn between<'a, 'b, T>(_: &'b T) where T: 'a, 'a: 'b {}
+
+fn in_body<'a>() where 'a: 'static {
+    let _: &'a u8 = &0;
+}
+
+trait Trait<'a> {}
+
+fn trait_bound<'a, T>() where T: Trait<'a> {}
+
+impl<'a> Trait<'a> for S where 'a: 'static {}
+
+// The bound is needed for the type annotation in the body to be well-formed.
+fn in_body_wf<'a, 'b>() where 'b: 'a {
+    let _x: &'a &'b () = &&();
+}
+
+// Other crates can name the parameter.
+pub fn exported<'a>() where 'a: 'static {}
+
+fn main() {}
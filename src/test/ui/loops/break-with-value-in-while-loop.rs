@@ -0,0 +1,9 @@
+fn main() {
+    let mut n = 0;
+    let _ = while n < 10 {
+        n += 1;
+        if n == 5 {
+            break n; //~ ERROR `break` with value from a `while` loop
+        }
+    };
+}
@@ -0,0 +1,20 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct Counter;
+
+impl Counter {
+    fn clone(&self, times: usize) -> Vec<Counter> {
+        (0..times).map(|_| Counter).collect()
+    }
+}
+
+fn main() {
+    let rc = Rc::new(Counter);
+    let _ = rc.strong_count();
+    //~^ ERROR no method named `strong_count` found for struct `Rc<Counter>` in the current scope
+
+    let arc = Arc::new(Counter);
+    let _ = arc.clone(2);
+    //~^ ERROR this function takes 0 arguments but 1 argument was supplied
+}
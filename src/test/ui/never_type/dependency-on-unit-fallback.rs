@@ -0,0 +1,23 @@
+// check-pass
+// run-rustfix
+
+#![allow(unreachable_code)]
+#![warn(dependency_on_unit_never_type_fallback)]
+
+fn takes_default<T: Default>(_: T) {}
+
+fn binding() {
+    let x = panic!();
+    takes_default(x);
+    //~^ WARN this function depends on never type fallback being `()`
+}
+
+fn argument() {
+    takes_default(panic!());
+    //~^ WARN this function depends on never type fallback being `()`
+}
+
+fn main() {
+    binding();
+    argument();
+}
@@ -0,0 +1,40 @@
+// run-pass
+// Check that `String`s can be matched against string literals and `Box`es against patterns
+// for their contents with `#![feature(deref_patterns)]`.
+
+#![feature(deref_patterns)]
+#![allow(incomplete_features)]
+
+enum Shape {
+    Circle(u32),
+    Square { side: u32 },
+}
+
+fn describe(name: String) -> &'static str {
+    match name {
+        "circle" => "round",
+        "square" => "angular",
+        _ => "unknown",
+    }
+}
+
+fn area(shape: Box<Shape>) -> u32 {
+    match shape {
+        Shape::Circle(radius) => 3 * radius * radius,
+        Shape::Square { side } => side * side,
+    }
+}
+
+fn main() {
+    assert_eq!(describe("circle".to_string()), "round");
+    assert_eq!(describe("square".to_string()), "angular");
+    assert_eq!(describe(String::new()), "unknown");
+
+    assert_eq!(area(Box::new(Shape::Circle(2))), 12);
+    assert_eq!(area(Box::new(Shape::Square { side: 3 })), 9);
+
+    // Default binding modes still apply through the `Box`.
+    let pair = Box::new((1, 2));
+    let (a, b) = &pair;
+    assert_eq!(*a + *b, 3);
+}
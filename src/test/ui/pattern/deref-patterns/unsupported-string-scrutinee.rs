@@ -0,0 +1,28 @@
+// Check that string literals can only be used to match on `String`s through `Deref`.
+
+#![feature(deref_patterns)]
+#![allow(incomplete_features)]
+
+use std::rc::Rc;
+
+struct NoDeref;
+
+fn main() {
+    match Rc::<str>::from("foo") {
+        "foo" => {}
+        //~^ ERROR cannot match `Rc<str>` against a string literal
+        _ => {}
+    }
+
+    match Rc::new(0i32) {
+        "foo" => {}
+        //~^ ERROR cannot match `Rc<i32>` against a string literal
+        _ => {}
+    }
+
+    match NoDeref {
+        "foo" => {}
+        //~^ ERROR cannot match `NoDeref` against a string literal
+        _ => {}
+    }
+}
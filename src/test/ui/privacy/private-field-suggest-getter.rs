@@ -0,0 +1,28 @@
+mod shape {
+    pub struct Square {
+        side: u32,
+        area: u32,
+    }
+
+    impl Square {
+        pub fn new(side: u32) -> Self {
+            Square { side, area: side * side }
+        }
+
+        pub fn get_side(&self) -> u32 {
+            self.side
+        }
+
+        pub fn get_area(&self, scale: u32) -> u32 {
+            self.area * scale
+        }
+    }
+}
+
+fn main() {
+    let square = shape::Square::new(2);
+    let _ = square.side;
+    //~^ ERROR field `side` of struct `Square` is private
+    let _ = square.area;
+    //~^ ERROR field `area` of struct `Square` is private
+}
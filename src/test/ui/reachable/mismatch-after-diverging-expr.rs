@@ -0,0 +1,33 @@
+// Check that a type mismatch in code made unreachable by a diverging expression points at that
+// expression only when the expected type was inferred from it.
+
+#![feature(label_break_value)]
+#![allow(unreachable_code)]
+
+fn after_return() {
+    return;
+    let _x: u32 = "hello"; //~ ERROR mismatched types
+}
+
+fn after_break() {
+    loop {
+        break;
+        let _y: bool = 1; //~ ERROR mismatched types
+    }
+}
+
+fn labeled_block() {
+    let _z = 'a: {
+        break 'a;
+        "hello" //~ ERROR mismatched types
+    };
+}
+
+fn labeled_block_with_annotation() {
+    let _w: () = 'a: {
+        break 'a;
+        "hello" //~ ERROR mismatched types
+    };
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+// Nested items can reuse the name of a lifetime declared on an enclosing item. When an error
+// mentions that name, both declarations should be pointed out.
+
+#![allow(dead_code)]
+
+trait Trait<'a, 'b> {
+    type Out;
+}
+
+fn outer<'a>(x: &'a u8) -> &'a u8 {
+    impl<'a, 'b> Trait<'a, 'b> for u8 {
+        type Out = &'a &'b ();
+        //~^ ERROR reference has a longer lifetime than the data it references
+    }
+
+    x
+}
+
+fn main() {}
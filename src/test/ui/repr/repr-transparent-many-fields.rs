@@ -0,0 +1,9 @@
+// Every non-zero-sized field after the first one should be pointed at.
+
+#[repr(transparent)]
+enum ManyFields {
+    Foo { a: u8, b: u16, c: u32 },
+}
+//~^^^ ERROR the variant of a transparent enum needs at most one non-zero-sized field, but has 3
+
+fn main() {}
@@ -0,0 +1,16 @@
+// run-pass
+// Check that `main` can return a type whose `Termination` impl is generic.
+
+use std::process::{ExitCode, Termination};
+
+struct Wrapper<T>(T);
+
+impl<T: Termination> Termination for Wrapper<T> {
+    fn report(self) -> ExitCode {
+        self.0.report()
+    }
+}
+
+fn main() -> Wrapper<Result<(), String>> {
+    Wrapper(Ok(()))
+}
@@ -0,0 +1,13 @@
+use std::process::{ExitCode, Termination};
+
+struct Wrapper<T>(T);
+
+impl<T: Termination> Termination for Wrapper<T> {
+    fn report(self) -> ExitCode {
+        self.0.report()
+    }
+}
+
+fn main() -> Wrapper<char> { //~ ERROR `main` has invalid return type `Wrapper<char>`
+    Wrapper('a')
+}
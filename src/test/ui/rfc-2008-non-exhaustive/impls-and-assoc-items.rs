@@ -0,0 +1,31 @@
+// Check that `#[non_exhaustive]` is rejected on impls and associated items.
+
+struct Foo;
+
+trait Tr {
+    #[non_exhaustive]
+    //~^ ERROR `#[non_exhaustive]` has no effect on associated types [E0701]
+    type Assoc;
+
+    #[non_exhaustive]
+    //~^ ERROR `#[non_exhaustive]` has no effect on associated functions [E0701]
+    fn make() -> Self;
+}
+
+#[non_exhaustive]
+//~^ ERROR `#[non_exhaustive]` has no effect on implementations [E0701]
+impl Tr for Foo {
+    type Assoc = ();
+
+    fn make() -> Self {
+        Foo
+    }
+}
+
+impl Foo {
+    #[non_exhaustive]
+    //~^ ERROR `#[non_exhaustive]` has no effect on methods [E0701]
+    fn get(&self) {}
+}
+
+fn main() {}
@@ -3,7 +3,7 @@
 struct Foo;
 
 #[non_exhaustive]
-//~^ ERROR attribute should be applied to a struct or enum [E0701]
+//~^ ERROR `#[non_exhaustive]` has no effect on traits [E0701]
 trait Bar { }
 
 #[non_exhaustive]
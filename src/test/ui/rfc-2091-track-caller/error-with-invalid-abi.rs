@@ -8,4 +8,14 @@ extern "C" fn f() {}
     //~^^ ERROR `#[track_caller]` requires Rust ABI
 }
 
+trait T {
+    #[track_caller]
+    extern "C" fn h();
+    //~^^ ERROR `#[track_caller]` requires Rust ABI
+
+    #[track_caller]
+    extern "C" fn i() {}
+    //~^^ ERROR `#[track_caller]` requires Rust ABI
+}
+
 fn main() {}
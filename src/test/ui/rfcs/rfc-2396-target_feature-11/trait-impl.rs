@@ -5,6 +5,10 @@
 trait Foo {
     fn foo(&self);
     unsafe fn unsf_foo(&self);
+
+    #[target_feature(enable = "sse2")]
+    //~^ ERROR cannot be applied to safe trait method
+    fn with_default(&self) {}
 }
 
 struct Bar;
@@ -0,0 +1,28 @@
+#![feature(arbitrary_self_types, dispatch_from_dyn, unsize)]
+
+// Check that we point at the implicit `Sized` bound that keeps a custom pointer receiver from
+// being dispatchable.
+
+use std::marker::Unsize;
+use std::ops::{Deref, DispatchFromDyn};
+
+struct Ptr<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized> Deref for Ptr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Unsize<U>, U: ?Sized> DispatchFromDyn<Ptr<U>> for Ptr<T> {}
+
+trait Trait {
+    fn method(self: Ptr<Self>);
+}
+
+fn use_dyn(_: &dyn Trait) {}
+//~^ ERROR the trait `Trait` cannot be made into an object
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![feature(arbitrary_self_types)]
+
+// Check that we explain which impl a custom pointer receiver is missing.
+
+use std::ops::Deref;
+
+struct Ptr<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized> Deref for Ptr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+struct NoDeref<T: ?Sized>(Box<T>);
+
+trait Trait {
+    fn method(self: Ptr<Self>);
+}
+
+trait Other {
+    fn method(self: NoDeref<Self>);
+    //~^ ERROR invalid `self` parameter type: NoDeref<Self>
+}
+
+fn use_dyn(_: &dyn Trait) {}
+//~^ ERROR the trait `Trait` cannot be made into an object
+
+fn main() {}
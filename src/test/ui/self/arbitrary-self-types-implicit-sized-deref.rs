@@ -0,0 +1,23 @@
+#![feature(arbitrary_self_types)]
+
+// Check that we point at the implicit `Sized` bound that keeps `Ptr<Self>` from
+// dereferencing to `Self`.
+
+use std::ops::Deref;
+
+struct Ptr<T: ?Sized>(Box<T>);
+
+impl<T> Deref for Ptr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+trait Trait {
+    fn method(self: Ptr<Self>);
+    //~^ ERROR invalid `self` parameter type: Ptr<Self>
+}
+
+fn main() {}
@@ -51,19 +51,19 @@ fn main() {
         let _: u64 = simd_bitmask(m64);
 
         let _: u16 = simd_bitmask(m2);
-        //~^ ERROR invalid monomorphization of `simd_bitmask` intrinsic
+        //~^ ERROR invalid use of `simd_bitmask` intrinsic
 
         let _: u16 = simd_bitmask(m8);
-        //~^ ERROR invalid monomorphization of `simd_bitmask` intrinsic
+        //~^ ERROR invalid use of `simd_bitmask` intrinsic
 
         let _: u32 = simd_bitmask(m16);
-        //~^ ERROR invalid monomorphization of `simd_bitmask` intrinsic
+        //~^ ERROR invalid use of `simd_bitmask` intrinsic
 
         let _: u64 = simd_bitmask(m32);
-        //~^ ERROR invalid monomorphization of `simd_bitmask` intrinsic
+        //~^ ERROR invalid use of `simd_bitmask` intrinsic
 
         let _: u128 = simd_bitmask(m64);
-        //~^ ERROR invalid monomorphization of `simd_bitmask` intrinsic
+        //~^ ERROR invalid use of `simd_bitmask` intrinsic
 
    }
 }
@@ -22,12 +22,12 @@ fn main() {
         let _: Simd<u32, 2> = simd_shuffle(v, v, I);
 
         let _: Simd<u32, 4> = simd_shuffle(v, v, I);
-        //~^ ERROR invalid monomorphization of `simd_shuffle` intrinsic
+        //~^ ERROR invalid use of `simd_shuffle` intrinsic
 
         let _: Simd<f32, 2> = simd_shuffle(v, v, I);
-        //~^ ERROR invalid monomorphization of `simd_shuffle` intrinsic
+        //~^ ERROR invalid use of `simd_shuffle` intrinsic
 
         let _: Simd<u32, 2> = simd_shuffle(v, v, I2);
-        //~^ ERROR invalid monomorphization of `simd_shuffle` intrinsic
+        //~^ ERROR invalid use of `simd_shuffle` intrinsic
     }
 }
@@ -0,0 +1,33 @@
+// check-fail
+
+// Test that calls to the SIMD intrinsics with concrete types are checked during type checking,
+// even in functions that are never monomorphized, while calls in generic code are left to codegen.
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types, dead_code)]
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+struct i32x4(i32, i32, i32, i32);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+struct i16x8(i16, i16, i16, i16, i16, i16, i16, i16);
+
+extern "platform-intrinsic" {
+    fn simd_eq<T, U>(x: T, y: T) -> U;
+    fn simd_extract<T, U>(x: T, idx: u32) -> U;
+}
+
+unsafe fn unused(x: i32x4) {
+    let _: i16x8 = simd_eq(x, x);
+    //~^ ERROR expected return type with length 4 (same as input type `i32x4`)
+    let _: f32 = simd_extract(x, 0);
+    //~^ ERROR expected return type `i32` (element of input `i32x4`), found `f32`
+}
+
+unsafe fn generic<T: Copy, U>(x: T) -> U {
+    simd_eq(x, x)
+}
+
+fn main() {}
@@ -9,7 +9,7 @@
 struct empty2([f32; 0]); //~ ERROR SIMD vector cannot be empty
 
 #[repr(simd)]
-struct pow2([f32; 7]);
+struct pow2([f32; 7]); //~ ERROR SIMD vector length must be a power of two
 
 #[repr(simd)]
 struct i64f64(i64, f64); //~ ERROR SIMD vector should be homogeneous
@@ -36,4 +36,8 @@ struct RGBA {
     a: f32
 }
 
+#[repr(simd)]
+struct TwoArrays([f32; 2], [f32; 2]);
+//~^ ERROR SIMD vector with an array field cannot have any other fields
+
 fn main() {}
@@ -0,0 +1,5 @@
+pub trait ATrait {
+    fn method(&self) {}
+}
+
+impl ATrait for u8 {}
@@ -0,0 +1,9 @@
+// aux-build:a_indirect_traits.rs
+
+extern crate a_indirect_traits;
+
+pub trait ZTrait {
+    fn method(&self) {}
+}
+
+impl ZTrait for u8 {}
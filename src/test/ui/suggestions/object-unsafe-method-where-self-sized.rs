@@ -0,0 +1,13 @@
+// run-rustfix
+#![allow(dead_code)]
+
+trait Trait {
+    fn ret(&self) -> Self;
+    fn generic<T>(&self, x: T);
+    fn ok(&self);
+}
+
+fn take(_: &dyn Trait) {}
+//~^ ERROR the trait `Trait` cannot be made into an object
+
+fn main() {}
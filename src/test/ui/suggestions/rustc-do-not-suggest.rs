@@ -0,0 +1,77 @@
+// Values of a type marked `#[rustc_do_not_suggest(..)]` don't get the borrow, deref or clone
+// suggestions it opts out of, while an otherwise identical type does.
+
+#![feature(rustc_attrs)]
+
+#[rustc_do_not_suggest(borrow, deref)]
+struct Handle(u32);
+
+struct Plain(u32);
+
+fn takes_handle_ref(_: &Handle) {}
+fn takes_plain_ref(_: &Plain) {}
+
+#[rustc_do_not_suggest(borrow)] //~ ERROR `#[rustc_do_not_suggest]` should be applied to a struct, enum or union
+fn not_a_type() {}
+
+#[rustc_do_not_suggest(move)] //~ ERROR unknown suggestion family
+struct Unknown;
+
+fn main() {
+    let handle = Handle(0);
+    takes_handle_ref(handle); //~ ERROR mismatched types
+    let plain = Plain(0);
+    takes_plain_ref(plain); //~ ERROR mismatched types
+}
+
+#[derive(Clone, Copy)]
+#[rustc_do_not_suggest(deref)]
+struct Id(u32);
+
+#[derive(Clone, Copy)]
+struct PlainId(u32);
+
+fn takes_id(_: Id) {}
+fn takes_plain_id(_: PlainId) {}
+
+#[rustc_do_not_suggest(clone)]
+struct Name;
+
+struct PlainName;
+
+impl Name {
+    #[rustc_conversion_suggestion]
+    fn duplicate(&self) -> Name {
+        Name
+    }
+}
+
+impl PlainName {
+    #[rustc_conversion_suggestion]
+    fn duplicate(&self) -> PlainName {
+        PlainName
+    }
+}
+
+fn takes_name(_: Name) {}
+fn takes_plain_name(_: PlainName) {}
+
+fn deref_and_clone(id: &Id, plain_id: &PlainId, name: &Name, plain_name: &PlainName) {
+    takes_id(id); //~ ERROR mismatched types
+    takes_plain_id(plain_id); //~ ERROR mismatched types
+    takes_name(name); //~ ERROR mismatched types
+    takes_plain_name(plain_name); //~ ERROR mismatched types
+}
+
+impl Name {
+    #[rustc_conversion_suggestion]
+    fn label(&self) -> String {
+        String::from("name")
+    }
+}
+
+fn takes_label(_: String) {}
+
+fn clone_only(name: &Name) {
+    takes_label(name); //~ ERROR mismatched types
+}
@@ -0,0 +1,21 @@
+use std::collections::HashSet;
+
+struct Grid {
+    cells: Vec<u8>,
+}
+
+impl Grid {
+    fn get(&self, idx: usize) -> u8 {
+        self.cells[idx]
+    }
+}
+
+fn main() {
+    let set: HashSet<i32> = HashSet::new();
+    let _ = set[&1];
+    //~^ ERROR cannot index into a value of type `HashSet<i32>`
+
+    let grid = Grid { cells: vec![0; 4] };
+    let _ = grid[2];
+    //~^ ERROR cannot index into a value of type `Grid`
+}
@@ -0,0 +1,11 @@
+// aux-build:a_indirect_traits.rs
+// aux-build:z_direct_traits.rs
+
+// Traits of direct dependencies are suggested before traits of their dependencies.
+
+extern crate z_direct_traits;
+
+fn main() {
+    1u8.method();
+    //~^ ERROR no method named `method` found for type `u8` in the current scope
+}
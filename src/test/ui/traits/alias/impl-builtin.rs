@@ -0,0 +1,15 @@
+#![feature(trait_alias)]
+
+// Trait aliases can't be implemented, so an impl can never reach the checks for builtin traits
+// like `Copy` through an alias, not even through a chain of aliases.
+
+trait CopyAlias = Copy;
+trait NestedCopyAlias = CopyAlias;
+
+#[derive(Clone)]
+struct S;
+
+impl CopyAlias for S {} //~ ERROR expected trait, found trait alias
+impl NestedCopyAlias for S {} //~ ERROR expected trait, found trait alias
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Check that unsatisfied obligations on `Self` in the default body of a trait method point
+// at that method, as the body is shared by every implementor that doesn't override it.
+
+trait Named {
+    fn name(&self) -> &'static str;
+}
+
+fn introduce<T: Named>(_: &T) {}
+
+trait Greeter {
+    fn greet(&self) {
+        introduce(self);
+        //~^ ERROR the trait bound `Self: Named` is not satisfied
+    }
+
+    // Obligations that don't involve `Self` don't get the note.
+    fn greet_number(&self) {
+        introduce(&0u8);
+        //~^ ERROR the trait bound `u8: Named` is not satisfied
+    }
+}
+
+fn main() {}
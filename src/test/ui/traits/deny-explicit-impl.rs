@@ -0,0 +1,18 @@
+#![feature(ptr_metadata, rustc_attrs)]
+
+use std::ptr::Pointee;
+
+struct Thin;
+
+impl Pointee for Thin {
+    //~^ ERROR explicit impls for the `Pointee` trait are not permitted
+    type Metadata = ();
+}
+
+#[rustc_deny_explicit_impl]
+trait CompilerImplemented {}
+
+impl CompilerImplemented for Thin {}
+//~^ ERROR explicit impls for the `CompilerImplemented` trait are not permitted
+
+fn main() {}
@@ -0,0 +1,16 @@
+// Check that an impl which only differs from the required one in a defaulted
+// parameter is pointed out, and that the bound can be adjusted to match it.
+
+struct Meters;
+struct Feet;
+
+trait Combine<Rhs = Self> {}
+
+impl Combine<Feet> for Meters {}
+
+fn combine_all<T: Combine>(_: T) {}
+
+fn main() {
+    combine_all(Meters);
+    //~^ ERROR the trait bound `Meters: Combine` is not satisfied
+}
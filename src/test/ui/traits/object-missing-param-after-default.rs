@@ -0,0 +1,9 @@
+// Check that the suggestion for a type parameter that must be specified on a trait object
+// spells out the defaults of the parameters before it.
+
+trait Bar<X = usize, A = Self> {}
+
+fn f(_: &dyn Bar) {}
+//~^ ERROR the type parameter `A` must be explicitly specified
+
+fn main() {}
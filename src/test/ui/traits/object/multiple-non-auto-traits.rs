@@ -0,0 +1,32 @@
+// Check that the diagnostic for multiple non-auto traits in a trait object points out which of
+// the traits could be the principal trait, and suggests removing the one that can't be. Inside of
+// a trait, object safety isn't taken into account, as asking for it could end up in a cycle.
+
+trait Safe {}
+
+trait NotSafe {
+    fn new() -> Self;
+}
+
+trait AlsoNotSafe {
+    fn new() -> Self;
+}
+
+type _0 = dyn NotSafe + Safe + Send;
+//~^ ERROR only auto traits can be used as additional traits in a trait object [E0225]
+
+type _1 = dyn Send + Safe + NotSafe;
+//~^ ERROR only auto traits can be used as additional traits in a trait object [E0225]
+
+type _2 = dyn NotSafe + 'static + Safe;
+//~^ ERROR only auto traits can be used as additional traits in a trait object [E0225]
+
+type _3 = dyn NotSafe + AlsoNotSafe;
+//~^ ERROR only auto traits can be used as additional traits in a trait object [E0225]
+
+trait InOwnSignature {
+    fn get(&self) -> Box<dyn InOwnSignature + Safe>;
+    //~^ ERROR only auto traits can be used as additional traits in a trait object [E0225]
+}
+
+fn main() {}
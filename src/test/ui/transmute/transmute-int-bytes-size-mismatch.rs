@@ -0,0 +1,11 @@
+use std::mem::transmute;
+
+unsafe fn to_bytes(x: u32) -> [u8; 8] {
+    transmute(x) //~ ERROR cannot transmute between types of different sizes
+}
+
+unsafe fn from_bytes(bytes: [u8; 2]) -> f32 {
+    transmute(bytes) //~ ERROR cannot transmute between types of different sizes
+}
+
+fn main() {}
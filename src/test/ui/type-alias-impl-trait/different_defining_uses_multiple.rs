@@ -0,0 +1,19 @@
+#![feature(type_alias_impl_trait)]
+
+fn main() {}
+
+// three definitions with different types, reported in one error
+type Foo = impl std::fmt::Debug;
+
+fn foo() -> Foo {
+    ""
+    //~^ ERROR concrete types differ between defining opaque type uses
+}
+
+fn bar() -> Foo {
+    42i32
+}
+
+fn baz() -> Foo {
+    true
+}
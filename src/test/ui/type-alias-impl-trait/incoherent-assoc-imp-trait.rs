@@ -9,6 +9,7 @@ impl MyTrait for () {}
 
 impl<F> FnOnce<()> for &F {
     //~^ ERROR type parameter `F` must be used
+    //~| ERROR conflicting implementations
     type Output = impl MyTrait;
     extern "rust-call" fn call_once(self, _: ()) -> Self::Output {}
 }
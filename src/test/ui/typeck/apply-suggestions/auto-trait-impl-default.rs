@@ -0,0 +1,13 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+// Removing `default` from an auto trait impl must leave a valid impl.
+
+#![feature(specialization)] //~ WARN the feature `specialization` is incomplete
+#![allow(dead_code)]
+
+struct S;
+
+default unsafe impl Send for S {}
+//~^ ERROR impls of auto traits cannot be default
+
+fn main() {}
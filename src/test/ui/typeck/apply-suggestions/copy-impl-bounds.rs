@@ -0,0 +1,16 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+// Applying the bounds suggested for an invalid `Copy` impl must make the impl valid.
+
+#![allow(dead_code)]
+
+#[derive(Clone)]
+struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+impl<T, U> Copy for Pair<T, U> {}
+//~^ ERROR the trait `Copy` may not be implemented for this type
+
+fn main() {}
@@ -0,0 +1,21 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+// Adding the where clauses required on a generic associated type with several lifetimes must
+// make the trait valid, including when one of them is a region outlives clause.
+
+#![feature(generic_associated_types)]
+#![allow(dead_code)]
+
+trait Deserializer<T, U> {
+    type Out<'x, 'y>;
+    //~^ ERROR missing required bounds on `Out`
+    fn deserialize<'a, 'b>(&self, input: &'a T, input2: &'b U) -> Self::Out<'a, 'b>;
+}
+
+trait RegionOutlives {
+    type Bar<'a, 'b>;
+    //~^ ERROR missing required bound on `Bar`
+    fn foo<'x, 'y>(&self, input: &'x &'y ()) -> Self::Bar<'x, 'y>;
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+// Adding the suggested return type must make the function typecheck.
+
+#![allow(dead_code)]
+
+fn answer() {
+    40 + 2usize
+    //~^ ERROR mismatched types
+}
+
+fn main() {}
@@ -3,7 +3,7 @@
 fn main() {
     const a: u8 = 1;
     let b: typeof(a) = 1i8;
-    //~^ ERROR `typeof` is a reserved keyword but unimplemented
+    //~^ ERROR use of the reserved `typeof` keyword is experimental
     //~| ERROR mismatched types
     //~| expected `u8`, found `i8`
 }
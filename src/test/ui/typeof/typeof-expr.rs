@@ -0,0 +1,21 @@
+// check-pass
+// Check that `typeof(expr)` evaluates to the type of `expr` when the feature is enabled.
+
+#![feature(typeof_expr)]
+#![allow(incomplete_features)]
+
+const LIMIT: u16 = 300;
+
+struct Config {
+    limit: typeof(LIMIT),
+}
+
+fn double(x: typeof(LIMIT)) -> typeof(LIMIT * 2) {
+    x * 2
+}
+
+fn main() {
+    let config = Config { limit: LIMIT };
+    let doubled: u16 = double(config.limit);
+    let _: typeof([0u8; 4]) = [doubled as u8; 4];
+}
@@ -13,6 +13,7 @@
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::nested_filter;
 use rustc_middle::traits::Reveal;
+use rustc_middle::ty::fast_reject::SimplifiedType;
 use rustc_middle::ty::{
     self, Binder, BoundConstness, GenericParamDefKind, ImplPolarity, ParamEnv, PredicateKind, TraitPredicate, TraitRef,
     Ty, TyCtxt, Visibility,
@@ -352,11 +353,11 @@ fn check_copy_clone<'tcx>(cx: &LateContext<'tcx>, item: &Item<'_>, trait_ref: &h
     // there's a Copy impl for any instance of the adt.
     if !is_copy(cx, ty) {
         if ty_subs.non_erasable_generics().next().is_some() {
-            let has_copy_impl = cx.tcx.all_local_trait_impls(()).get(&copy_id).map_or(false, |impls| {
-                impls
-                    .iter()
-                    .any(|&id| matches!(cx.tcx.type_of(id).kind(), ty::Adt(adt, _) if ty_adt.did() == adt.did()))
-            });
+            let has_copy_impl = cx
+                .tcx
+                .trait_impls_by_self_simplified(copy_id)
+                .relevant_impls(Some(SimplifiedType::AdtSimplifiedType(ty_adt.did())))
+                .any(|id| matches!(cx.tcx.type_of(id).kind(), ty::Adt(adt, _) if ty_adt.did() == adt.did()));
             if !has_copy_impl {
                 return;
             }
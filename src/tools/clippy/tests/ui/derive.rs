@@ -86,4 +86,12 @@ fn clone(&self) -> Self {
     }
 }
 
+// Ok, there's no `Copy` impl for any instance of this type.
+struct NotCopy<T>(T);
+impl<T: Clone> Clone for NotCopy<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 fn main() {}
@@ -548,6 +548,7 @@ pub fn expected_output_path(
     UI_STDERR_64,
     UI_STDERR_32,
     UI_STDERR_16,
+    UI_RECORDS,
 ];
 pub const UI_STDERR: &str = "stderr";
 pub const UI_STDOUT: &str = "stdout";
@@ -557,6 +558,7 @@ pub fn expected_output_path(
 pub const UI_STDERR_64: &str = "64bit.stderr";
 pub const UI_STDERR_32: &str = "32bit.stderr";
 pub const UI_STDERR_16: &str = "16bit.stderr";
+pub const UI_RECORDS: &str = "records";
 
 /// Absolute path to the directory where all output for all tests in the given
 /// `relative_dir` group should reside. Example:
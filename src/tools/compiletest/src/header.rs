@@ -159,6 +159,9 @@ pub struct TestProps {
     pub should_ice: bool,
     // If true, the stderr is expected to be different across bit-widths.
     pub stderr_per_bitwidth: bool,
+    // If true, the diagnostics are compared against a `.records` file listing their Fluent
+    // identifiers and arguments, instead of against the rendered `.stderr`.
+    pub diagnostic_records: bool,
     // The MIR opt to unit test, if any
     pub mir_unit_test: Option<String>,
 }
@@ -192,6 +195,7 @@ mod directives {
     pub const RUSTFIX_ONLY_MACHINE_APPLICABLE: &'static str = "rustfix-only-machine-applicable";
     pub const ASSEMBLY_OUTPUT: &'static str = "assembly-output";
     pub const STDERR_PER_BITWIDTH: &'static str = "stderr-per-bitwidth";
+    pub const DIAGNOSTIC_RECORDS: &'static str = "diagnostic-records";
     pub const INCREMENTAL: &'static str = "incremental";
     pub const KNOWN_BUG: &'static str = "known-bug";
     pub const MIR_UNIT_TEST: &'static str = "unit-test";
@@ -239,6 +243,7 @@ pub fn new() -> Self {
             assembly_output: None,
             should_ice: false,
             stderr_per_bitwidth: false,
+            diagnostic_records: false,
             mir_unit_test: None,
         }
     }
@@ -405,6 +410,7 @@ fn load_from(&mut self, testfile: &Path, cfg: Option<&str>, config: &Config) {
                     |r| r.trim().to_string(),
                 );
                 config.set_name_directive(ln, STDERR_PER_BITWIDTH, &mut self.stderr_per_bitwidth);
+                config.set_name_directive(ln, DIAGNOSTIC_RECORDS, &mut self.diagnostic_records);
                 config.set_name_directive(ln, INCREMENTAL, &mut self.incremental);
 
                 // Unlike the other `name_value_directive`s this needs to be handled manually,
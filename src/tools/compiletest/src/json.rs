@@ -15,6 +15,14 @@ struct Diagnostic {
     spans: Vec<DiagnosticSpan>,
     children: Vec<Diagnostic>,
     rendered: Option<String>,
+    record: Option<DiagnosticRecord>,
+}
+
+/// The untranslated form of a diagnostic, emitted with `-Z json-diagnostic-records`.
+#[derive(Deserialize)]
+struct DiagnosticRecord {
+    slug: Option<String>,
+    args: Vec<(String, String)>,
 }
 
 #[derive(Deserialize)]
@@ -139,6 +147,55 @@ pub fn extract_rendered(output: &str) -> String {
         .collect()
 }
 
+/// Renders the diagnostics in `output` as one record per diagnostic and suggestion, keyed by
+/// their Fluent identifier and arguments instead of the rendered text, so that rewording a
+/// message doesn't change the output.
+pub fn extract_records(output: &str) -> String {
+    let mut records = String::new();
+    for line in output.lines().filter(|line| line.starts_with('{')) {
+        if let Ok(diagnostic) = serde_json::from_str::<Diagnostic>(line) {
+            push_record(&mut records, &diagnostic, 0);
+        }
+    }
+    records
+}
+
+fn push_record(records: &mut String, diagnostic: &Diagnostic, depth: usize) {
+    let indent = "    ".repeat(depth);
+    let code = diagnostic.code.as_ref().map_or_else(String::new, |code| format!("[{}]", code.code));
+    let (message, args) = match &diagnostic.record {
+        Some(DiagnosticRecord { slug: Some(slug), args }) => (slug.clone(), &args[..]),
+        _ => (format!("{:?}", diagnostic.message), &[][..]),
+    };
+    records.push_str(&format!("{}{}{}: {}", indent, diagnostic.level, code, message));
+    if !args.is_empty() {
+        let args: Vec<_> =
+            args.iter().map(|(name, value)| format!("{}={:?}", name, value)).collect();
+        records.push_str(&format!(" {{{}}}", args.join(", ")));
+    }
+    if let Some(span) = diagnostic.spans.iter().find(|span| span.is_primary) {
+        records.push_str(&format!(" @ {}:{}", span.line_start, span.column_start));
+    }
+    records.push('\n');
+
+    for span in &diagnostic.spans {
+        if let Some(replacement) = &span.suggested_replacement {
+            records.push_str(&format!(
+                "{}    {}:{}-{}:{} => {:?}\n",
+                indent,
+                span.line_start,
+                span.column_start,
+                span.line_end,
+                span.column_end,
+                replacement,
+            ));
+        }
+    }
+    for child in &diagnostic.children {
+        push_record(records, child, depth + 1);
+    }
+}
+
 pub fn parse_output(file_name: &str, output: &str, proc_res: &ProcRes) -> Vec<Error> {
     output.lines().flat_map(|line| parse_line(file_name, line, output, proc_res)).collect()
 }
@@ -7,7 +7,7 @@
 use crate::common::{CompareMode, FailMode, PassMode};
 use crate::common::{Config, TestPaths};
 use crate::common::{Pretty, RunPassValgrind};
-use crate::common::{UI_RUN_STDERR, UI_RUN_STDOUT};
+use crate::common::{UI_RECORDS, UI_RUN_STDERR, UI_RUN_STDOUT};
 use crate::compute_diff::{write_diff, write_filtered_diff};
 use crate::errors::{self, Error, ErrorKind};
 use crate::header::TestProps;
@@ -1947,6 +1947,9 @@ fn make_compile_args(
                     rustc.args(&["--error-format", "json"]);
                     rustc.args(&["--json", "future-incompat"]);
                 }
+                if self.props.diagnostic_records {
+                    rustc.arg("-Zjson-diagnostic-records");
+                }
                 rustc.arg("-Ccodegen-units=1");
                 rustc.arg("-Zui-testing");
                 rustc.arg("-Zdeduplicate-diagnostics=no");
@@ -3176,7 +3179,14 @@ fn load_compare_outputs(
                     errors +=
                         self.compare_output(stdout_kind, &normalized_stdout, &expected_stdout);
                 }
-                if !self.props.dont_check_compiler_stderr {
+                if self.props.diagnostic_records {
+                    let records = self.normalize_output(
+                        &json::extract_records(&proc_res.stderr),
+                        &self.props.normalize_stderr,
+                    );
+                    let expected_records = self.load_expected_output(UI_RECORDS);
+                    errors += self.compare_output(UI_RECORDS, &records, &expected_records);
+                } else if !self.props.dont_check_compiler_stderr {
                     errors +=
                         self.compare_output(stderr_kind, &normalized_stderr, &expected_stderr);
                 }